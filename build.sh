@@ -19,6 +19,10 @@ if ! command -v wasm-pack; then
 fi
 
 
+# Shown by the `version` command to identify the build in bug reports.
+INITIATIVE_BUILD="${INITIATIVE_BUILD:-$(git -C "$project_root" rev-parse --short HEAD 2>/dev/null || true)}"
+export INITIATIVE_BUILD
+
 cd "$project_root/web"
 wasm-pack build --release
 npm install
@@ -5,6 +5,11 @@ pub async fn run(mut app: App) -> io::Result<()> {
     let mut buffer = String::new();
     let stdin = io::stdin();
 
+    // Scripts reading the output can opt out of Markdown formatting.
+    if std::env::var_os("INITIATIVE_PLAINTEXT").is_some_and(|value| !value.is_empty()) {
+        app.set_plaintext(true);
+    }
+
     loop {
         match stdin.read_line(&mut buffer) {
             Ok(0) => return Ok(()),
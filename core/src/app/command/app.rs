@@ -1,19 +1,159 @@
+use super::roll::{roll_ability_scores, DiceFormula};
+use super::Command;
 use crate::app::{
-    AppMeta, Autocomplete, AutocompleteSuggestion, CommandMatches, ContextAwareParse, Runnable,
+    App, AppMeta, Autocomplete, AutocompleteSuggestion, CommandMatches, ContextAwareParse, Runnable,
 };
-use crate::utils::CaseInsensitiveStr;
+use crate::utils::{edit_distance, CaseInsensitiveStr};
+use crate::world::{NameFlavor, Units};
 use async_trait::async_trait;
-use caith::Roller;
 use initiative_macros::changelog;
+use rand::prelude::*;
 use std::fmt;
 
+/// Topics available through `help [command]`. The first name of each is the one that is suggested;
+/// the others are other commands covered by the same page.
+const HELP_TOPICS: &[(&[&str], &str)] = &[
+    (
+        &["alias", "aliases", "unalias", "shortcut", "shortcuts"],
+        include_str!("../../../../data/help/alias.md"),
+    ),
+    (
+        &["describe", "prose"],
+        include_str!("../../../../data/help/describe.md"),
+    ),
+    (
+        &["edit", "is", "rename", "reroll"],
+        include_str!("../../../../data/help/edit.md"),
+    ),
+    (
+        &[
+            "generate",
+            "town",
+            "region",
+            "generate town",
+            "generate region",
+        ],
+        include_str!("../../../../data/help/generate.md"),
+    ),
+    (
+        &["history", "!!", "it", "that"],
+        include_str!("../../../../data/help/history.md"),
+    ),
+    (
+        &["initiative", "combat", "roll initiative"],
+        include_str!("../../../../data/help/initiative.md"),
+    ),
+    (
+        &[
+            "journal",
+            "save",
+            "load",
+            "delete",
+            "search",
+            "list",
+            "export",
+            "import",
+            "clear",
+            "clear world",
+            "stats",
+            "world stats",
+            "recent",
+        ],
+        include_str!("../../../../data/help/journal.md"),
+    ),
+    (
+        &["note", "notes", "note add", "note set"],
+        include_str!("../../../../data/help/note.md"),
+    ),
+    (
+        &["npc", "npcs", "character", "characters"],
+        include_str!("../../../../data/help/npc.md"),
+    ),
+    (
+        &["place", "places", "inn", "building", "buildings"],
+        include_str!("../../../../data/help/place.md"),
+    ),
+    (
+        &[
+            "put",
+            "locations",
+            "locations in",
+            "what is in",
+            "who is at",
+        ],
+        include_str!("../../../../data/help/put.md"),
+    ),
+    (
+        &["roll", "dice", "roll stats"],
+        include_str!("../../../../data/help/roll.md"),
+    ),
+    (
+        &[
+            "settings",
+            "set",
+            "units",
+            "emoji",
+            "names",
+            "demographics",
+            "seed",
+            "last seed",
+        ],
+        include_str!("../../../../data/help/settings.md"),
+    ),
+    (
+        &[
+            "srd",
+            "reference",
+            "spell",
+            "spells",
+            "item",
+            "items",
+            "item categories",
+            "magic items",
+            "monster",
+            "monsters",
+            "conditions",
+            "traits",
+            "caster",
+        ],
+        include_str!("../../../../data/help/srd.md"),
+    ),
+    (
+        &["table", "tables", "roll table", "table create"],
+        include_str!("../../../../data/help/table.md"),
+    ),
+    (
+        &["tag", "tags", "untag"],
+        include_str!("../../../../data/help/tag.md"),
+    ),
+    (
+        &["time", "now", "date", "+", "-"],
+        include_str!("../../../../data/help/time.md"),
+    ),
+    (
+        &["undo", "redo"],
+        include_str!("../../../../data/help/undo.md"),
+    ),
+];
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AppCommand {
     About,
     Changelog,
     Debug,
+    Emoji(bool),
     Help,
+    HelpTopic(String),
+    History,
+    LastSeed,
+    Names(NameFlavor),
+    Repeat,
     Roll(String),
+    RollStats,
+    Seed(u64),
+    StandardArray,
+    Units(Units),
+    Version,
 }
 
 #[async_trait(?Send)]
@@ -29,25 +169,88 @@ impl Runnable for AppCommand {
                 app_meta.repository.journal().await,
             ),
             Self::Changelog => changelog!().to_string(),
+            Self::Version => App::version(),
+            Self::Emoji(emoji) => {
+                app_meta.emoji = emoji;
+                if emoji {
+                    "Emoji will now be shown.".to_string()
+                } else {
+                    "Emoji will no longer be shown.".to_string()
+                }
+            }
             Self::Help => include_str!("../../../../data/help.md")
                 .trim_end()
                 .to_string(),
-            Self::Roll(s) => Roller::new(&s)
-                .ok()
-                .and_then(|r| r.roll_with(&mut app_meta.rng).ok())
-                .map(|result| {
-                    result
-                        .to_string()
-                        .trim_end()
-                        .replace('\n', "\\\n")
-                        .replace('`', "")
-                })
-                .ok_or_else(|| {
+            Self::HelpTopic(topic) => {
+                if let Some((_, text)) = HELP_TOPICS
+                    .iter()
+                    .find(|(names, _)| names.iter().any(|name| name.eq_ci(&topic)))
+                {
+                    text.trim_end().to_string()
+                } else {
+                    return Err(help_topic_not_found(&topic));
+                }
+            }
+            Self::History => {
+                if app_meta.history.is_empty() {
+                    return Err("You haven't run any commands yet.".to_string());
+                }
+
+                let mut output = "# Recent commands".to_string();
+                app_meta.history.iter().enumerate().for_each(|(i, input)| {
+                    output.push_str(if i == 0 { "\n\n" } else { "\\\n" });
+                    output.push_str(&format!("`{}`", input));
+                });
+                output
+            }
+            Self::Repeat => {
+                let input = app_meta.history.back().cloned().ok_or_else(|| {
+                    "There is no previous command to repeat.".to_string()
+                })?;
+
+                app_meta.push_history(&input);
+                Command::parse_input_irrefutable(&input, app_meta)
+                    .await
+                    .run(&input, app_meta)
+                    .await?
+            }
+            Self::Roll(s) => {
+                let formula = DiceFormula::parse(&s)?;
+                formula.roll(&mut app_meta.rng).ok_or_else(|| {
                     format!(
                         "\"{}\" is not a valid dice formula. See `help` for some examples.",
                         s
                     )
-                })?,
+                })?
+            }
+            Self::RollStats => roll_ability_scores(&mut app_meta.rng)
+                .ok_or_else(|| "Couldn't roll ability scores.".to_string())?,
+            Self::StandardArray => "**Standard array:** 15, 14, 13, 12, 10, 8\n\n_Assign each score to the ability of your choice._".to_string(),
+            Self::Seed(seed) => {
+                app_meta.rng = SmallRng::seed_from_u64(seed);
+                app_meta.next_seed = Some(seed);
+                format!(
+                    "Random results are now seeded with {}. Use `seed {}` again to repeat them.",
+                    seed, seed,
+                )
+            }
+            Self::LastSeed => {
+                let seed = app_meta.last_seed.ok_or_else(|| {
+                    "You haven't generated anything yet. Try `npc` first.".to_string()
+                })?;
+                format!(
+                    "The last thing you generated used seed {}. Use `seed {}`, then the same command, to generate it again.",
+                    seed, seed,
+                )
+            }
+            Self::Names(names) => {
+                app_meta.names = names;
+                format!("Human characters will now be given {} names.", names)
+            }
+            Self::Units(units) => {
+                app_meta.units = units;
+                format!("Measurements will now be shown in {} units.", units)
+            }
         })
     }
 }
@@ -61,13 +264,53 @@ impl ContextAwareParse for AppCommand {
             CommandMatches::new_canonical(Self::Changelog)
         } else if input.eq_ci("debug") {
             CommandMatches::new_canonical(Self::Debug)
+        } else if input.eq_ci("version") {
+            CommandMatches::new_canonical(Self::Version)
         } else if input.eq_ci("help") {
             CommandMatches::new_canonical(Self::Help)
-        } else if input.starts_with_ci("roll ") {
-            CommandMatches::new_canonical(Self::Roll(input[5..].to_string()))
-        } else if !input.chars().all(|c| c.is_ascii_digit())
-            && Roller::new(input).map_or(false, |r| r.roll().is_ok())
+        } else if let Some(topic) = input
+            .strip_prefix_ci("help ")
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+        {
+            CommandMatches::new_canonical(Self::HelpTopic(topic.to_lowercase()))
+        } else if input.eq_ci("history") {
+            CommandMatches::new_canonical(Self::History)
+        } else if input.eq_ci("last seed") {
+            CommandMatches::new_canonical(Self::LastSeed)
+        } else if input.trim() == "!!" {
+            CommandMatches::new_canonical(Self::Repeat)
+        } else if let Some(seed) = input
+            .strip_prefix_ci("seed ")
+            .and_then(|s| s.trim().parse().ok())
         {
+            CommandMatches::new_canonical(Self::Seed(seed))
+        } else if let Some(units) = input
+            .strip_prefix_ci("set units ")
+            .and_then(|s| s.trim().parse().ok())
+        {
+            CommandMatches::new_canonical(Self::Units(units))
+        } else if let Some(names) = input
+            .strip_prefix_ci("set names ")
+            .and_then(|s| s.trim().parse().ok())
+        {
+            CommandMatches::new_canonical(Self::Names(names))
+        } else if input.eq_ci("set emoji on") {
+            CommandMatches::new_canonical(Self::Emoji(true))
+        } else if input.eq_ci("set emoji off") {
+            CommandMatches::new_canonical(Self::Emoji(false))
+        } else if input.eq_ci("roll stats") {
+            CommandMatches::new_canonical(Self::RollStats)
+        } else if input.eq_ci("roll stats standard") {
+            CommandMatches::new_canonical(Self::StandardArray)
+        } else if input.eq_ci("roll percentile") {
+            CommandMatches::new_canonical(Self::Roll("d100".to_string()))
+        } else if input.starts_with_ci("roll ")
+            && !input.starts_with_ci("roll table ")
+            && !input.eq_ci("roll initiative")
+        {
+            CommandMatches::new_canonical(Self::Roll(input[5..].to_string()))
+        } else if !input.chars().all(|c| c.is_ascii_digit()) && DiceFormula::parse(input).is_ok() {
             CommandMatches::new_fuzzy(Self::Roll(input.to_string()))
         } else {
             CommandMatches::default()
@@ -86,15 +329,75 @@ impl Autocomplete for AppCommand {
             AutocompleteSuggestion::new("about", "about initiative.sh"),
             AutocompleteSuggestion::new("changelog", "show latest updates"),
             AutocompleteSuggestion::new("help", "how to use initiative.sh"),
+            AutocompleteSuggestion::new("help [command]", "how to use a command"),
+            AutocompleteSuggestion::new("history", "list recent commands"),
+            AutocompleteSuggestion::new("!!", "repeat the last command"),
+            AutocompleteSuggestion::new("last seed", "the seed of the last result"),
+            AutocompleteSuggestion::new("version", "show the app version"),
         ]
         .into_iter()
         .filter(|suggestion| suggestion.term.starts_with_ci(input))
+        .chain(
+            input
+                .strip_prefix_ci("help ")
+                .filter(|topic| !topic.is_empty())
+                .into_iter()
+                .flat_map(|topic| {
+                    HELP_TOPICS
+                        .iter()
+                        .map(|(names, _)| names[0])
+                        .filter(move |name| name.starts_with_ci(topic))
+                })
+                .map(|name| {
+                    AutocompleteSuggestion::new(
+                        format!("help {}", name),
+                        format!("how to use `{}`", name),
+                    )
+                }),
+        )
         .chain(
             ["roll"]
                 .into_iter()
                 .filter(|s| s.starts_with_ci(input))
                 .map(|_| AutocompleteSuggestion::new("roll [dice]", "roll eg. 8d6 or d20+3")),
         )
+        .chain(
+            [
+                ("roll percentile", "roll d100"),
+                ("roll stats", "roll 4d6 for each ability"),
+                ("roll stats standard", "the standard ability array"),
+            ]
+            .into_iter()
+            .filter(|(term, _)| input.len() > "roll ".len() && term.starts_with_ci(input))
+            .map(|(term, summary)| AutocompleteSuggestion::new(term, summary)),
+        )
+        .chain(
+            ["seed"]
+                .into_iter()
+                .filter(|s| s.starts_with_ci(input))
+                .map(|_| AutocompleteSuggestion::new("seed [number]", "make results repeatable")),
+        )
+        .chain(
+            [
+                ("set emoji on", "show emoji"),
+                ("set emoji off", "hide emoji"),
+            ]
+            .into_iter()
+            .filter(|(term, _)| term.starts_with_ci(input))
+            .map(|(term, summary)| AutocompleteSuggestion::new(term, summary)),
+        )
+        .chain(
+            Units::get_words()
+                .map(|units| format!("set units {}", units))
+                .filter(|term| term.starts_with_ci(input))
+                .map(|term| AutocompleteSuggestion::new(term, "change measurement units")),
+        )
+        .chain(
+            NameFlavor::variants()
+                .map(|names| format!("set names {}", names))
+                .filter(|term| term.starts_with_ci(input))
+                .map(|term| AutocompleteSuggestion::new(term, "change the style of human names")),
+        )
         .collect()
     }
 }
@@ -105,12 +408,69 @@ impl fmt::Display for AppCommand {
             Self::About => write!(f, "about"),
             Self::Changelog => write!(f, "changelog"),
             Self::Debug => write!(f, "debug"),
+            Self::Emoji(true) => write!(f, "set emoji on"),
+            Self::Emoji(false) => write!(f, "set emoji off"),
             Self::Help => write!(f, "help"),
+            Self::HelpTopic(topic) => write!(f, "help {}", topic),
+            Self::History => write!(f, "history"),
+            Self::LastSeed => write!(f, "last seed"),
+            Self::Names(names) => write!(f, "set names {}", names),
+            Self::Repeat => write!(f, "!!"),
             Self::Roll(s) => write!(f, "roll {}", s),
+            Self::RollStats => write!(f, "roll stats"),
+            Self::StandardArray => write!(f, "roll stats standard"),
+            Self::Seed(seed) => write!(f, "seed {}", seed),
+            Self::Units(units) => write!(f, "set units {}", units),
+            Self::Version => write!(f, "version"),
         }
     }
 }
 
+/// Suggest the topics closest to one that doesn't exist, or list them all if nothing is close.
+fn help_topic_not_found(topic: &str) -> String {
+    let mut similar: Vec<(usize, &str)> = HELP_TOPICS
+        .iter()
+        .filter_map(|(names, _)| {
+            names
+                .iter()
+                .map(|name| {
+                    if name.starts_with_ci(topic) {
+                        0
+                    } else {
+                        edit_distance(name, topic)
+                    }
+                })
+                .min()
+                .filter(|&distance| distance <= 2 && distance < topic.chars().count())
+                .map(|distance| (distance, names[0]))
+        })
+        .collect();
+    similar.sort_unstable();
+
+    if similar.is_empty() {
+        format!(
+            "There is no help for \"{}\". Help is available for {}, or try `help` for an overview.",
+            topic,
+            HELP_TOPICS
+                .iter()
+                .map(|(names, _)| format!("`help {}`", names[0]))
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+    } else {
+        format!(
+            "There is no help for \"{}\". Did you mean {}?",
+            topic,
+            similar
+                .iter()
+                .take(3)
+                .map(|(_, name)| format!("`help {}`", name))
+                .collect::<Vec<_>>()
+                .join(" or "),
+        )
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -138,12 +498,70 @@ mod test {
             block_on(AppCommand::parse_input("d20", &app_meta)),
         );
 
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::Seed(1234)),
+            block_on(AppCommand::parse_input("SEED 1234", &app_meta)),
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            block_on(AppCommand::parse_input("seed potato", &app_meta)),
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::Units(Units::Metric)),
+            block_on(AppCommand::parse_input("set units Metric", &app_meta)),
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(AppCommand::HelpTopic("npc".to_string())),
+            block_on(AppCommand::parse_input("HELP NPC ", &app_meta)),
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            block_on(AppCommand::parse_input("help ", &app_meta)),
+        );
+
         assert_eq!(
             CommandMatches::default(),
             block_on(AppCommand::parse_input("potato", &app_meta)),
         );
     }
 
+    #[test]
+    fn help_topic_test() {
+        let mut app_meta = app_meta();
+
+        for (names, text) in HELP_TOPICS {
+            for name in names.iter() {
+                assert_eq!(
+                    Ok(text.trim_end().to_string()),
+                    block_on(AppCommand::HelpTopic(name.to_string()).run("", &mut app_meta)),
+                );
+            }
+        }
+
+        assert_eq!(
+            Err("There is no help for \"npcc\". Did you mean `help npc`?".to_string()),
+            block_on(AppCommand::HelpTopic("npcc".to_string()).run("", &mut app_meta)),
+        );
+
+        assert_eq!(
+            Err("There is no help for \"tabel\". Did you mean `help table`?".to_string()),
+            block_on(AppCommand::HelpTopic("tabel".to_string()).run("", &mut app_meta)),
+        );
+
+        let result = block_on(AppCommand::HelpTopic("potato".to_string()).run("", &mut app_meta));
+        assert!(
+            result.as_ref().unwrap_err().starts_with(
+                "There is no help for \"potato\". Help is available for `help alias`, "
+            ),
+            "{:?}",
+            result,
+        );
+    }
+
     #[test]
     fn autocomplete_test() {
         let app_meta = app_meta();
@@ -151,7 +569,10 @@ mod test {
         [
             ("about", "about initiative.sh"),
             ("changelog", "show latest updates"),
-            ("help", "how to use initiative.sh"),
+            ("history", "list recent commands"),
+            ("!!", "repeat the last command"),
+            ("last seed", "the seed of the last result"),
+            ("version", "show the app version"),
         ]
         .into_iter()
         .for_each(|(term, summary)| {
@@ -181,6 +602,61 @@ mod test {
             block_on(AppCommand::autocomplete("roll", &app_meta)),
         );
 
+        assert_autocomplete(
+            &[
+                ("set emoji on", "show emoji"),
+                ("set emoji off", "hide emoji"),
+            ][..],
+            block_on(AppCommand::autocomplete("set e", &app_meta)),
+        );
+
+        assert_autocomplete(
+            &[
+                ("set units imperial", "change measurement units"),
+                ("set units metric", "change measurement units"),
+            ][..],
+            block_on(AppCommand::autocomplete("set u", &app_meta)),
+        );
+
+        assert_autocomplete(
+            &[
+                ("set names arabic", "change the style of human names"),
+                ("set names celtic", "change the style of human names"),
+                ("set names common", "change the style of human names"),
+                ("set names nordic", "change the style of human names"),
+            ][..],
+            block_on(AppCommand::autocomplete("set n", &app_meta)),
+        );
+
+        assert_autocomplete(
+            &[
+                ("roll stats", "roll 4d6 for each ability"),
+                ("roll stats standard", "the standard ability array"),
+            ][..],
+            block_on(AppCommand::autocomplete("roll s", &app_meta)),
+        );
+
+        assert_autocomplete(
+            &[
+                ("help", "how to use initiative.sh"),
+                ("help [command]", "how to use a command"),
+            ][..],
+            block_on(AppCommand::autocomplete("help", &app_meta)),
+        );
+
+        assert_autocomplete(
+            &[("help [command]", "how to use a command")][..],
+            block_on(AppCommand::autocomplete("help ", &app_meta)),
+        );
+
+        assert_autocomplete(
+            &[
+                ("help note", "how to use `note`"),
+                ("help npc", "how to use `npc`"),
+            ][..],
+            block_on(AppCommand::autocomplete("help n", &app_meta)),
+        );
+
         // Debug should be excluded from the autocomplete results.
         assert_eq!(
             Vec::<AutocompleteSuggestion>::new(),
@@ -196,7 +672,19 @@ mod test {
             AppCommand::About,
             AppCommand::Changelog,
             AppCommand::Debug,
+            AppCommand::Emoji(true),
+            AppCommand::Emoji(false),
             AppCommand::Help,
+            AppCommand::HelpTopic("npc".to_string()),
+            AppCommand::History,
+            AppCommand::LastSeed,
+            AppCommand::Names(NameFlavor::Nordic),
+            AppCommand::Repeat,
+            AppCommand::RollStats,
+            AppCommand::StandardArray,
+            AppCommand::Seed(42),
+            AppCommand::Units(Units::Metric),
+            AppCommand::Version,
         ]
         .into_iter()
         .for_each(|command| {
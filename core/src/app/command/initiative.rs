@@ -0,0 +1,1192 @@
+use crate::app::{
+    AppMeta, Autocomplete, AutocompleteSuggestion, CommandMatches, ContextAwareParse, Runnable,
+};
+use crate::utils::CaseInsensitiveStr;
+use async_trait::async_trait;
+use rand::Rng;
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::fmt;
+
+/// The combatants in an encounter, kept in the order they act once initiative has been rolled.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Initiative {
+    combatants: Vec<Combatant>,
+
+    /// The index of the combatant whose turn it is.
+    turn: usize,
+
+    /// The current round, starting from 1 once initiative has been rolled.
+    round: u32,
+
+    /// Take combatants out of the order as soon as they drop to 0 HP.
+    auto_remove: bool,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Combatant {
+    name: String,
+    modifier: i32,
+
+    /// The d20 roll plus modifier, or None until initiative has been rolled.
+    total: Option<i32>,
+
+    /// Decides the order of combatants with the same total and modifier, who roll off against
+    /// each other until the tie is broken.
+    tiebreaker: u8,
+
+    /// Current and maximum hit points, if they are being tracked.
+    hp: Option<(u32, u32)>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InitiativeCommand {
+    Add {
+        name: String,
+        modifier: i32,
+        hp: Option<u32>,
+    },
+    AutoRemove(bool),
+    Clear,
+    Damage {
+        name: String,
+        amount: u32,
+    },
+    Heal {
+        name: String,
+        amount: u32,
+    },
+    List,
+    Next,
+    Prev,
+    Remove {
+        name: String,
+    },
+    Roll,
+}
+
+impl Initiative {
+    /// Add a combatant. If initiative has already been rolled, the newcomer rolls straight away
+    /// and takes their place in the order.
+    pub fn add(
+        &mut self,
+        name: &str,
+        modifier: i32,
+        hp: Option<u32>,
+        rng: &mut impl Rng,
+    ) -> Result<&Combatant, String> {
+        if self.get(name).is_some() {
+            return Err(format!(
+                "There is already a combatant named \"{}\" in the initiative order.",
+                name,
+            ));
+        }
+
+        let total = if self.is_rolled() {
+            Some(roll_d20(rng) + modifier)
+        } else {
+            None
+        };
+
+        self.combatants.push(Combatant {
+            name: name.to_string(),
+            modifier,
+            total,
+            tiebreaker: 0,
+            hp: hp.map(|hp| (hp, hp)),
+        });
+
+        if total.is_some() {
+            // Keep the turn with whoever had it, even if the newcomer goes before them.
+            let active = self.combatants[self.turn].name.clone();
+            self.sort(rng);
+            self.turn = self.position(&active).unwrap();
+        }
+
+        Ok(self.get(name).unwrap())
+    }
+
+    /// Roll d20 plus modifier for every combatant and sort them from highest to lowest.
+    pub fn roll(&mut self, rng: &mut impl Rng) {
+        for combatant in self.combatants.iter_mut() {
+            combatant.total = Some(roll_d20(rng) + combatant.modifier);
+            combatant.tiebreaker = 0;
+        }
+
+        self.sort(rng);
+        self.turn = 0;
+        self.round = 1;
+    }
+
+    /// Remove a combatant. If it was their turn, the turn passes to whoever is next.
+    pub fn remove(&mut self, name: &str) -> Result<Combatant, String> {
+        let index = self.position(name).ok_or_else(|| {
+            format!(
+                "There is no combatant named \"{}\" in the initiative order.",
+                name,
+            )
+        })?;
+        let combatant = self.combatants.remove(index);
+
+        if self.combatants.is_empty() {
+            self.turn = 0;
+            self.round = 0;
+        } else if index < self.turn {
+            self.turn -= 1;
+        } else if self.turn == self.combatants.len() {
+            // The last combatant of the round was removed on their turn, so the next round begins.
+            self.turn = 0;
+            self.round += 1;
+        }
+
+        Ok(combatant)
+    }
+
+    /// Pass the turn to the next combatant, starting a new round after the last.
+    pub fn next(&mut self) -> Result<&Combatant, String> {
+        self.check_rolled()?;
+
+        self.turn += 1;
+        if self.turn == self.combatants.len() {
+            self.turn = 0;
+            self.round += 1;
+        }
+
+        Ok(&self.combatants[self.turn])
+    }
+
+    /// Return the turn to the previous combatant, going back a round if need be.
+    pub fn prev(&mut self) -> Result<&Combatant, String> {
+        self.check_rolled()?;
+
+        if self.turn > 0 {
+            self.turn -= 1;
+        } else if self.round > 1 {
+            self.turn = self.combatants.len() - 1;
+            self.round -= 1;
+        } else {
+            return Err("It's already the first turn of the first round.".to_string());
+        }
+
+        Ok(&self.combatants[self.turn])
+    }
+
+    /// Take hit points away from a combatant, no lower than 0. Returns the combatant as they
+    /// were left, and whether they were removed from the order as a result.
+    pub fn damage(&mut self, name: &str, amount: u32) -> Result<(Combatant, bool), String> {
+        let combatant = self.get_hp_mut(name)?;
+        if let Some((current, _)) = &mut combatant.hp {
+            *current = current.saturating_sub(amount);
+        }
+        let combatant = combatant.clone();
+
+        if self.auto_remove && combatant.is_down() {
+            self.remove(name)?;
+            Ok((combatant, true))
+        } else {
+            Ok((combatant, false))
+        }
+    }
+
+    /// Restore hit points to a combatant, no higher than their maximum.
+    pub fn heal(&mut self, name: &str, amount: u32) -> Result<&Combatant, String> {
+        let combatant = self.get_hp_mut(name)?;
+        if let Some((current, max)) = &mut combatant.hp {
+            *current = current.saturating_add(amount).min(*max);
+        }
+        Ok(combatant)
+    }
+
+    pub fn set_auto_remove(&mut self, auto_remove: bool) {
+        self.auto_remove = auto_remove;
+    }
+
+    pub fn clear(&mut self) {
+        self.combatants.clear();
+        self.turn = 0;
+        self.round = 0;
+    }
+
+    /// The combatant whose turn it is, if initiative has been rolled.
+    pub fn active(&self) -> Option<&Combatant> {
+        if self.is_rolled() {
+            self.combatants.get(self.turn)
+        } else {
+            None
+        }
+    }
+
+    pub fn round(&self) -> u32 {
+        self.round
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Combatant> {
+        self.combatants
+            .iter()
+            .find(|combatant| combatant.name.eq_ci(name))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.combatants.is_empty()
+    }
+
+    pub fn is_rolled(&self) -> bool {
+        self.combatants
+            .first()
+            .and_then(|combatant| combatant.total)
+            .is_some()
+    }
+
+    pub fn combatants(&self) -> impl Iterator<Item = &Combatant> {
+        self.combatants.iter()
+    }
+
+    fn get_hp_mut(&mut self, name: &str) -> Result<&mut Combatant, String> {
+        let combatant = self
+            .combatants
+            .iter_mut()
+            .find(|combatant| combatant.name.eq_ci(name))
+            .ok_or_else(|| {
+                format!(
+                    "There is no combatant named \"{}\" in the initiative order.",
+                    name,
+                )
+            })?;
+
+        if combatant.hp.is_none() {
+            Err(format!(
+                "{} has no hit points to track. Give them some when adding them, eg. `initiative add {} 7hp`.",
+                combatant.name, combatant.name,
+            ))
+        } else {
+            Ok(combatant)
+        }
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.combatants
+            .iter()
+            .position(|combatant| combatant.name.eq_ci(name))
+    }
+
+    fn check_rolled(&self) -> Result<(), String> {
+        if self.is_empty() {
+            Err(NO_COMBATANTS.to_string())
+        } else if !self.is_rolled() {
+            Err(
+                "Initiative hasn't been rolled yet. Use `initiative roll` once everyone is in."
+                    .to_string(),
+            )
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Order by total, then by modifier, then by whoever wins a roll-off.
+    fn sort(&mut self, rng: &mut impl Rng) {
+        let key = |combatant: &Combatant| (combatant.total, combatant.modifier);
+        self.combatants
+            .sort_by_key(|combatant| Reverse(key(combatant)));
+
+        let mut start = 0;
+        while start < self.combatants.len() {
+            let len = self.combatants[start..]
+                .iter()
+                .take_while(|combatant| key(combatant) == key(&self.combatants[start]))
+                .count();
+            let tied = &mut self.combatants[start..start + len];
+
+            // A tiebreaker of 0 belongs to a newcomer who hasn't taken part in the roll-off yet.
+            if tied.len() > 1
+                && (tied.iter().any(|c| c.tiebreaker == 0)
+                    || !all_distinct(tied.iter().map(|c| c.tiebreaker)))
+            {
+                roll_off(tied, rng);
+            }
+
+            tied.sort_by_key(|combatant| Reverse(combatant.tiebreaker));
+            start += len;
+        }
+    }
+}
+
+impl Combatant {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn total(&self) -> Option<i32> {
+        self.total
+    }
+
+    pub fn hp(&self) -> Option<(u32, u32)> {
+        self.hp
+    }
+
+    /// True if the combatant's hit points are tracked and have dropped to 0.
+    pub fn is_down(&self) -> bool {
+        matches!(self.hp, Some((0, _)))
+    }
+}
+
+/// The largest initiative modifier, positive or negative, that a combatant can be given. Even
+/// legendary monsters don't come close, and it keeps the rolled totals from overflowing.
+const MAX_MODIFIER: i32 = 30;
+
+const NO_COMBATANTS: &str =
+    "There is no one in the initiative order yet. Add someone with `initiative add [name] [modifier]`.";
+
+fn roll_d20(rng: &mut impl Rng) -> i32 {
+    rng.gen_range(1..=20)
+}
+
+/// True for a word shaped like a modifier, eg. `+2`, whether or not it's in range.
+fn is_modifier_word(word: &str) -> bool {
+    word.strip_prefix(['+', '-'])
+        .is_some_and(|digits| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// True for a word shaped like hit points, eg. `7hp`, whether or not it's in range.
+fn is_hp_word(word: &str) -> bool {
+    word.strip_suffix_ci("hp")
+        .is_some_and(|digits| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Reroll the tiebreakers until they all differ. More than 20 combatants can't all roll
+/// differently on a d20, so give up eventually and leave the rest in the order they were added.
+fn roll_off(tied: &mut [Combatant], rng: &mut impl Rng) {
+    for _ in 0..100 {
+        for combatant in tied.iter_mut() {
+            combatant.tiebreaker = roll_d20(rng) as u8;
+        }
+
+        if all_distinct(tied.iter().map(|c| c.tiebreaker)) {
+            return;
+        }
+    }
+}
+
+fn all_distinct(values: impl Iterator<Item = u8>) -> bool {
+    let mut seen = HashSet::new();
+    values.into_iter().all(|value| seen.insert(value))
+}
+
+impl fmt::Display for Initiative {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_rolled() {
+            writeln!(f, "# Initiative: round {}", self.round)?;
+        } else {
+            writeln!(f, "# Initiative")?;
+        }
+
+        for (i, combatant) in self.combatants.iter().enumerate() {
+            if let Some(total) = combatant.total {
+                // The combatant whose turn it is stands out in bold.
+                let strong = if i == self.turn { "**" } else { "" };
+
+                write!(
+                    f,
+                    "\n{}. {}{}: {} ({:+}){}",
+                    i + 1,
+                    strong,
+                    combatant.name,
+                    total,
+                    combatant.modifier,
+                    strong,
+                )?;
+            } else {
+                write!(f, "\n* {} ({:+})", combatant.name, combatant.modifier)?;
+            }
+
+            if let Some((current, max)) = combatant.hp {
+                write!(f, ", {}/{} HP", current, max)?;
+
+                if current == 0 {
+                    write!(f, " (down)")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait(?Send)]
+impl Runnable for InitiativeCommand {
+    async fn run(self, _input: &str, app_meta: &mut AppMeta) -> Result<String, String> {
+        let initiative = &mut app_meta.initiative;
+
+        match self {
+            Self::Add { name, modifier, hp } => {
+                // The parser only takes a modifier or hit points off the end of the name if they
+                // are in range, so anything shaped like one that's left over is out of range.
+                if let Some((_, word)) = name.rsplit_once(' ') {
+                    if is_modifier_word(word) {
+                        return Err(format!(
+                            "\"{}\" is not a valid modifier. Modifiers must be between -{} and +{}.",
+                            word, MAX_MODIFIER, MAX_MODIFIER,
+                        ));
+                    } else if is_hp_word(word) {
+                        return Err(format!(
+                            "\"{}\" is not a valid number of hit points. Hit points must be between 1 and {}.",
+                            word,
+                            u32::MAX,
+                        ));
+                    }
+                }
+
+                let combatant = initiative.add(&name, modifier, hp, &mut app_meta.rng)?;
+
+                let message = if let Some(total) = combatant.total() {
+                    format!(
+                        "{} rolled {} and was added to the initiative order.",
+                        combatant.name(),
+                        total,
+                    )
+                } else {
+                    format!(
+                        "{} was added to the initiative order. Use `initiative roll` once everyone is in.",
+                        combatant.name(),
+                    )
+                };
+
+                Ok(format!("{}\n\n_{}_", initiative, message))
+            }
+            Self::AutoRemove(auto_remove) => {
+                initiative.set_auto_remove(auto_remove);
+
+                Ok(if auto_remove {
+                    "Combatants will be removed from the initiative order when they drop to 0 HP."
+                } else {
+                    "Combatants at 0 HP will stay in the initiative order."
+                }
+                .to_string())
+            }
+            Self::Damage { name, amount } => {
+                let message = match initiative.damage(&name, amount)? {
+                    (combatant, true) => format!(
+                        "{} took {} damage and was removed from the initiative order.",
+                        combatant.name(),
+                        amount,
+                    ),
+                    (combatant, false) if combatant.is_down() => {
+                        format!("{} took {} damage and is down.", combatant.name(), amount)
+                    }
+                    (combatant, false) => {
+                        let (current, max) = combatant.hp().unwrap();
+                        format!(
+                            "{} took {} damage and has {}/{} HP left.",
+                            combatant.name(),
+                            amount,
+                            current,
+                            max,
+                        )
+                    }
+                };
+
+                if initiative.is_empty() {
+                    Ok(message)
+                } else {
+                    Ok(format!("{}\n\n_{}_", initiative, message))
+                }
+            }
+            Self::Heal { name, amount } => {
+                let combatant = initiative.heal(&name, amount)?;
+                let (current, max) = combatant.hp().unwrap();
+                let message = format!(
+                    "{} was healed for {} and has {}/{} HP.",
+                    combatant.name(),
+                    amount,
+                    current,
+                    max,
+                );
+
+                Ok(format!("{}\n\n_{}_", initiative, message))
+            }
+            Self::Clear => {
+                initiative.clear();
+                Ok("The initiative order was cleared.".to_string())
+            }
+            Self::List if initiative.is_empty() => Ok(format!("*{}*", NO_COMBATANTS)),
+            Self::List if !initiative.is_rolled() => Ok(format!(
+                "{}\n\n_Use `initiative roll` once everyone is in._",
+                initiative,
+            )),
+            Self::List => Ok(initiative.to_string()),
+            Self::Next | Self::Prev => {
+                let combatant = if self == Self::Next {
+                    initiative.next()?
+                } else {
+                    initiative.prev()?
+                };
+                let message = format!("It's {}'s turn.", combatant.name());
+
+                Ok(format!("{}\n\n_{}_", initiative, message))
+            }
+            Self::Remove { name } => {
+                let combatant = initiative.remove(&name)?;
+                let message = format!(
+                    "{} was removed from the initiative order.",
+                    combatant.name()
+                );
+
+                if initiative.is_empty() {
+                    Ok(message)
+                } else {
+                    Ok(format!("{}\n\n_{}_", initiative, message))
+                }
+            }
+            Self::Roll if initiative.is_empty() => Err(NO_COMBATANTS.to_string()),
+            Self::Roll => {
+                initiative.roll(&mut app_meta.rng);
+                Ok(initiative.to_string())
+            }
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl ContextAwareParse for InitiativeCommand {
+    async fn parse_input(input: &str, _app_meta: &AppMeta) -> CommandMatches<Self> {
+        if let Some(rest) = input
+            .strip_prefix_ci("initiative add ")
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+        {
+            // A trailing +2 or -1 is the modifier and 7hp the hit points, in either order.
+            // Anything before them is the name.
+            let (mut name, mut modifier, mut hp) = (rest, None, None);
+
+            while let Some((start, word)) = name.rsplit_once(' ') {
+                if let (None, Ok(value)) = (modifier, word.parse::<i32>()) {
+                    if word.starts_with(['+', '-']) && value.abs() <= MAX_MODIFIER {
+                        modifier = Some(value);
+                        name = start.trim_end();
+                        continue;
+                    }
+                }
+
+                if let (None, Some(Ok(value))) = (
+                    hp,
+                    word.strip_suffix_ci("hp").map(|value| value.parse::<u32>()),
+                ) {
+                    if value > 0 {
+                        hp = Some(value);
+                        name = start.trim_end();
+                        continue;
+                    }
+                }
+
+                break;
+            }
+
+            CommandMatches::new_canonical(Self::Add {
+                name: name.to_string(),
+                modifier: modifier.unwrap_or_default(),
+                hp,
+            })
+        } else if let Some((name, amount)) = input
+            .strip_prefix_ci("damage ")
+            .and_then(|s| s.trim().rsplit_once(' '))
+            .and_then(|(name, amount)| Some((name.trim(), amount.parse().ok()?)))
+            .filter(|(name, _)| !name.is_empty())
+        {
+            CommandMatches::new_canonical(Self::Damage {
+                name: name.to_string(),
+                amount,
+            })
+        } else if let Some((name, amount)) = input
+            .strip_prefix_ci("heal ")
+            .and_then(|s| s.trim().rsplit_once(' '))
+            .and_then(|(name, amount)| Some((name.trim(), amount.parse().ok()?)))
+            .filter(|(name, _)| !name.is_empty())
+        {
+            CommandMatches::new_canonical(Self::Heal {
+                name: name.to_string(),
+                amount,
+            })
+        } else if input.eq_ci("initiative auto-remove on") {
+            CommandMatches::new_canonical(Self::AutoRemove(true))
+        } else if input.eq_ci("initiative auto-remove off") {
+            CommandMatches::new_canonical(Self::AutoRemove(false))
+        } else if let Some(name) = input
+            .strip_prefix_ci("initiative remove ")
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+        {
+            CommandMatches::new_canonical(Self::Remove {
+                name: name.to_string(),
+            })
+        } else if input.eq_ci("initiative next") {
+            CommandMatches::new_canonical(Self::Next)
+        } else if input.eq_ci("initiative prev") {
+            CommandMatches::new_canonical(Self::Prev)
+        } else if input.eq_ci("initiative roll") || input.eq_ci("roll initiative") {
+            CommandMatches::new_canonical(Self::Roll)
+        } else if input.eq_ci("initiative list") || input.eq_ci("initiative") {
+            CommandMatches::new_canonical(Self::List)
+        } else if input.eq_ci("initiative clear") {
+            CommandMatches::new_canonical(Self::Clear)
+        } else {
+            CommandMatches::default()
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl Autocomplete for InitiativeCommand {
+    async fn autocomplete(input: &str, _app_meta: &AppMeta) -> Vec<AutocompleteSuggestion> {
+        if input.is_empty() {
+            return Vec::new();
+        }
+
+        [
+            ("damage [name] [amount]", "reduce a combatant's hit points"),
+            ("heal [name] [amount]", "restore a combatant's hit points"),
+            (
+                "initiative add [name] [modifier]",
+                "add to the initiative order",
+            ),
+            (
+                "initiative auto-remove [on|off]",
+                "remove combatants at 0 HP",
+            ),
+            ("initiative clear", "end the encounter"),
+            ("initiative list", "show the initiative order"),
+            ("initiative next", "pass the turn to the next combatant"),
+            ("initiative prev", "go back to the previous turn"),
+            (
+                "initiative remove [name]",
+                "remove from the initiative order",
+            ),
+            ("initiative roll", "roll initiative for everyone"),
+        ]
+        .into_iter()
+        .filter(|(term, _)| term.starts_with_ci(input))
+        .map(|(term, summary)| AutocompleteSuggestion::new(term, summary))
+        .collect()
+    }
+}
+
+impl fmt::Display for InitiativeCommand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::Add {
+                name,
+                modifier,
+                hp: Some(hp),
+            } => write!(f, "initiative add {} {}hp {:+}", name, hp, modifier),
+            Self::Add {
+                name,
+                modifier,
+                hp: None,
+            } => write!(f, "initiative add {} {:+}", name, modifier),
+            Self::AutoRemove(true) => write!(f, "initiative auto-remove on"),
+            Self::AutoRemove(false) => write!(f, "initiative auto-remove off"),
+            Self::Clear => write!(f, "initiative clear"),
+            Self::Damage { name, amount } => write!(f, "damage {} {}", name, amount),
+            Self::Heal { name, amount } => write!(f, "heal {} {}", name, amount),
+            Self::List => write!(f, "initiative list"),
+            Self::Next => write!(f, "initiative next"),
+            Self::Prev => write!(f, "initiative prev"),
+            Self::Remove { name } => write!(f, "initiative remove {}", name),
+            Self::Roll => write!(f, "initiative roll"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::app::assert_autocomplete;
+    use crate::storage::NullDataStore;
+    use crate::Event;
+    use rand::prelude::*;
+    use tokio_test::block_on;
+
+    #[test]
+    fn roll_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut initiative = Initiative::default();
+        initiative.add("Goblin", 2, None, &mut rng).unwrap();
+        initiative.add("Bugbear", 1, None, &mut rng).unwrap();
+        initiative.add("Wolf", 2, None, &mut rng).unwrap();
+        assert!(!initiative.is_rolled());
+
+        for _ in 0..50 {
+            initiative.roll(&mut rng);
+
+            let totals: Vec<_> = initiative.combatants().map(|c| c.total.unwrap()).collect();
+            assert!(
+                totals.windows(2).all(|pair| pair[0] >= pair[1]),
+                "{:?}",
+                totals
+            );
+
+            for combatant in initiative.combatants() {
+                let roll = combatant.total.unwrap() - combatant.modifier;
+                assert!((1..=20).contains(&roll), "{:?}", combatant);
+            }
+        }
+    }
+
+    #[test]
+    fn roll_test_ties() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut initiative = Initiative::default();
+
+        for name in ["Goblin", "Bugbear", "Wolf", "Orc"] {
+            initiative.combatants.push(Combatant {
+                name: name.to_string(),
+                modifier: if name == "Bugbear" { 3 } else { 1 },
+                total: Some(12),
+                tiebreaker: 0,
+                hp: None,
+            });
+        }
+
+        initiative.sort(&mut rng);
+
+        // The higher modifier goes first, then the rest roll off.
+        let combatants: Vec<_> = initiative.combatants().collect();
+        assert_eq!("Bugbear", combatants[0].name);
+        assert!(
+            combatants[1..]
+                .windows(2)
+                .all(|pair| pair[0].tiebreaker > pair[1].tiebreaker),
+            "{:?}",
+            combatants,
+        );
+
+        // Sorting again doesn't disturb a tie that has already been broken.
+        let order: Vec<_> = initiative.combatants().map(|c| c.name.clone()).collect();
+        initiative.sort(&mut rng);
+        assert_eq!(
+            order,
+            initiative
+                .combatants()
+                .map(|c| c.name.clone())
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn add_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut initiative = Initiative::default();
+
+        assert_eq!(
+            None,
+            initiative.add("Goblin", 2, None, &mut rng).unwrap().total
+        );
+        assert_eq!(
+            Err(
+                "There is already a combatant named \"GOBLIN\" in the initiative order."
+                    .to_string()
+            ),
+            initiative.add("GOBLIN", 2, None, &mut rng).map(|_| ()),
+        );
+
+        initiative.roll(&mut rng);
+        assert!(initiative
+            .add("Wolf", 2, None, &mut rng)
+            .unwrap()
+            .total
+            .is_some());
+        assert_eq!(2, initiative.combatants().count());
+
+        initiative.clear();
+        assert!(initiative.is_empty());
+    }
+
+    #[test]
+    fn next_prev_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut initiative = Initiative::default();
+        initiative.add("Goblin", 2, None, &mut rng).unwrap();
+
+        assert_eq!(
+            Err(
+                "Initiative hasn't been rolled yet. Use `initiative roll` once everyone is in."
+                    .to_string()
+            ),
+            initiative.next().map(|_| ()),
+        );
+
+        initiative.add("Bugbear", 1, None, &mut rng).unwrap();
+        initiative.roll(&mut rng);
+        let names: Vec<String> = initiative.combatants().map(|c| c.name.clone()).collect();
+        assert_eq!((0, 1), (initiative.turn, initiative.round));
+
+        assert_eq!(
+            Err("It's already the first turn of the first round.".to_string()),
+            initiative.prev().map(|_| ()),
+        );
+
+        assert_eq!(names[1], initiative.next().unwrap().name);
+        assert_eq!((1, 1), (initiative.turn, initiative.round));
+
+        // Advancing past the last combatant starts a new round.
+        assert_eq!(names[0], initiative.next().unwrap().name);
+        assert_eq!((0, 2), (initiative.turn, initiative.round));
+
+        assert_eq!(names[1], initiative.prev().unwrap().name);
+        assert_eq!((1, 1), (initiative.turn, initiative.round));
+    }
+
+    #[test]
+    fn remove_test() {
+        let mut initiative = Initiative::default();
+        for (i, name) in ["Goblin", "Bugbear", "Wolf", "Orc"].into_iter().enumerate() {
+            initiative.combatants.push(Combatant {
+                name: name.to_string(),
+                modifier: 0,
+                total: Some(20 - i as i32),
+                tiebreaker: 0,
+                hp: None,
+            });
+        }
+        initiative.round = 1;
+        initiative.turn = 2;
+
+        // Removing someone earlier in the order keeps the turn with Wolf.
+        initiative.remove("goblin").unwrap();
+        assert_eq!("Wolf", initiative.active().unwrap().name);
+
+        // Removing the active combatant passes the turn along.
+        initiative.remove("Wolf").unwrap();
+        assert_eq!("Orc", initiative.active().unwrap().name);
+        assert_eq!(1, initiative.round);
+
+        // ...to the top of the next round if they were last.
+        initiative.remove("Orc").unwrap();
+        assert_eq!("Bugbear", initiative.active().unwrap().name);
+        assert_eq!(2, initiative.round);
+
+        assert_eq!(
+            Err("There is no combatant named \"Orc\" in the initiative order.".to_string()),
+            initiative.remove("Orc").map(|_| ()),
+        );
+
+        initiative.remove("Bugbear").unwrap();
+        assert_eq!(None, initiative.active());
+        assert_eq!(0, initiative.round);
+    }
+
+    #[test]
+    fn add_test_keeps_turn() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut initiative = Initiative::default();
+        initiative.add("Goblin", -5, None, &mut rng).unwrap();
+        initiative.roll(&mut rng);
+
+        // Wolf can't roll lower than Goblin's best possible total, so goes first.
+        initiative.add("Wolf", 20, None, &mut rng).unwrap();
+        assert_eq!("Wolf", initiative.combatants[0].name);
+        assert_eq!("Goblin", initiative.active().unwrap().name);
+    }
+
+    #[test]
+    fn damage_heal_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut initiative = Initiative::default();
+        initiative.add("Goblin", 2, Some(7), &mut rng).unwrap();
+        initiative.add("Wolf", 2, None, &mut rng).unwrap();
+
+        let (goblin, removed) = initiative.damage("goblin", 3).unwrap();
+        assert_eq!((Some((4, 7)), false), (goblin.hp, removed));
+
+        // Healing can't go above the maximum, nor damage below 0.
+        assert_eq!(Some((7, 7)), initiative.heal("Goblin", 5).unwrap().hp);
+        let (goblin, removed) = initiative.damage("Goblin", 10).unwrap();
+        assert_eq!((Some((0, 7)), false), (goblin.hp, removed));
+        assert!(goblin.is_down());
+
+        assert_eq!(
+            Err("Wolf has no hit points to track. Give them some when adding them, eg. `initiative add Wolf 7hp`.".to_string()),
+            initiative.damage("wolf", 1).map(|_| ()),
+        );
+        assert_eq!(
+            Err("There is no combatant named \"Orc\" in the initiative order.".to_string()),
+            initiative.heal("Orc", 1).map(|_| ()),
+        );
+
+        initiative.set_auto_remove(true);
+        initiative.heal("Goblin", 1).unwrap();
+        let (goblin, removed) = initiative.damage("Goblin", 1).unwrap();
+        assert_eq!((Some((0, 7)), true), (goblin.hp, removed));
+        assert_eq!(None, initiative.get("Goblin"));
+
+        // The setting outlasts the encounter.
+        initiative.clear();
+        assert!(initiative.auto_remove);
+    }
+
+    #[test]
+    fn display_initiative_test() {
+        let mut initiative = Initiative::default();
+        initiative.combatants.push(Combatant {
+            name: "Goblin".to_string(),
+            modifier: 2,
+            total: None,
+            tiebreaker: 0,
+            hp: None,
+        });
+        initiative.combatants.push(Combatant {
+            name: "Bugbear".to_string(),
+            modifier: -1,
+            total: None,
+            tiebreaker: 0,
+            hp: None,
+        });
+
+        assert_eq!(
+            "# Initiative\n\n* Goblin (+2)\n* Bugbear (-1)",
+            initiative.to_string(),
+        );
+
+        initiative.combatants[1].hp = Some((0, 12));
+        assert_eq!(
+            "# Initiative\n\n* Goblin (+2)\n* Bugbear (-1), 0/12 HP (down)",
+            initiative.to_string(),
+        );
+        initiative.combatants[1].hp = None;
+
+        initiative.combatants[0].hp = Some((4, 7));
+        initiative.combatants[0].total = Some(17);
+        initiative.combatants[1].total = Some(4);
+        initiative.round = 3;
+        initiative.turn = 1;
+        assert_eq!(
+            "# Initiative: round 3\n\n1. Goblin: 17 (+2), 4/7 HP\n2. **Bugbear: 4 (-1)**",
+            initiative.to_string(),
+        );
+    }
+
+    #[test]
+    fn run_test() {
+        let mut app_meta = app_meta();
+
+        assert_eq!(
+            Err("There is no one in the initiative order yet. Add someone with `initiative add [name] [modifier]`.".to_string()),
+            block_on(InitiativeCommand::Roll.run("", &mut app_meta)),
+        );
+
+        assert_eq!(
+            Ok("# Initiative\n\n* Goblin (+2)\n\n_Goblin was added to the initiative order. Use `initiative roll` once everyone is in._".to_string()),
+            block_on(
+                InitiativeCommand::Add {
+                    name: "Goblin".to_string(),
+                    modifier: 2,
+                    hp: None,
+                }
+                .run("", &mut app_meta)
+            ),
+        );
+
+        let output = block_on(InitiativeCommand::Roll.run("", &mut app_meta)).unwrap();
+        assert!(
+            output.starts_with("# Initiative: round 1\n\n1. **Goblin: "),
+            "{}",
+            output,
+        );
+        assert_eq!(
+            Ok(output),
+            block_on(InitiativeCommand::List.run("", &mut app_meta)),
+        );
+
+        assert_eq!(
+            Ok("The initiative order was cleared.".to_string()),
+            block_on(InitiativeCommand::Clear.run("", &mut app_meta)),
+        );
+        assert!(app_meta.initiative.is_empty());
+    }
+
+    #[test]
+    fn parse_input_test() {
+        let app_meta = app_meta();
+
+        assert_eq!(
+            CommandMatches::new_canonical(InitiativeCommand::Add {
+                name: "Goblin Boss".to_string(),
+                modifier: 0,
+                hp: None,
+            }),
+            block_on(InitiativeCommand::parse_input(
+                "initiative add Goblin Boss",
+                &app_meta
+            )),
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(InitiativeCommand::Add {
+                name: "Goblin 2".to_string(),
+                modifier: -1,
+                hp: None,
+            }),
+            block_on(InitiativeCommand::parse_input(
+                "initiative add Goblin 2 -1",
+                &app_meta
+            )),
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(InitiativeCommand::Add {
+                name: "Goblin".to_string(),
+                modifier: 2,
+                hp: Some(7),
+            }),
+            block_on(InitiativeCommand::parse_input(
+                "initiative add Goblin 7HP +2",
+                &app_meta
+            )),
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(InitiativeCommand::Add {
+                name: "Goblin".to_string(),
+                modifier: 2,
+                hp: Some(7),
+            }),
+            block_on(InitiativeCommand::parse_input(
+                "initiative add Goblin +2 7hp",
+                &app_meta
+            )),
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(InitiativeCommand::Add {
+                name: "Goblin 0hp".to_string(),
+                modifier: 0,
+                hp: None,
+            }),
+            block_on(InitiativeCommand::parse_input(
+                "initiative add Goblin 0hp",
+                &app_meta
+            )),
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(InitiativeCommand::Damage {
+                name: "Goblin Boss".to_string(),
+                amount: 3,
+            }),
+            block_on(InitiativeCommand::parse_input(
+                "damage Goblin Boss 3",
+                &app_meta
+            )),
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            block_on(InitiativeCommand::parse_input("heal Goblin", &app_meta)),
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(InitiativeCommand::Roll),
+            block_on(InitiativeCommand::parse_input("roll initiative", &app_meta)),
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(InitiativeCommand::List),
+            block_on(InitiativeCommand::parse_input("initiative", &app_meta)),
+        );
+
+        assert_eq!(
+            CommandMatches::default(),
+            block_on(InitiativeCommand::parse_input("initiative add ", &app_meta)),
+        );
+    }
+
+    #[test]
+    fn autocomplete_test() {
+        let app_meta = app_meta();
+
+        assert_autocomplete(
+            &[
+                (
+                    "initiative add [name] [modifier]",
+                    "add to the initiative order",
+                ),
+                (
+                    "initiative auto-remove [on|off]",
+                    "remove combatants at 0 HP",
+                ),
+                ("initiative clear", "end the encounter"),
+                ("initiative list", "show the initiative order"),
+                ("initiative next", "pass the turn to the next combatant"),
+                ("initiative prev", "go back to the previous turn"),
+                (
+                    "initiative remove [name]",
+                    "remove from the initiative order",
+                ),
+                ("initiative roll", "roll initiative for everyone"),
+            ][..],
+            block_on(InitiativeCommand::autocomplete("INIT", &app_meta)),
+        );
+
+        assert_autocomplete(
+            &[("initiative roll", "roll initiative for everyone")][..],
+            block_on(InitiativeCommand::autocomplete("initiative ro", &app_meta)),
+        );
+
+        assert_autocomplete(
+            &[("damage [name] [amount]", "reduce a combatant's hit points")][..],
+            block_on(InitiativeCommand::autocomplete("dam", &app_meta)),
+        );
+    }
+
+    #[test]
+    fn display_test() {
+        let app_meta = app_meta();
+
+        [
+            InitiativeCommand::Add {
+                name: "Goblin".to_string(),
+                modifier: 2,
+                hp: None,
+            },
+            InitiativeCommand::Add {
+                name: "Bugbear".to_string(),
+                modifier: -1,
+                hp: Some(12),
+            },
+            InitiativeCommand::AutoRemove(true),
+            InitiativeCommand::AutoRemove(false),
+            InitiativeCommand::Clear,
+            InitiativeCommand::Damage {
+                name: "Goblin".to_string(),
+                amount: 3,
+            },
+            InitiativeCommand::Heal {
+                name: "Goblin".to_string(),
+                amount: 2,
+            },
+            InitiativeCommand::List,
+            InitiativeCommand::Next,
+            InitiativeCommand::Prev,
+            InitiativeCommand::Remove {
+                name: "Goblin".to_string(),
+            },
+            InitiativeCommand::Roll,
+        ]
+        .into_iter()
+        .for_each(|command| {
+            let command_string = command.to_string();
+            assert_ne!("", command_string);
+
+            assert_eq!(
+                CommandMatches::new_canonical(command),
+                block_on(InitiativeCommand::parse_input(&command_string, &app_meta)),
+                "{}",
+                command_string,
+            );
+        });
+    }
+
+    fn event_dispatcher(_event: Event) {}
+
+    fn app_meta() -> AppMeta {
+        AppMeta::new(NullDataStore, &event_dispatcher)
+    }
+}
@@ -1,27 +1,159 @@
 pub use alias::CommandAlias;
 pub use app::AppCommand;
+pub use initiative::{Initiative, InitiativeCommand};
+pub use roll::DiceFormula;
 pub use runnable::{
     Autocomplete, AutocompleteSuggestion, CommandMatches, ContextAwareParse, Runnable,
 };
+pub use table::{RandomTable, TableCommand};
 pub use tutorial::TutorialCommand;
+pub use user_alias::UserAliasCommand;
 
 #[cfg(test)]
 pub use runnable::assert_autocomplete;
 
 mod alias;
 mod app;
+mod initiative;
+mod roll;
 mod runnable;
+mod table;
 mod tutorial;
+mod user_alias;
 
 use super::AppMeta;
 use crate::reference::ReferenceCommand;
 use crate::storage::StorageCommand;
 use crate::time::TimeCommand;
-use crate::world::WorldCommand;
+use crate::utils::edit_distance;
+use crate::world::npc::Species;
+use crate::world::place::PlaceType;
+use crate::world::{Npc, Place, WorldCommand};
 use async_trait::async_trait;
 use futures::join;
 use std::fmt;
 
+/// The top-level commands, with a short description of each, for use in help menus. The
+/// descriptions match those offered by autocomplete.
+pub const COMMANDS: &[(&str, &str)] = &[
+    ("!!", "repeat the last command"),
+    ("+[number]", "advance time"),
+    ("-[number]", "rewind time"),
+    ("Open Game License", "SRD license"),
+    ("about", "about initiative.sh"),
+    ("alias [name] = [command]", "create a shortcut"),
+    ("alias list", "show your shortcuts"),
+    ("caster [class] [level]", "SRD spell slots"),
+    ("changelog", "show latest updates"),
+    ("clear world", "delete everything and start over"),
+    ("conditions", "SRD index"),
+    ("damage [name] [amount]", "reduce a combatant's hit points"),
+    ("delete [name]", "remove an entry from journal"),
+    ("describe [character]", "describe a character in prose"),
+    ("export", "export the journal contents"),
+    ("generate [thing]", "create anything, eg. generate tavern"),
+    (
+        "generate region [type]",
+        "create a region of a chosen type, eg. forest",
+    ),
+    ("generate town", "create a town with places and people"),
+    ("generate treasure [CR]", "coins and magic items"),
+    ("heal [name] [amount]", "restore a combatant's hit points"),
+    ("help", "how to use initiative.sh"),
+    ("help [command]", "how to use a command"),
+    ("history", "list recent commands"),
+    ("import", "import a journal backup"),
+    (
+        "import overwrite",
+        "import a backup, replacing existing entries",
+    ),
+    (
+        "initiative add [name] [modifier]",
+        "add to the initiative order",
+    ),
+    (
+        "initiative auto-remove [on|off]",
+        "remove combatants at 0 HP",
+    ),
+    ("initiative clear", "end the encounter"),
+    ("initiative list", "show the initiative order"),
+    ("initiative next", "pass the turn to the next combatant"),
+    ("initiative prev", "go back to the previous turn"),
+    (
+        "initiative remove [name]",
+        "remove from the initiative order",
+    ),
+    ("initiative roll", "roll initiative for everyone"),
+    ("item categories", "SRD index"),
+    ("journal", "list journal contents"),
+    ("last seed", "the seed of the last result"),
+    ("list [npcs|places|regions]", "list journal entries by type"),
+    ("list tag [tag]", "list journal entries with a tag"),
+    ("load [name]", "load an entry"),
+    ("locations in [region]", "list the locations in a region"),
+    ("magic items", "SRD index"),
+    ("monsters", "SRD index"),
+    (
+        "note add [name]: [note]",
+        "add a note to a character or place",
+    ),
+    (
+        "note set [name]: [note]",
+        "replace the notes on a character or place",
+    ),
+    ("notes [name]", "show the notes on a character or place"),
+    ("now", "get the current time"),
+    ("npc", "create person"),
+    ("place", "create place"),
+    ("put [name] in [place]", "move something into a place"),
+    ("recent", "list recently generated things"),
+    ("redo", "redo the last change that was undone"),
+    (
+        "relate [name] to [name] as [relationship]",
+        "record how two characters are related",
+    ),
+    (
+        "rename [name] to [new name]",
+        "change the name of something",
+    ),
+    ("reroll [field]", "regenerate part of the last result"),
+    ("roll [dice]", "roll eg. 8d6 or d20+3"),
+    ("roll percentile", "roll d100"),
+    ("roll stats", "roll 4d6 for each ability"),
+    ("roll stats standard", "the standard ability array"),
+    ("roll table [name]", "roll on a custom table"),
+    ("save [name]", "save an entry to journal"),
+    ("search [name]", "find journal entries by name"),
+    ("seed [number]", "make results repeatable"),
+    (
+        "set demographics [species] [weight]...",
+        "eg. human 50 elf 50",
+    ),
+    ("set emoji off", "hide emoji"),
+    ("set emoji on", "show emoji"),
+    ("set names arabic", "change the style of human names"),
+    ("set names celtic", "change the style of human names"),
+    ("set names common", "change the style of human names"),
+    ("set names nordic", "change the style of human names"),
+    ("set units imperial", "change measurement units"),
+    ("set units metric", "change measurement units"),
+    ("show demographics", "current species weights"),
+    ("spells", "SRD index"),
+    ("table create [name]: [entries]", "create a random table"),
+    ("tag [name] [tags]", "tag a character or place"),
+    ("tutorial", "feature walkthrough"),
+    ("unalias [name]", "remove a shortcut"),
+    ("undo", "undo the last change"),
+    (
+        "untag [name] [tags]",
+        "remove tags from a character or place",
+    ),
+    ("version", "show the app version"),
+    ("what is in [place]", "show the places inside a place"),
+    ("who is at [place]", "list characters at a place"),
+    ("world stats", "count what's in your journal"),
+];
+
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Command {
     matches: CommandMatches<CommandType>,
@@ -44,14 +176,22 @@ impl Command {
         }
     }
 
+    /// True if the input wasn't recognized as any command at all.
+    pub fn is_empty(&self) -> bool {
+        self.matches.canonical_match.is_none() && self.matches.fuzzy_matches.is_empty()
+    }
+
     pub async fn parse_input_irrefutable(input: &str, app_meta: &AppMeta) -> Self {
         let parse_results = join!(
             CommandAlias::parse_input(input, app_meta),
             AppCommand::parse_input(input, app_meta),
+            InitiativeCommand::parse_input(input, app_meta),
             ReferenceCommand::parse_input(input, app_meta),
             StorageCommand::parse_input(input, app_meta),
+            TableCommand::parse_input(input, app_meta),
             TimeCommand::parse_input(input, app_meta),
             TutorialCommand::parse_input(input, app_meta),
+            UserAliasCommand::parse_input(input, app_meta),
             WorldCommand::parse_input(input, app_meta),
         );
 
@@ -62,7 +202,10 @@ impl Command {
             .union(parse_results.3)
             .union(parse_results.4)
             .union(parse_results.5)
-            .union(parse_results.6);
+            .union(parse_results.6)
+            .union(parse_results.7)
+            .union(parse_results.8)
+            .union(parse_results.9);
 
         // While it is normally a fatal error to encounter two command subtypes claiming canonical
         // matches on a given input, the exception is where aliases are present. In this case, we
@@ -123,7 +266,22 @@ impl Runnable for Command {
             }
         } else {
             match &self.matches.fuzzy_matches.len() {
-                0 => Err(format!("Unknown command: \"{}\"", input)),
+                0 => {
+                    let similar = similar_words(input);
+                    if similar.is_empty() {
+                        Err(format!("Unknown command: \"{}\"", input))
+                    } else {
+                        Err(format!(
+                            "Unknown command: \"{}\". Did you mean {}?",
+                            input,
+                            similar
+                                .iter()
+                                .map(|word| format!("`{}`", word))
+                                .collect::<Vec<_>>()
+                                .join(" or "),
+                        ))
+                    }
+                }
                 1 => {
                     let mut fuzzy_matches = self.matches.fuzzy_matches;
                     fuzzy_matches.pop().unwrap().run(input, app_meta).await
@@ -147,6 +305,36 @@ impl Runnable for Command {
     }
 }
 
+/// Find the command words closest to an unrecognized input, eg. "npcc" => "npc". Only words within
+/// two typos are considered (one for very short inputs), since anything further is more likely to
+/// be noise than a helpful guess.
+fn similar_words(input: &str) -> Vec<&'static str> {
+    let input = input.trim();
+    let max_distance = (input.chars().count().saturating_sub(1) / 2).min(2);
+
+    let mut words: Vec<(usize, &str)> = COMMANDS
+        .iter()
+        .map(|(term, _)| term.split('[').next().unwrap().trim_end())
+        .chain(Npc::get_words().iter().copied())
+        .chain(Place::get_words().iter().copied())
+        .chain(PlaceType::get_words())
+        .chain(Species::get_words())
+        .filter(|word| word.chars().any(char::is_alphabetic))
+        .map(|word| (edit_distance(word, input), word))
+        .filter(|&(distance, _)| distance > 0 && distance <= max_distance)
+        .collect();
+    words.sort_unstable();
+    words.dedup();
+
+    let closest = words.first().map(|&(distance, _)| distance);
+    words
+        .into_iter()
+        .take_while(|&(distance, _)| Some(distance) == closest)
+        .map(|(_, word)| word)
+        .take(3)
+        .collect()
+}
+
 #[async_trait(?Send)]
 impl ContextAwareParse for Command {
     async fn parse_input(input: &str, app_meta: &AppMeta) -> CommandMatches<Self> {
@@ -160,10 +348,13 @@ impl Autocomplete for Command {
         let results = join!(
             CommandAlias::autocomplete(input, app_meta),
             AppCommand::autocomplete(input, app_meta),
+            InitiativeCommand::autocomplete(input, app_meta),
             ReferenceCommand::autocomplete(input, app_meta),
             StorageCommand::autocomplete(input, app_meta),
+            TableCommand::autocomplete(input, app_meta),
             TimeCommand::autocomplete(input, app_meta),
             TutorialCommand::autocomplete(input, app_meta),
+            UserAliasCommand::autocomplete(input, app_meta),
             WorldCommand::autocomplete(input, app_meta),
         );
 
@@ -175,18 +366,26 @@ impl Autocomplete for Command {
             .chain(results.4)
             .chain(results.5)
             .chain(results.6)
+            .chain(results.7)
+            .chain(results.8)
+            .chain(results.9)
             .collect()
     }
 }
 
+// Commands are short-lived, so the size of WorldCommand isn't worth boxing it for.
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CommandType {
     Alias(CommandAlias),
     App(AppCommand),
+    Initiative(InitiativeCommand),
     Reference(ReferenceCommand),
     Storage(StorageCommand),
+    Table(TableCommand),
     Time(TimeCommand),
     Tutorial(TutorialCommand),
+    UserAlias(UserAliasCommand),
     World(WorldCommand),
 }
 
@@ -199,10 +398,13 @@ impl CommandType {
         match self {
             Self::Alias(c) => c.run(input, app_meta).await,
             Self::App(c) => c.run(input, app_meta).await,
+            Self::Initiative(c) => c.run(input, app_meta).await,
             Self::Reference(c) => c.run(input, app_meta).await,
             Self::Storage(c) => c.run(input, app_meta).await,
+            Self::Table(c) => c.run(input, app_meta).await,
             Self::Time(c) => c.run(input, app_meta).await,
             Self::Tutorial(c) => c.run(input, app_meta).await,
+            Self::UserAlias(c) => c.run(input, app_meta).await,
             Self::World(c) => c.run(input, app_meta).await,
         }
     }
@@ -213,10 +415,13 @@ impl fmt::Display for CommandType {
         match self {
             Self::Alias(c) => write!(f, "{}", c),
             Self::App(c) => write!(f, "{}", c),
+            Self::Initiative(c) => write!(f, "{}", c),
             Self::Reference(c) => write!(f, "{}", c),
             Self::Storage(c) => write!(f, "{}", c),
+            Self::Table(c) => write!(f, "{}", c),
             Self::Time(c) => write!(f, "{}", c),
             Self::Tutorial(c) => write!(f, "{}", c),
+            Self::UserAlias(c) => write!(f, "{}", c),
             Self::World(c) => write!(f, "{}", c),
         }
     }
@@ -236,6 +441,12 @@ impl From<AppCommand> for CommandType {
     }
 }
 
+impl From<InitiativeCommand> for CommandType {
+    fn from(c: InitiativeCommand) -> CommandType {
+        CommandType::Initiative(c)
+    }
+}
+
 impl From<CommandAlias> for CommandType {
     fn from(c: CommandAlias) -> CommandType {
         CommandType::Alias(c)
@@ -254,6 +465,12 @@ impl From<StorageCommand> for CommandType {
     }
 }
 
+impl From<TableCommand> for CommandType {
+    fn from(c: TableCommand) -> CommandType {
+        CommandType::Table(c)
+    }
+}
+
 impl From<TimeCommand> for CommandType {
     fn from(c: TimeCommand) -> CommandType {
         CommandType::Time(c)
@@ -266,6 +483,12 @@ impl From<TutorialCommand> for CommandType {
     }
 }
 
+impl From<UserAliasCommand> for CommandType {
+    fn from(c: UserAliasCommand) -> CommandType {
+        CommandType::UserAlias(c)
+    }
+}
+
 impl From<WorldCommand> for CommandType {
     fn from(c: WorldCommand) -> CommandType {
         CommandType::World(c)
@@ -281,6 +504,43 @@ mod test {
     use crate::Event;
     use tokio_test::block_on;
 
+    #[test]
+    fn commands_test() {
+        let app_meta = app_meta();
+
+        let mut sorted = COMMANDS.to_vec();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(COMMANDS, &sorted[..]);
+
+        // The summaries of undo and redo describe what is to be undone, so only those of the
+        // other commands can be compared.
+        for &(term, summary) in COMMANDS
+            .iter()
+            .filter(|(term, _)| !["undo", "redo"].contains(term))
+        {
+            let input = term.split('[').next().unwrap().trim_end();
+            let suggestions = block_on(Command::autocomplete(input, &app_meta));
+
+            assert!(
+                suggestions.contains(&AutocompleteSuggestion::new(term, summary)),
+                "{:?} not in {:?}",
+                (term, summary),
+                suggestions,
+            );
+        }
+    }
+
+    #[test]
+    fn similar_words_test() {
+        assert_eq!(vec!["npc"], similar_words("npcc"));
+        assert_eq!(vec!["journal"], similar_words("jornal"));
+        assert_eq!(vec!["elf"], similar_words("ELFF "));
+        assert_eq!(Vec::<&str>::new(), similar_words("elf"));
+        assert_eq!(Vec::<&str>::new(), similar_words("zz"));
+        assert_eq!(Vec::<&str>::new(), similar_words("potato salad"));
+    }
+
     #[test]
     fn parse_input_test() {
         let app_meta = app_meta();
@@ -330,15 +590,17 @@ mod test {
     fn autocomplete_test() {
         assert_autocomplete(
             &[
+                ("damage [name] [amount]", "reduce a combatant's hit points"),
                 ("Dancing Lights", "SRD spell"),
                 ("Darkness", "SRD spell"),
-                ("Darkvision", "SRD spell"),
+                ("Darkvision", "SRD trait"),
                 ("date", "get the current time"),
                 ("Daylight", "SRD spell"),
                 ("Death Ward", "SRD spell"),
                 ("Delayed Blast Fireball", "SRD spell"),
                 ("delete [name]", "remove an entry from journal"),
                 ("Demiplane", "SRD spell"),
+                ("describe [character]", "describe a character in prose"),
                 ("desert", "create desert"),
                 ("Detect Evil and Good", "SRD spell"),
                 ("Detect Magic", "SRD spell"),
@@ -348,6 +610,7 @@ mod test {
                 ("domain", "create domain"),
                 ("dragonborn", "create dragonborn"),
                 ("duchy", "create duchy"),
+                ("dunes", "create desert"),
                 ("duty-house", "create duty-house"),
                 ("dwarf", "create dwarf"),
                 ("dwarvish", "create dwarvish person"),
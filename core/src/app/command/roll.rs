@@ -0,0 +1,342 @@
+use caith::{RollHistory, RollResultType, Roller, SingleRollResult};
+use rand::Rng;
+
+/// A dice formula as typed by the user, translated into the syntax understood by `caith`.
+///
+/// `caith`'s own suffixes keep their meaning: `K` and `k` keep the highest and lowest dice, and
+/// `D` and `d` drop the highest and lowest. The conventional `kh`/`kl` and `dh`/`dl` are accepted
+/// alongside them.
+#[derive(Debug)]
+pub struct DiceFormula {
+    formula: String,
+    terms: Vec<Option<KeepDrop>>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum KeepDrop {
+    KeepHighest(usize),
+    KeepLowest(usize),
+    DropHighest(usize),
+    DropLowest(usize),
+}
+
+impl DiceFormula {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let invalid = || {
+            format!(
+                "\"{}\" is not a valid dice formula. See `help` for some examples.",
+                input,
+            )
+        };
+
+        let chars: Vec<char> = input.chars().collect();
+        let mut formula = String::with_capacity(input.len());
+        let mut terms: Vec<(usize, Option<KeepDrop>)> = Vec::new();
+        let mut in_options = false;
+        let mut i = 0;
+
+        while let Some(&c) = chars.get(i) {
+            if c == ':' {
+                // Everything after the colon is a comment.
+                formula.extend(&chars[i..]);
+                break;
+            }
+
+            if in_options {
+                let option = match (c, chars.get(i + 1)) {
+                    ('k', Some('h')) => Some(('K', 2)),
+                    ('k', Some('l')) => Some(('k', 2)),
+                    ('k', _) => Some(('k', 1)),
+                    ('K', _) => Some(('K', 1)),
+                    ('d', Some('h')) => Some(('D', 2)),
+                    ('d', Some('l')) => Some(('d', 2)),
+                    ('d', _) => Some(('d', 1)),
+                    ('D', _) => Some(('D', 1)),
+                    _ => None,
+                };
+
+                if let Some((option, len)) = option {
+                    let digits: String = chars[i + len..]
+                        .iter()
+                        .take_while(|c| c.is_ascii_digit())
+                        .collect();
+                    let count = digits.parse().map_err(|_| invalid())?;
+                    let keep_drop = match option {
+                        'K' => KeepDrop::KeepHighest(count),
+                        'k' => KeepDrop::KeepLowest(count),
+                        'D' => KeepDrop::DropHighest(count),
+                        _ => KeepDrop::DropLowest(count),
+                    };
+
+                    if let Some((dice, term)) = terms.last_mut() {
+                        keep_drop.validate(*dice)?;
+                        *term = Some(keep_drop);
+                    }
+
+                    formula.push_str(&keep_drop.to_string());
+                    i += len + digits.len();
+                    continue;
+                } else if !(c.is_ascii_alphanumeric() || c.is_whitespace() || "![],".contains(c)) {
+                    in_options = false;
+                }
+            } else if c == 'd' && chars.get(i + 1).is_some_and(is_die_size) {
+                let digits: String = chars[..i]
+                    .iter()
+                    .rev()
+                    .take_while(|c| c.is_ascii_digit())
+                    .collect();
+                terms.push((digits.parse().unwrap_or(1), None));
+
+                formula.push(c);
+                i += 1;
+
+                while let Some(&c) = chars.get(i).filter(|c| is_die_size(c)) {
+                    formula.push(c);
+                    i += 1;
+                }
+
+                in_options = true;
+                continue;
+            }
+
+            formula.push(c);
+            i += 1;
+        }
+
+        if Roller::new(&formula).is_ok_and(|r| r.roll().is_ok()) {
+            Ok(Self {
+                formula,
+                terms: terms.into_iter().map(|(_, term)| term).collect(),
+            })
+        } else {
+            Err(invalid())
+        }
+    }
+
+    /// Roll the dice, striking through any dice that were dropped from the total.
+    pub fn roll(&self, rng: &mut impl Rng) -> Option<String> {
+        let result = Roller::new(&self.formula).ok()?.roll_with(rng).ok()?;
+        let mut output = result.to_string();
+
+        if self.terms.iter().any(Option::is_some) {
+            let singles: Vec<&SingleRollResult> = match result.get_result() {
+                RollResultType::Single(single) => vec![single],
+                RollResultType::Repeated(repeated) => repeated.iter().collect(),
+            };
+
+            let mut offset = 0;
+            for single in singles {
+                let history = single.to_string_history();
+                let marked = self.mark_dropped(single);
+
+                if let Some(pos) = output[offset..].find(&history) {
+                    output.replace_range(offset + pos..offset + pos + history.len(), &marked);
+                    offset += pos + marked.len();
+                }
+            }
+        }
+
+        Some(output.trim_end().replace('\n', "\\\n").replace('`', ""))
+    }
+
+    /// Roll the dice and return only the total, for results that are used rather than shown.
+    pub fn roll_total(&self, rng: &mut impl Rng) -> Option<i64> {
+        Roller::new(&self.formula)
+            .ok()?
+            .roll_with(rng)
+            .ok()?
+            .as_single()
+            .map(|result| result.get_total())
+    }
+
+    fn mark_dropped(&self, result: &SingleRollResult) -> String {
+        let mut terms = self.terms.iter();
+
+        result
+            .get_history()
+            .iter()
+            .map(|history| match history {
+                RollHistory::Roll(dice) => {
+                    let values: Vec<u64> = dice.iter().map(|die| die.res).collect();
+                    let dropped = terms
+                        .next()
+                        .and_then(|term| term.as_ref())
+                        // If every die came up the same, striking out any one of them would be
+                        // arbitrary, so none are.
+                        .filter(|_| values.iter().any(|value| *value != values[0]))
+                        .map(|term| term.dropped(&values))
+                        .unwrap_or_default();
+
+                    let values: Vec<String> = values
+                        .iter()
+                        .enumerate()
+                        .map(|(i, value)| {
+                            if dropped.contains(&i) {
+                                format!("~~{}~~", value)
+                            } else {
+                                value.to_string()
+                            }
+                        })
+                        .collect();
+
+                    format!("[{}]", values.join(", "))
+                }
+                RollHistory::Fudge(_) => {
+                    terms.next();
+                    history.to_string()
+                }
+                _ => history.to_string(),
+            })
+            .collect()
+    }
+}
+
+/// Roll 4d6, dropping the lowest die, for each of the six abilities.
+pub fn roll_ability_scores(rng: &mut impl Rng) -> Option<String> {
+    let formula = DiceFormula::parse("4d6d1").ok()?;
+
+    ["STR", "DEX", "CON", "INT", "WIS", "CHA"]
+        .iter()
+        .map(|ability| {
+            formula
+                .roll(rng)
+                .map(|roll| format!("**{}:** {}", ability, roll))
+        })
+        .collect::<Option<Vec<_>>>()
+        .map(|lines| lines.join("\\\n"))
+}
+
+/// Dice sides are either a number or `F` for Fudge dice.
+fn is_die_size(c: &char) -> bool {
+    c.is_ascii_digit() || *c == 'F' || *c == 'f'
+}
+
+impl KeepDrop {
+    fn validate(&self, dice: usize) -> Result<(), String> {
+        match self {
+            Self::KeepHighest(n) | Self::KeepLowest(n) if *n == 0 || *n > dice => {
+                Err(format!("Can't keep {} dice from a roll of {}.", n, dice))
+            }
+            Self::DropHighest(n) | Self::DropLowest(n) if *n >= dice => {
+                Err(format!("Can't drop {} dice from a roll of {}.", n, dice))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The indices of the dice that don't count toward the total.
+    fn dropped(&self, values: &[u64]) -> Vec<usize> {
+        let mut sorted: Vec<usize> = (0..values.len()).collect();
+        sorted.sort_by_key(|&i| values[i]);
+
+        let len = values.len();
+        match *self {
+            Self::KeepHighest(n) => sorted[..len.saturating_sub(n)].to_vec(),
+            Self::KeepLowest(n) => sorted[n.min(len)..].to_vec(),
+            Self::DropHighest(n) => sorted[len.saturating_sub(n)..].to_vec(),
+            Self::DropLowest(n) => sorted[..n.min(len)].to_vec(),
+        }
+    }
+}
+
+impl std::fmt::Display for KeepDrop {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::KeepHighest(n) => write!(f, "K{}", n),
+            Self::KeepLowest(n) => write!(f, "k{}", n),
+            Self::DropHighest(n) => write!(f, "D{}", n),
+            Self::DropLowest(n) => write!(f, "d{}", n),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn parse_test() {
+        assert_eq!("2d20k1", DiceFormula::parse("2d20k1").unwrap().formula);
+        assert_eq!("4d6K3", DiceFormula::parse("4d6kh3").unwrap().formula);
+        assert_eq!("2d20k1", DiceFormula::parse("2d20kl1").unwrap().formula);
+        assert_eq!("2d20K1+5", DiceFormula::parse("2d20K1+5").unwrap().formula);
+        assert_eq!("4d6d1", DiceFormula::parse("4d6dl1").unwrap().formula);
+        assert_eq!("3d10 D1", DiceFormula::parse("3d10 dh1").unwrap().formula);
+        assert_eq!(
+            "d20+3: dexterity check",
+            DiceFormula::parse("d20+3: dexterity check")
+                .unwrap()
+                .formula,
+        );
+
+        assert_eq!(
+            Err("Can't keep 5 dice from a roll of 4.".to_string()),
+            DiceFormula::parse("4d6k5").map(|_| ()),
+        );
+        assert_eq!(
+            Err("Can't drop 2 dice from a roll of 2.".to_string()),
+            DiceFormula::parse("2d20d2").map(|_| ()),
+        );
+        assert_eq!(
+            Err("\"4d6k\" is not a valid dice formula. See `help` for some examples.".to_string()),
+            DiceFormula::parse("4d6k").map(|_| ()),
+        );
+    }
+
+    #[test]
+    fn dropped_test() {
+        let values = [6, 1, 5, 2];
+
+        assert_eq!(vec![1], KeepDrop::KeepHighest(3).dropped(&values));
+        assert_eq!(vec![2, 0], KeepDrop::KeepLowest(2).dropped(&values));
+        assert_eq!(vec![0], KeepDrop::DropHighest(1).dropped(&values));
+        assert_eq!(vec![1, 3], KeepDrop::DropLowest(2).dropped(&values));
+    }
+
+    #[test]
+    fn roll_ability_scores_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let output = roll_ability_scores(&mut rng).unwrap();
+        let lines: Vec<&str> = output.split("\\\n").collect();
+
+        assert_eq!(6, lines.len(), "{}", output);
+        for (line, ability) in lines.iter().zip(["STR", "DEX", "CON", "INT", "WIS", "CHA"]) {
+            assert!(line.starts_with(&format!("**{}:** [", ability)), "{}", line);
+            assert_eq!(1, line.matches("~~").count() / 2, "{}", line);
+
+            let total: u8 = line
+                .rsplit_once(" = **")
+                .and_then(|(_, total)| total.strip_suffix("**"))
+                .and_then(|total| total.parse().ok())
+                .unwrap();
+            assert!((3..=18).contains(&total), "{}", line);
+        }
+    }
+
+    #[test]
+    fn roll_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        let output = DiceFormula::parse("4d6kh3")
+            .unwrap()
+            .roll(&mut rng)
+            .unwrap();
+        assert_eq!(1, output.matches("~~").count() / 2, "{}", output);
+
+        let output = DiceFormula::parse("d20+3").unwrap().roll(&mut rng).unwrap();
+        assert!(!output.contains("~~"), "{}", output);
+    }
+
+    #[test]
+    fn roll_total_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let formula = DiceFormula::parse("2d6 * 10").unwrap();
+
+        for _ in 0..20 {
+            let total = formula.roll_total(&mut rng).unwrap();
+            assert!((20..=120).contains(&total), "{}", total);
+            assert_eq!(0, total % 10, "{}", total);
+        }
+    }
+}
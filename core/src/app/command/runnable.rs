@@ -96,7 +96,7 @@ impl From<(String, String)> for AutocompleteSuggestion {
 /// an item and a spell.)
 ///
 /// || Canonical matches || Fuzzy matches || Result ||
-/// | 0 | 0 | Error: "Unknown command: '...'" |
+/// | 0 | 0 | Error: "Unknown command: '...'", suggesting any similar commands |
 /// | 0 | 1 | The fuzzy match is run. |
 /// | 0 | 2+ | Error: "There are several possible interpretations of this command. Did you mean:" |
 /// | 1 | 0 | The canonical match is run. |
@@ -0,0 +1,644 @@
+use crate::app::{
+    AppMeta, Autocomplete, AutocompleteSuggestion, CommandMatches, ContextAwareParse, Runnable,
+};
+use crate::utils::CaseInsensitiveStr;
+use crate::world::weighted_index_from_tuple;
+use async_trait::async_trait;
+use rand::Rng;
+use std::fmt;
+
+/// The largest weight, or the highest number in a range, that a table entry can have. This keeps
+/// the total of a table's weights from overflowing.
+const MAX_WEIGHT: usize = 1000;
+
+/// A user-defined random table, eg. `table create rumors: "orc raid", 3x "missing child"`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RandomTable {
+    name: String,
+    entries: Vec<(String, usize)>,
+
+    /// Tables whose entries have ranges, eg. `01-50 common, 51-00 rare`, are rolled on a die of
+    /// this size. Each entry's weight is then the size of its range, in order from 1 up.
+    die: Option<usize>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TableCommand {
+    Create { name: String, entries: String },
+    Roll { name: String },
+}
+
+impl RandomTable {
+    /// Parse a comma-separated list of entries, each optionally prefixed with a weight such as
+    /// `3x`. Entries may be quoted, which allows them to contain commas.
+    pub fn parse(name: &str, input: &str) -> Result<Self, String> {
+        let entry_inputs = split_entries(input);
+
+        // A table with any ranges is a range table, but a lone number such as `10 gold` could
+        // just as easily be part of a flat entry.
+        if entry_inputs
+            .iter()
+            .any(|entry| split_range(entry.trim()).is_some_and(|((start, end), _)| start != end))
+        {
+            return Self::parse_ranges(name, &entry_inputs);
+        }
+
+        let mut entries = Vec::new();
+
+        for entry in entry_inputs {
+            let entry = entry.trim();
+
+            let (weight, entry) = match entry.split_once(|c: char| c.is_whitespace()) {
+                Some((weight, rest)) if weight.ends_with(['x', 'X']) => {
+                    match weight[..weight.len() - 1].parse::<usize>() {
+                        Ok(0) => {
+                            return Err(format!(
+                                "\"{}\" has a weight of zero. Weights must be at least 1.",
+                                unquote(rest.trim()),
+                            ))
+                        }
+                        Ok(weight) if weight > MAX_WEIGHT => {
+                            return Err(format!(
+                                "\"{}\" has a weight of {}. Weights can be at most {}.",
+                                unquote(rest.trim()),
+                                weight,
+                                MAX_WEIGHT,
+                            ))
+                        }
+                        Ok(weight) => (weight, rest.trim()),
+                        Err(_) => (1, entry),
+                    }
+                }
+                _ => (1, entry),
+            };
+
+            let entry = unquote(entry);
+            if entry.is_empty() {
+                return Err("Table entries can't be empty.".to_string());
+            }
+
+            entries.push((entry.to_string(), weight));
+        }
+
+        if entries.is_empty() {
+            Err(format!("The table \"{}\" needs at least one entry.", name))
+        } else if entries
+            .iter()
+            .try_fold(0usize, |total, &(_, weight)| total.checked_add(weight))
+            .is_none()
+        {
+            Err(format!(
+                "The weights in the table \"{}\" add up to too much. Try smaller weights.",
+                name,
+            ))
+        } else {
+            Ok(Self {
+                name: name.to_string(),
+                entries,
+                die: None,
+            })
+        }
+    }
+
+    /// Parse entries that each start with a range, eg. `01-50 common`, or a single number. The
+    /// ranges must cover every number from 1 up to the highest with no gaps or overlaps.
+    fn parse_ranges(name: &str, entry_inputs: &[&str]) -> Result<Self, String> {
+        let mut ranges = Vec::with_capacity(entry_inputs.len());
+
+        for entry in entry_inputs {
+            let entry = entry.trim();
+            let ((start, end), text) = split_range(entry).ok_or_else(|| {
+                format!(
+                    "\"{}\" needs a range like the other entries, eg. `51-90`.",
+                    unquote(entry),
+                )
+            })?;
+
+            let text = unquote(text);
+            if text.is_empty() {
+                return Err("Table entries can't be empty.".to_string());
+            } else if start == 0 || start > end {
+                return Err(format!(
+                    "\"{}\" has an invalid range. Ranges count up from 1, eg. `01-50`.",
+                    text,
+                ));
+            } else if end > MAX_WEIGHT {
+                return Err(format!(
+                    "\"{}\" has a range that goes past {}, which is as high as ranges go.",
+                    text, MAX_WEIGHT,
+                ));
+            }
+
+            ranges.push((start, end, text));
+        }
+
+        ranges.sort_by_key(|&(start, end, _)| (start, end));
+
+        let mut next = 1;
+        let mut entries = Vec::with_capacity(ranges.len());
+
+        for (i, &(start, end, text)) in ranges.iter().enumerate() {
+            if start > next {
+                return Err(format!(
+                    "Nothing in the table \"{}\" is rolled on {}. Ranges can't have gaps.",
+                    name,
+                    display_range(next, start - 1),
+                ));
+            } else if start < next {
+                return Err(format!(
+                    "\"{}\" and \"{}\" overlap on {}. Ranges can't overlap.",
+                    ranges[i - 1].2,
+                    text,
+                    display_range(start, end.min(next - 1)),
+                ));
+            }
+
+            entries.push((text.to_string(), end - start + 1));
+            next = end + 1;
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            entries,
+            die: Some(next - 1),
+        })
+    }
+
+    pub fn roll(&self, rng: &mut impl Rng) -> &str {
+        self.roll_numbered(rng).0
+    }
+
+    /// Roll on the table, along with the number rolled if its entries have ranges.
+    pub fn roll_numbered(&self, rng: &mut impl Rng) -> (&str, Option<usize>) {
+        if let Some(die) = self.die {
+            let roll = rng.gen_range(1..=die);
+            let mut total = 0;
+
+            for (entry, size) in &self.entries {
+                total += size;
+                if roll <= total {
+                    return (entry.as_str(), Some(roll));
+                }
+            }
+
+            unreachable!();
+        } else {
+            (
+                weighted_index_from_tuple(rng, &self.entries[..]).as_str(),
+                None,
+            )
+        }
+    }
+
+    pub fn entry_count(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+/// Split on commas, except those within quotes.
+fn split_entries(input: &str) -> Vec<&str> {
+    let mut entries = Vec::new();
+    let mut in_quotes = false;
+    let mut start = 0;
+
+    for (i, c) in input.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => {
+                entries.push(&input[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    if !input[start..].trim().is_empty() || !entries.is_empty() {
+        entries.push(&input[start..]);
+    }
+
+    entries
+}
+
+/// Split a leading range from an entry, eg. `"51-90 uncommon"` => `((51, 90), "uncommon")`. A
+/// single number is a range of one. Following the percentile dice convention, `00` means 100.
+fn split_range(entry: &str) -> Option<((usize, usize), &str)> {
+    let (range, text) = entry.split_once(|c: char| c.is_whitespace())?;
+    let parse = |number: &str| match number {
+        "00" => Some(100),
+        number if number.chars().all(|c| c.is_ascii_digit()) => number.parse().ok(),
+        _ => None,
+    };
+
+    let (start, end) = match range.split_once(['-', '\u{2013}']) {
+        Some((start, end)) => (parse(start)?, parse(end)?),
+        None => (parse(range)?, parse(range)?),
+    };
+
+    Some(((start, end), text.trim()))
+}
+
+/// A number as written on a percentile table, eg. `07` or `00`.
+fn display_roll(roll: usize, die: usize) -> String {
+    match (roll, die) {
+        (100, 100) => "00".to_string(),
+        (roll, 100) => format!("{:02}", roll),
+        (roll, _) => roll.to_string(),
+    }
+}
+
+fn display_range(start: usize, end: usize) -> String {
+    if start == end {
+        start.to_string()
+    } else {
+        format!("{}-{}", start, end)
+    }
+}
+
+fn unquote(input: &str) -> &str {
+    input
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or(input)
+}
+
+#[async_trait(?Send)]
+impl Runnable for TableCommand {
+    async fn run(self, _input: &str, app_meta: &mut AppMeta) -> Result<String, String> {
+        match self {
+            Self::Create { name, entries } => {
+                let table = RandomTable::parse(&name, &entries)?;
+                let len = table.entry_count();
+                let replaced = app_meta.tables.insert(name.to_lowercase(), table).is_some();
+
+                Ok(format!(
+                    "Table \"{}\" {} with {} {}. Use `roll table {}` to roll on it.",
+                    name,
+                    if replaced { "replaced" } else { "created" },
+                    len,
+                    if len == 1 { "entry" } else { "entries" },
+                    name,
+                ))
+            }
+            Self::Roll { name } => {
+                if let Some(table) = app_meta.tables.get(&name.to_lowercase()) {
+                    let (entry, roll) = table.roll_numbered(&mut app_meta.rng);
+
+                    if let Some((roll, die)) = roll.zip(table.die) {
+                        Ok(format!(
+                            "**{}:** {} (rolled {} on d{})",
+                            table.name,
+                            entry,
+                            display_roll(roll, die),
+                            die,
+                        ))
+                    } else {
+                        Ok(format!("**{}:** {}", table.name, entry))
+                    }
+                } else {
+                    Err(format!(
+                        "There is no table named \"{}\". Create one with `table create {}: [entries]`.",
+                        name, name,
+                    ))
+                }
+            }
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl ContextAwareParse for TableCommand {
+    async fn parse_input(input: &str, _app_meta: &AppMeta) -> CommandMatches<Self> {
+        if let Some((name, entries)) = input
+            .strip_prefix_ci("table create ")
+            .and_then(|s| s.split_once(':'))
+            .filter(|(name, _)| !name.trim().is_empty())
+        {
+            CommandMatches::new_canonical(Self::Create {
+                name: name.trim().to_string(),
+                entries: entries.trim().to_string(),
+            })
+        } else if let Some(name) = input
+            .strip_prefix_ci("roll table ")
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+        {
+            CommandMatches::new_canonical(Self::Roll {
+                name: name.to_string(),
+            })
+        } else {
+            CommandMatches::default()
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl Autocomplete for TableCommand {
+    async fn autocomplete(input: &str, app_meta: &AppMeta) -> Vec<AutocompleteSuggestion> {
+        if input.is_empty() {
+            return Vec::new();
+        }
+
+        let mut suggestions = Vec::new();
+
+        if "table create".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "table create [name]: [entries]",
+                "create a random table",
+            ));
+        }
+
+        if input.starts_with_ci("roll table ") {
+            suggestions.extend(
+                app_meta
+                    .tables
+                    .values()
+                    .map(|table| format!("roll table {}", table.name))
+                    .filter(|term| term.starts_with_ci(input))
+                    .map(|term| AutocompleteSuggestion::new(term, "roll on a custom table")),
+            );
+        } else if input.len() > "roll ".len() && "roll table".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "roll table [name]",
+                "roll on a custom table",
+            ));
+        }
+
+        suggestions
+    }
+}
+
+impl fmt::Display for TableCommand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::Create { name, entries } => write!(f, "table create {}: {}", name, entries),
+            Self::Roll { name } => write!(f, "roll table {}", name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::app::assert_autocomplete;
+    use crate::storage::NullDataStore;
+    use crate::Event;
+    use rand::prelude::*;
+    use tokio_test::block_on;
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(
+            Ok(RandomTable {
+                name: "rumors".to_string(),
+                entries: vec![
+                    ("orc raid".to_string(), 1),
+                    ("missing child".to_string(), 3),
+                    ("the mayor, a vampire".to_string(), 1),
+                    ("2x4".to_string(), 1),
+                ],
+                die: None,
+            }),
+            RandomTable::parse(
+                "rumors",
+                "\"orc raid\", 3x \"missing child\", \"the mayor, a vampire\", 2x4",
+            ),
+        );
+
+        assert_eq!(
+            Err("\"orc raid\" has a weight of zero. Weights must be at least 1.".to_string()),
+            RandomTable::parse("rumors", "0x \"orc raid\""),
+        );
+        assert_eq!(
+            Err("\"orc raid\" has a weight of 1001. Weights can be at most 1000.".to_string()),
+            RandomTable::parse("rumors", "1001x \"orc raid\""),
+        );
+        assert_eq!(
+            Err(
+                "\"a\" has a weight of 18446744073709551615. Weights can be at most 1000."
+                    .to_string()
+            ),
+            RandomTable::parse("x", "18446744073709551615x a, 18446744073709551615x b"),
+        );
+        assert_eq!(
+            Err("Table entries can't be empty.".to_string()),
+            RandomTable::parse("rumors", "orc raid,,missing child"),
+        );
+        assert_eq!(
+            Err("The table \"rumors\" needs at least one entry.".to_string()),
+            RandomTable::parse("rumors", " "),
+        );
+    }
+
+    #[test]
+    fn parse_ranges_test() {
+        assert_eq!(
+            Ok(RandomTable {
+                name: "loot".to_string(),
+                entries: vec![
+                    ("common".to_string(), 50),
+                    ("uncommon".to_string(), 40),
+                    ("rare".to_string(), 10),
+                ],
+                die: Some(100),
+            }),
+            RandomTable::parse("loot", "01-50 common, 51\u{2013}90 uncommon, 91-00 rare"),
+        );
+
+        assert_eq!(
+            Ok(RandomTable {
+                name: "weather".to_string(),
+                entries: vec![
+                    ("sun".to_string(), 1),
+                    ("rain, heavy".to_string(), 4),
+                    ("snow".to_string(), 1),
+                ],
+                die: Some(6),
+            }),
+            RandomTable::parse("weather", "6 snow, 1 sun, 2-5 \"rain, heavy\""),
+        );
+
+        assert_eq!(
+            Err(
+                "Nothing in the table \"loot\" is rolled on 51-60. Ranges can't have gaps."
+                    .to_string()
+            ),
+            RandomTable::parse("loot", "01-50 common, 61-00 rare"),
+        );
+        assert_eq!(
+            Err(
+                "Nothing in the table \"loot\" is rolled on 1-4. Ranges can't have gaps."
+                    .to_string()
+            ),
+            RandomTable::parse("loot", "05-50 common, 51-00 rare"),
+        );
+        assert_eq!(
+            Err("\"common\" and \"rare\" overlap on 41-50. Ranges can't overlap.".to_string()),
+            RandomTable::parse("loot", "01-50 common, 41-00 rare"),
+        );
+        assert_eq!(
+            Err("\"rare\" has an invalid range. Ranges count up from 1, eg. `01-50`.".to_string()),
+            RandomTable::parse("loot", "01-50 common, 90-51 rare"),
+        );
+        assert_eq!(
+            Err(
+                "\"rare\" has a range that goes past 1000, which is as high as ranges go."
+                    .to_string()
+            ),
+            RandomTable::parse("loot", "1-50 common, 51-18446744073709551615 rare"),
+        );
+        assert_eq!(
+            Err("\"rare\" needs a range like the other entries, eg. `51-90`.".to_string()),
+            RandomTable::parse("loot", "01-50 common, rare"),
+        );
+
+        // A lone number is part of the entry unless there are ranges elsewhere.
+        assert_eq!(
+            Ok(vec![
+                ("10 gold".to_string(), 1),
+                ("20 silver".to_string(), 1)
+            ]),
+            RandomTable::parse("loot", "10 gold, 20 silver").map(|table| table.entries),
+        );
+    }
+
+    #[test]
+    fn roll_ranges_test() {
+        let table = RandomTable::parse("loot", "01-50 common, 51-90 uncommon, 91-00 rare").unwrap();
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        for _ in 0..200 {
+            let (entry, roll) = table.roll_numbered(&mut rng);
+            let roll = roll.unwrap();
+
+            match roll {
+                1..=50 => assert_eq!("common", entry),
+                51..=90 => assert_eq!("uncommon", entry),
+                91..=100 => assert_eq!("rare", entry),
+                _ => panic!("{}", roll),
+            }
+        }
+
+        assert_eq!("00", display_roll(100, 100));
+        assert_eq!("07", display_roll(7, 100));
+        assert_eq!("6", display_roll(6, 6));
+    }
+
+    #[test]
+    fn roll_test() {
+        let table = RandomTable::parse("weather", "sun, 8x rain").unwrap();
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        let results: Vec<&str> = (0..100).map(|_| table.roll(&mut rng)).collect();
+        let rain = results.iter().filter(|s| **s == "rain").count();
+        assert!(rain > 75 && rain < 100, "{:?}", results);
+
+        let mut rng = SmallRng::seed_from_u64(0);
+        assert_eq!(
+            results,
+            (0..100).map(|_| table.roll(&mut rng)).collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn run_test() {
+        let mut app_meta = app_meta();
+
+        assert_eq!(
+            Ok(
+                "Table \"Weather\" created with 1 entry. Use `roll table Weather` to roll on it."
+                    .to_string()
+            ),
+            block_on(
+                TableCommand::Create {
+                    name: "Weather".to_string(),
+                    entries: "rain".to_string(),
+                }
+                .run("", &mut app_meta)
+            ),
+        );
+
+        assert_eq!(
+            Ok("**Weather:** rain".to_string()),
+            block_on(
+                TableCommand::Roll {
+                    name: "weather".to_string(),
+                }
+                .run("", &mut app_meta)
+            ),
+        );
+
+        assert_eq!(
+            Err(
+                "There is no table named \"loot\". Create one with `table create loot: [entries]`."
+                    .to_string()
+            ),
+            block_on(
+                TableCommand::Roll {
+                    name: "loot".to_string(),
+                }
+                .run("", &mut app_meta)
+            ),
+        );
+    }
+
+    #[test]
+    fn autocomplete_test() {
+        let mut app_meta = app_meta();
+        block_on(
+            TableCommand::Create {
+                name: "Weather".to_string(),
+                entries: "rain".to_string(),
+            }
+            .run("", &mut app_meta),
+        )
+        .unwrap();
+
+        assert_autocomplete(
+            &[("table create [name]: [entries]", "create a random table")][..],
+            block_on(TableCommand::autocomplete("TAB", &app_meta)),
+        );
+
+        assert_autocomplete(
+            &[("roll table [name]", "roll on a custom table")][..],
+            block_on(TableCommand::autocomplete("roll t", &app_meta)),
+        );
+
+        assert_autocomplete(
+            &[("roll table Weather", "roll on a custom table")][..],
+            block_on(TableCommand::autocomplete("roll table w", &app_meta)),
+        );
+    }
+
+    #[test]
+    fn display_test() {
+        let app_meta = app_meta();
+
+        [
+            TableCommand::Create {
+                name: "rumors".to_string(),
+                entries: "\"orc raid\", 3x \"missing child\"".to_string(),
+            },
+            TableCommand::Roll {
+                name: "rumors".to_string(),
+            },
+        ]
+        .into_iter()
+        .for_each(|command| {
+            let command_string = command.to_string();
+            assert_ne!("", command_string);
+
+            assert_eq!(
+                CommandMatches::new_canonical(command),
+                block_on(TableCommand::parse_input(&command_string, &app_meta)),
+                "{}",
+                command_string,
+            );
+        });
+    }
+
+    fn event_dispatcher(_event: Event) {}
+
+    fn app_meta() -> AppMeta {
+        AppMeta::new(NullDataStore, &event_dispatcher)
+    }
+}
@@ -0,0 +1,319 @@
+use super::Command;
+use crate::app::{
+    AppMeta, Autocomplete, AutocompleteSuggestion, CommandMatches, ContextAwareParse, Runnable,
+};
+use crate::utils::CaseInsensitiveStr;
+use async_trait::async_trait;
+use std::fmt;
+
+/// Shortcuts defined by the user, eg. `alias tav = inn`. Not to be confused with
+/// [`super::CommandAlias`], which is used internally for contextual commands such as `more`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum UserAliasCommand {
+    Define { name: String, expansion: String },
+    List,
+    Remove { name: String },
+}
+
+#[async_trait(?Send)]
+impl Runnable for UserAliasCommand {
+    async fn run(self, _input: &str, app_meta: &mut AppMeta) -> Result<String, String> {
+        match self {
+            Self::Define { name, expansion } => {
+                if name.is_empty()
+                    || name.contains(|c: char| c.is_whitespace() || c == ';')
+                    || name.in_ci(&["alias", "unalias"])
+                {
+                    return Err(
+                        "Alias names must be a single word, eg. `alias tav = inn`.".to_string()
+                    );
+                } else if expansion.is_empty() {
+                    return Err(format!("The alias `{}` needs a command to run.", name));
+                } else if expansion.split(';').any(|command| {
+                    command
+                        .split_whitespace()
+                        .next()
+                        .is_some_and(|word| word.eq_ci(&name))
+                }) {
+                    return Err(format!("The alias `{}` can't refer to itself.", name));
+                }
+
+                if !Command::parse_input_irrefutable(&name, app_meta)
+                    .await
+                    .is_empty()
+                {
+                    return Err(format!(
+                        "`{}` is already a command, so it can't be used as an alias.",
+                        name,
+                    ));
+                }
+
+                let replaced = app_meta
+                    .user_aliases
+                    .insert(name.to_lowercase(), expansion.clone())
+                    .is_some();
+
+                Ok(format!(
+                    "Alias `{}` {}. Typing `{}` will now run `{}`.",
+                    name,
+                    if replaced { "updated" } else { "created" },
+                    name,
+                    expansion,
+                ))
+            }
+            Self::List => {
+                if app_meta.user_aliases.is_empty() {
+                    return Err(
+                        "You haven't defined any aliases yet. Try `alias [name] = [command]`."
+                            .to_string(),
+                    );
+                }
+
+                let mut output = "# Aliases".to_string();
+                for (i, (name, expansion)) in app_meta.user_aliases.iter().enumerate() {
+                    output.push_str(if i == 0 { "\n\n" } else { "\\\n" });
+                    output.push_str(&format!("`{}`: `{}`", name, expansion));
+                }
+                Ok(output)
+            }
+            Self::Remove { name } => {
+                if let Some(expansion) = app_meta.user_aliases.remove(&name.to_lowercase()) {
+                    Ok(format!(
+                        "Alias `{}` removed. It used to run `{}`.",
+                        name, expansion,
+                    ))
+                } else {
+                    Err(format!("There is no alias named `{}`.", name))
+                }
+            }
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl ContextAwareParse for UserAliasCommand {
+    async fn parse_input(input: &str, _app_meta: &AppMeta) -> CommandMatches<Self> {
+        if input.eq_ci("alias list") {
+            CommandMatches::new_canonical(Self::List)
+        } else if let Some((name, expansion)) = input
+            .strip_prefix_ci("alias ")
+            .and_then(|s| s.split_once('='))
+        {
+            CommandMatches::new_canonical(Self::Define {
+                name: name.trim().to_string(),
+                expansion: expansion.trim().to_string(),
+            })
+        } else if let Some(name) = input.strip_prefix_ci("unalias ") {
+            CommandMatches::new_canonical(Self::Remove {
+                name: name.trim().to_string(),
+            })
+        } else {
+            CommandMatches::default()
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl Autocomplete for UserAliasCommand {
+    async fn autocomplete(input: &str, app_meta: &AppMeta) -> Vec<AutocompleteSuggestion> {
+        if input.is_empty() {
+            return Vec::new();
+        }
+
+        let mut suggestions: Vec<_> = app_meta
+            .user_aliases
+            .iter()
+            .filter(|(name, _)| name.starts_with_ci(input))
+            .map(|(name, expansion)| {
+                AutocompleteSuggestion::new(name.to_string(), format!("alias for {}", expansion))
+            })
+            .collect();
+
+        if "alias".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "alias [name] = [command]",
+                "create a shortcut",
+            ));
+        }
+
+        if "alias list".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "alias list",
+                "show your shortcuts",
+            ));
+        }
+
+        if let Some(name_start) = input.strip_prefix_ci("unalias ") {
+            suggestions.extend(
+                app_meta
+                    .user_aliases
+                    .keys()
+                    .filter(|name| name.starts_with_ci(name_start))
+                    .map(|name| {
+                        AutocompleteSuggestion::new(
+                            format!("unalias {}", name),
+                            "remove this shortcut",
+                        )
+                    }),
+            );
+        } else if "unalias".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "unalias [name]",
+                "remove a shortcut",
+            ));
+        }
+
+        suggestions
+    }
+}
+
+impl fmt::Display for UserAliasCommand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::Define { name, expansion } => write!(f, "alias {} = {}", name, expansion),
+            Self::List => write!(f, "alias list"),
+            Self::Remove { name } => write!(f, "unalias {}", name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::app::assert_autocomplete;
+    use crate::storage::NullDataStore;
+    use crate::Event;
+    use tokio_test::block_on;
+
+    #[test]
+    fn run_test() {
+        let mut app_meta = app_meta();
+
+        assert_eq!(
+            Ok("Alias `tav` created. Typing `tav` will now run `inn`.".to_string()),
+            block_on(define("tav", "inn").run("", &mut app_meta)),
+        );
+        assert_eq!(
+            Ok("Alias `TAV` updated. Typing `TAV` will now run `npc; inn`.".to_string()),
+            block_on(define("TAV", "npc; inn").run("", &mut app_meta)),
+        );
+        assert_eq!(
+            Some("npc; inn"),
+            app_meta.user_aliases.get("tav").map(String::as_str)
+        );
+
+        assert_eq!(
+            Err("The alias `loop` can't refer to itself.".to_string()),
+            block_on(define("loop", "npc; LOOP").run("", &mut app_meta)),
+        );
+        assert_eq!(
+            Err("`npc` is already a command, so it can't be used as an alias.".to_string()),
+            block_on(define("npc", "inn").run("", &mut app_meta)),
+        );
+        assert_eq!(
+            Err("Alias names must be a single word, eg. `alias tav = inn`.".to_string()),
+            block_on(define("my tav", "inn").run("", &mut app_meta)),
+        );
+        assert_eq!(
+            Err("The alias `tav` needs a command to run.".to_string()),
+            block_on(define("tav", "").run("", &mut app_meta)),
+        );
+        assert_eq!(
+            Err("Alias names must be a single word, eg. `alias tav = inn`.".to_string()),
+            block_on(define("unalias", "inn").run("", &mut app_meta)),
+        );
+    }
+
+    #[test]
+    fn list_remove_test() {
+        let mut app_meta = app_meta();
+
+        assert_eq!(
+            Err("You haven't defined any aliases yet. Try `alias [name] = [command]`.".to_string()),
+            block_on(UserAliasCommand::List.run("", &mut app_meta)),
+        );
+
+        block_on(define("tav", "inn").run("", &mut app_meta)).unwrap();
+        block_on(define("party", "npc; npc").run("", &mut app_meta)).unwrap();
+
+        assert_eq!(
+            Ok("# Aliases\n\n`party`: `npc; npc`\\\n`tav`: `inn`".to_string()),
+            block_on(UserAliasCommand::List.run("", &mut app_meta)),
+        );
+
+        assert_eq!(
+            Ok("Alias `TAV` removed. It used to run `inn`.".to_string()),
+            block_on(remove("TAV").run("", &mut app_meta)),
+        );
+        assert_eq!(
+            Err("There is no alias named `tav`.".to_string()),
+            block_on(remove("tav").run("", &mut app_meta)),
+        );
+        assert_eq!(1, app_meta.user_aliases.len());
+    }
+
+    #[test]
+    fn autocomplete_test() {
+        let mut app_meta = app_meta();
+        block_on(define("tav", "inn").run("", &mut app_meta)).unwrap();
+
+        assert_autocomplete(
+            &[("tav", "alias for inn")][..],
+            block_on(UserAliasCommand::autocomplete("TA", &app_meta)),
+        );
+
+        assert_autocomplete(
+            &[
+                ("alias [name] = [command]", "create a shortcut"),
+                ("alias list", "show your shortcuts"),
+            ][..],
+            block_on(UserAliasCommand::autocomplete("al", &app_meta)),
+        );
+
+        assert_autocomplete(
+            &[("unalias [name]", "remove a shortcut")][..],
+            block_on(UserAliasCommand::autocomplete("unal", &app_meta)),
+        );
+
+        assert_autocomplete(
+            &[("unalias tav", "remove this shortcut")][..],
+            block_on(UserAliasCommand::autocomplete("unalias T", &app_meta)),
+        );
+    }
+
+    #[test]
+    fn display_test() {
+        let app_meta = app_meta();
+
+        for command in [define("tav", "inn"), UserAliasCommand::List, remove("tav")] {
+            let command_string = command.to_string();
+            assert_ne!("", command_string);
+
+            assert_eq!(
+                CommandMatches::new_canonical(command),
+                block_on(UserAliasCommand::parse_input(&command_string, &app_meta)),
+                "{}",
+                command_string,
+            );
+        }
+    }
+
+    fn define(name: &str, expansion: &str) -> UserAliasCommand {
+        UserAliasCommand::Define {
+            name: name.to_string(),
+            expansion: expansion.to_string(),
+        }
+    }
+
+    fn remove(name: &str) -> UserAliasCommand {
+        UserAliasCommand::Remove {
+            name: name.to_string(),
+        }
+    }
+
+    fn event_dispatcher(_event: Event) {}
+
+    fn app_meta() -> AppMeta {
+        AppMeta::new(NullDataStore, &event_dispatcher)
+    }
+}
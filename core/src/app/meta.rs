@@ -1,16 +1,83 @@
+use super::command::{Initiative, RandomTable};
 use super::{CommandAlias, Event};
 use crate::storage::{DataStore, Repository};
+use crate::utils::CaseInsensitiveStr;
 use crate::world;
 use rand::prelude::*;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::fmt;
 
+/// The number of commands remembered by `history`.
+const MAX_HISTORY: usize = 50;
+
+/// The number of suggestions shown by autocomplete, unless the frontend asks for another.
+const DEFAULT_AUTOCOMPLETE_LIMIT: usize = 10;
+
+/// The number of generated things that can be referred to by number, eg. `save 2`. This is more
+/// than the largest list that can be generated at once, so every result in a list has a number.
+const MAX_GENERATED: usize = 50;
+
+/// Tips shown at startup, one at random each time, so that returning users discover features
+/// they might have missed.
+pub const TIPS: &[&str] = &[
+    "`npc x5` generates several characters at once, up to 20.",
+    "`reroll name` regenerates just the name of the last thing you generated.",
+    "Several commands can be run at once by separating them with semicolons, eg. `npc; npc; roll d20`.",
+    "`alias party = npc; npc; npc` defines a shortcut of your own, which you can then run by typing `party`.",
+    "`seed 42` makes results repeatable, so you can share an exact tavern with another DM.",
+    "`describe [name]` writes a short paragraph about a character.",
+    "`help [command]` explains a command in more detail, eg. `help roll`.",
+    "`generate town` creates a town with an inn, a general store and a temple, each with a few characters.",
+    "`it` and `that` stand for whatever you looked at last, eg. `save it`.",
+    "`table create [name]: [entries]` makes a random table that you can roll on with `roll table [name]`.",
+];
+
 pub struct AppMeta {
+    /// The most suggestions that autocomplete returns. Zero turns suggestions off.
+    pub autocomplete_limit: usize,
+
     pub command_aliases: HashSet<CommandAlias>,
     pub demographics: world::Demographics,
+    pub emoji: bool,
     pub event_dispatcher: &'static dyn Fn(Event),
+
+    /// The names of the things most recently generated without being saved, oldest first, so
+    /// that they can be referred to by number. Numbers count up from 1 in the order things were
+    /// generated and are never reused, so a number always refers to the same thing.
+    pub generated: VecDeque<String>,
+
+    /// How many of the oldest generated names have been forgotten, ie. the number of the first
+    /// name in `generated`, minus one.
+    pub generated_forgotten: usize,
+
+    pub history: VecDeque<String>,
+
+    /// The combatants in the current encounter, if any.
+    pub initiative: Initiative,
+
+    /// The name of the thing most recently created, shown or changed, which `it` and `that`
+    /// refer to.
+    pub last_thing: Option<String>,
+
+    /// The seed that the most recent generation started from, reported by `last seed`.
+    pub last_seed: Option<u64>,
+
+    /// The flavor of generated human names, chosen with `set names [flavor]`.
+    pub names: world::NameFlavor,
+
+    /// Strip Markdown from output, for frontends that can't render it.
+    pub plaintext: bool,
+
     pub rng: SmallRng,
+
+    /// Set by `seed [number]`, so that the next generation starts from that seed rather than a
+    /// fresh one drawn from `rng`.
+    pub next_seed: Option<u64>,
+
     pub repository: Repository,
+    pub tables: HashMap<String, RandomTable>,
+    pub units: world::Units,
+    pub user_aliases: BTreeMap<String, String>,
 }
 
 impl AppMeta {
@@ -19,12 +86,101 @@ impl AppMeta {
         event_dispatcher: &'static F,
     ) -> Self {
         Self {
+            autocomplete_limit: DEFAULT_AUTOCOMPLETE_LIMIT,
             command_aliases: HashSet::default(),
             demographics: world::Demographics::default(),
+            emoji: true,
             event_dispatcher,
+            generated: VecDeque::default(),
+            generated_forgotten: 0,
+            history: VecDeque::default(),
+            initiative: Initiative::default(),
+            last_seed: None,
+            last_thing: None,
+            names: world::NameFlavor::default(),
+            plaintext: false,
             repository: Repository::new(data_store),
+            next_seed: None,
             rng: SmallRng::from_entropy(),
+            tables: HashMap::default(),
+            units: world::Units::default(),
+            user_aliases: BTreeMap::default(),
+        }
+    }
+
+    /// The commands that the input stands for, if its first word is a user-defined alias. Any
+    /// words following the alias are appended to the expansion.
+    pub fn expand_user_alias(&self, input: &str) -> Option<String> {
+        let input = input.trim();
+        let (name, rest) = input.split_once(char::is_whitespace).unwrap_or((input, ""));
+
+        self.user_aliases
+            .get(&name.to_lowercase())
+            .map(|expansion| {
+                format!("{} {}", expansion, rest.trim_start())
+                    .trim_end()
+                    .to_string()
+            })
+    }
+
+    /// The name that the user meant. `it` and `that` stand for the thing most recently
+    /// referenced, and a number for the thing that was given that number when it was generated;
+    /// any other name is returned as-is.
+    pub fn resolve_pronoun(&self, name: &str) -> Result<String, String> {
+        let name = name.trim();
+
+        if name.eq_ci("it") || name.eq_ci("that") {
+            self.last_thing.clone().ok_or_else(|| {
+                format!(
+                    "There is nothing for \"{}\" to refer to yet. Try creating something first, eg. `npc`.",
+                    name,
+                )
+            })
+        } else if let Some(generated) = name
+            .parse::<usize>()
+            .ok()
+            .and_then(|i| i.checked_sub(self.generated_forgotten + 1))
+            .and_then(|i| self.generated.get(i))
+        {
+            Ok(generated.clone())
+        } else {
+            Ok(name.to_string())
+        }
+    }
+
+    /// Reseed the random number generator before generating something, remembering the seed so
+    /// that `seed [number]` followed by the same command gives the same result.
+    pub fn reseed(&mut self) -> u64 {
+        let seed = self.next_seed.take().unwrap_or_else(|| self.rng.gen());
+        self.rng = SmallRng::seed_from_u64(seed);
+        self.last_seed = Some(seed);
+        seed
+    }
+
+    /// Remember the name of a newly generated thing, forgetting the oldest if there are too many.
+    /// Returns the number that now refers to it.
+    pub fn push_generated(&mut self, name: &str) -> usize {
+        if self.generated.len() >= MAX_GENERATED {
+            self.generated.pop_front();
+            self.generated_forgotten += 1;
         }
+
+        self.generated.push_back(name.to_string());
+        self.generated_forgotten + self.generated.len()
+    }
+
+    /// The things that can currently be referred to by number, oldest first.
+    pub fn numbered_generated(&self) -> impl Iterator<Item = (usize, &String)> {
+        (self.generated_forgotten + 1..).zip(self.generated.iter())
+    }
+
+    /// Remember a command so that it can be repeated with `!!`, forgetting the oldest if the
+    /// history is full.
+    pub fn push_history(&mut self, input: &str) {
+        if self.history.len() >= MAX_HISTORY {
+            self.history.pop_front();
+        }
+        self.history.push_back(input.trim().to_string());
     }
 }
 
@@ -51,11 +207,101 @@ mod test {
         app_meta.demographics = Demographics::new(HashMap::new().into());
 
         assert_eq!(
-            "AppMeta { command_aliases: {}, demographics: Demographics { groups: GroupMapWrapper({}) }, repository: Repository { data_store_enabled: false, recent: [] } }",
+            "AppMeta { command_aliases: {}, demographics: Demographics { groups: GroupMapWrapper({}), names: Common }, repository: Repository { data_store_enabled: false, recent: [] } }",
             format!("{:?}", app_meta),
         );
     }
 
+    #[test]
+    fn push_history_test() {
+        let mut app_meta = app_meta();
+
+        for i in 0..=MAX_HISTORY {
+            app_meta.push_history(&format!(" roll d{} ", i));
+        }
+
+        assert_eq!(MAX_HISTORY, app_meta.history.len());
+        assert_eq!(
+            Some("roll d1"),
+            app_meta.history.front().map(String::as_str)
+        );
+        assert_eq!(
+            Some(format!("roll d{}", MAX_HISTORY).as_str()),
+            app_meta.history.back().map(String::as_str),
+        );
+    }
+
+    #[test]
+    fn resolve_pronoun_test() {
+        let mut app_meta = app_meta();
+
+        assert_eq!(
+            Ok("Potato Johnson".to_string()),
+            app_meta.resolve_pronoun("Potato Johnson")
+        );
+        assert_eq!(
+            Err("There is nothing for \"it\" to refer to yet. Try creating something first, eg. `npc`.".to_string()),
+            app_meta.resolve_pronoun("it"),
+        );
+
+        app_meta.last_thing = Some("Potato Johnson".to_string());
+        assert_eq!(
+            Ok("Potato Johnson".to_string()),
+            app_meta.resolve_pronoun("It")
+        );
+        assert_eq!(
+            Ok("Potato Johnson".to_string()),
+            app_meta.resolve_pronoun("that")
+        );
+        assert_eq!(Ok("Italy".to_string()), app_meta.resolve_pronoun("Italy"));
+    }
+
+    #[test]
+    fn resolve_pronoun_test_generated() {
+        let mut app_meta = app_meta();
+        assert_eq!(Ok("1".to_string()), app_meta.resolve_pronoun("1"));
+
+        assert_eq!(1, app_meta.push_generated("Potato 1"));
+        assert_eq!(2, app_meta.push_generated("Potato 2"));
+        assert_eq!(Ok("Potato 1".to_string()), app_meta.resolve_pronoun(" 1 "));
+
+        // Generating something else doesn't renumber what came before.
+        assert_eq!(3, app_meta.push_generated("Potato 3"));
+        assert_eq!(Ok("Potato 1".to_string()), app_meta.resolve_pronoun("1"));
+        assert_eq!(Ok("Potato 3".to_string()), app_meta.resolve_pronoun("3"));
+        assert_eq!(Ok("4".to_string()), app_meta.resolve_pronoun("4"));
+        assert_eq!(Ok("0".to_string()), app_meta.resolve_pronoun("0"));
+
+        // The oldest are forgotten rather than renumbered.
+        for i in 4..=MAX_GENERATED + 1 {
+            assert_eq!(i, app_meta.push_generated(&format!("Potato {}", i)));
+        }
+        assert_eq!(MAX_GENERATED, app_meta.generated.len());
+        assert_eq!(Ok("1".to_string()), app_meta.resolve_pronoun("1"));
+        assert_eq!(Ok("Potato 2".to_string()), app_meta.resolve_pronoun("2"));
+        assert_eq!(
+            Some((MAX_GENERATED + 1, &format!("Potato {}", MAX_GENERATED + 1))),
+            app_meta.numbered_generated().last(),
+        );
+    }
+
+    #[test]
+    fn reseed_test() {
+        let mut app_meta = app_meta();
+        assert_eq!(None, app_meta.last_seed);
+
+        let seed = app_meta.reseed();
+        assert_eq!(Some(seed), app_meta.last_seed);
+        let value: u64 = app_meta.rng.gen();
+
+        app_meta.next_seed = Some(seed);
+        assert_eq!(seed, app_meta.reseed());
+        assert_eq!(None, app_meta.next_seed);
+        assert_eq!(value, app_meta.rng.gen::<u64>());
+
+        assert_ne!(seed, app_meta.reseed());
+    }
+
     fn event_dispatcher(_event: Event) {}
 
     fn app_meta() -> AppMeta {
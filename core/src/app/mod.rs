@@ -1,18 +1,22 @@
 pub use command::{
     AppCommand, Autocomplete, AutocompleteSuggestion, Command, CommandAlias, CommandMatches,
-    ContextAwareParse, Runnable,
+    ContextAwareParse, DiceFormula, Runnable,
 };
-pub use meta::AppMeta;
+pub use meta::{AppMeta, TIPS};
+pub use output::{CommandOutput, Link};
 
 #[cfg(test)]
 pub use command::assert_autocomplete;
 
 mod command;
 mod meta;
+mod output;
 
 use crate::storage::backup::{import, BackupData};
-use crate::utils::CaseInsensitiveStr;
+use crate::utils::{strip_emoji, strip_markdown, CaseInsensitiveStr};
+use command::{CommandType, COMMANDS};
 use initiative_macros::motd;
+use rand::seq::SliceRandom;
 
 /// The application wrapper. Its inner [`AppMeta`] object holds metadata associated with the
 /// application, including ephemeral storage of journal entries and the object representing the
@@ -32,7 +36,9 @@ pub enum Event {
     Export(BackupData),
 
     /// The user typed the `import` command and should be prompted to select a file to import.
-    Import,
+    /// The `overwrite` flag should be passed back to [`App::bulk_import`] along with the file
+    /// contents.
+    Import { overwrite: bool },
 }
 
 impl App {
@@ -43,25 +49,136 @@ impl App {
     /// Initialize a running application. This is done as a separate step from the constructor
     /// because it runs asynchronously. Its purpose, in turn, is to trigger the underlying data
     /// store to initialize, which may involve opening a database connection.
-    pub async fn init(&mut self) -> &'static str {
+    ///
+    /// Returns the message of the day, followed by a random tip. If the data store couldn't be
+    /// initialized, a warning to that effect is shown instead of the tip.
+    pub async fn init(&mut self) -> String {
         self.meta.repository.init().await;
         let (motd, motd_len) = motd!("! Local storage is not available in your browser. You will be able to use initiative.sh, but anything you save will not persist beyond this session.");
 
         if self.meta.repository.data_store_enabled() {
-            &motd[..motd_len]
+            format!(
+                "{}\n\n_Tip: {}_",
+                truncate_motd(motd, motd_len),
+                TIPS.choose(&mut self.meta.rng).unwrap(),
+            )
         } else {
-            motd
+            motd.to_string()
         }
     }
 
     /// The user typed an input and pressed Enter. What happens?
     ///
+    /// Several commands can be chained with semicolons, eg. `npc; npc; roll d20`. They run in
+    /// order and their outputs are concatenated. The chain stops at the first command that fails,
+    /// and the error is returned along with the output of the commands that preceded it.
+    ///
+    /// User-defined aliases are expanded before the commands are parsed. Expansions are not
+    /// themselves expanded, so an alias can't recurse.
+    ///
     /// On success or failure, returns a String that can be displayed back to the user.
     pub async fn command(&mut self, input: &str) -> Result<String, String> {
-        Command::parse_input_irrefutable(input, &self.meta)
-            .await
-            .run(input, &mut self.meta)
+        // Everything after the `=` of an alias definition belongs to the alias.
+        let inputs = if input.trim_start().starts_with_ci("alias ") {
+            vec![input.trim()]
+        } else {
+            split_commands(input)
+        };
+
+        let mut is_expanded = false;
+        let inputs: Vec<String> = inputs
+            .into_iter()
+            .flat_map(|input| {
+                if let Some(expansion) = self.meta.expand_user_alias(input) {
+                    is_expanded = true;
+                    split_commands(&expansion)
+                        .into_iter()
+                        .map(str::to_string)
+                        .collect()
+                } else {
+                    vec![input.to_string()]
+                }
+            })
+            .collect();
+
+        if !is_expanded {
+            match inputs.as_slice() {
+                [] => return self.run_command(input).await,
+                [input] => return self.run_command(input).await,
+                _ => {}
+            }
+        }
+
+        let mut output = String::new();
+
+        for input in inputs.iter().map(String::as_str) {
+            if !output.is_empty() {
+                output.push_str("\n\n");
+            }
+
+            match self.run_command(input).await {
+                Ok(s) => output.push_str(&s),
+                Err(e) => {
+                    output.push_str(&e);
+                    return Err(output);
+                }
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Equivalent to [`App::command`], but the output is accompanied by the links it contains,
+    /// so that the UI doesn't need to find them in the Markdown. See [`CommandOutput`].
+    pub async fn command_structured(
+        &mut self,
+        input: &str,
+    ) -> Result<CommandOutput, CommandOutput> {
+        self.command(input)
             .await
+            .map(CommandOutput::from)
+            .map_err(CommandOutput::from)
+    }
+
+    async fn run_command(&mut self, input: &str) -> Result<String, String> {
+        let command = Command::parse_input_irrefutable(input, &self.meta).await;
+
+        // Repeating a command records the command being repeated instead.
+        if !matches!(
+            command.get_type(),
+            Some(CommandType::App(AppCommand::History | AppCommand::Repeat)),
+        ) && !input.trim().is_empty()
+        {
+            self.meta.push_history(input);
+        }
+
+        let mut result = command.run(input, &mut self.meta).await;
+
+        if !self.meta.emoji {
+            result = result
+                .map(|output| strip_emoji(&output))
+                .map_err(|output| strip_emoji(&output));
+        }
+
+        if self.meta.plaintext {
+            result = result
+                .map(|output| strip_markdown(&output))
+                .map_err(|output| strip_markdown(&output));
+        }
+
+        result
+    }
+
+    /// Frontends that display output as-is, rather than rendering it as Markdown, can switch to
+    /// plain text output. Off by default.
+    pub fn set_plaintext(&mut self, plaintext: bool) {
+        self.meta.plaintext = plaintext;
+    }
+
+    /// Frontends with more room for suggestions can show more of them, or fewer with less room.
+    /// A limit of 0 turns autocomplete off. 10 by default.
+    pub fn set_autocomplete_limit(&mut self, limit: usize) {
+        self.meta.autocomplete_limit = limit;
     }
 
     /// The user has updated their input and a new set of suggestions should be populated. This
@@ -70,20 +187,148 @@ impl App {
     /// used here to allow either `String` or `&'static str`, whatever is appropriate to a given
     /// case.
     ///
-    /// Returns a maximum of 10 results.
+    /// Returns at most as many results as the limit set with [`App::set_autocomplete_limit`].
     pub async fn autocomplete(&self, input: &str) -> Vec<AutocompleteSuggestion> {
-        let mut suggestions: Vec<_> = Command::autocomplete(input, &self.meta).await;
+        if self.meta.autocomplete_limit == 0 {
+            return Vec::new();
+        }
+
+        // Only the last of several chained commands is completed.
+        let (prefix, input) = match split_commands_pos(input).last() {
+            Some(&pos) => {
+                let rest = input[pos + 1..].trim_start();
+                (&input[..input.len() - rest.len()], rest)
+            }
+            None => ("", input),
+        };
+
+        let mut suggestions: Vec<_> = Command::autocomplete(input, &self.meta)
+            .await
+            .into_iter()
+            .map(|suggestion| {
+                if prefix.is_empty() {
+                    suggestion
+                } else {
+                    AutocompleteSuggestion::new(
+                        format!("{}{}", prefix, suggestion.term),
+                        suggestion.summary,
+                    )
+                }
+            })
+            .collect();
         suggestions.sort_by(|a, b| a.term.cmp_ci(&b.term));
-        suggestions.truncate(10);
+        suggestions.truncate(self.meta.autocomplete_limit);
         suggestions
     }
 
+    /// Every top-level command along with a short description, sorted by name, eg. for building
+    /// a help menu. Unlike [`App::autocomplete`], this doesn't depend on any input or state.
+    pub fn commands() -> Vec<AutocompleteSuggestion> {
+        COMMANDS
+            .iter()
+            .map(|&(term, summary)| AutocompleteSuggestion::new(term, summary))
+            .collect()
+    }
+
+    /// The version of the app, followed by the build identifier if one was set in the
+    /// `INITIATIVE_BUILD` environment variable at compile time, eg. "initiative.sh v0.1.0 (build
+    /// 1a2b3c4)". Users can see this with the `version` command when reporting bugs.
+    pub fn version() -> String {
+        match option_env!("INITIATIVE_BUILD").filter(|build| !build.is_empty()) {
+            Some(build) => format!(
+                "initiative.sh v{} (build {})",
+                env!("CARGO_PKG_VERSION"),
+                build,
+            ),
+            None => format!("initiative.sh v{}", env!("CARGO_PKG_VERSION")),
+        }
+    }
+
     /// The part of the import flow that occurs after the user selects a file in response to the
     /// [`Event::Import`].
-    pub async fn bulk_import(&mut self, data: BackupData) -> Result<String, String> {
-        import(&mut self.meta.repository, data)
+    pub async fn bulk_import(
+        &mut self,
+        data: BackupData,
+        overwrite: bool,
+    ) -> Result<String, String> {
+        import(&mut self.meta.repository, data, overwrite)
             .await
             .map(|stats| stats.to_string())
             .map_err(|_| "Failed to import.".to_string())
     }
 }
+
+/// The message of the day without the storage warning that `motd!` appends to it. The length
+/// should always fall between the two, but it's rounded down to a character boundary anyway so
+/// that a stray multibyte character can't cause a panic.
+fn truncate_motd(motd: &str, motd_len: usize) -> &str {
+    let len = (0..=motd_len.min(motd.len()))
+        .rev()
+        .find(|&i| motd.is_char_boundary(i))
+        .unwrap_or_default();
+    &motd[..len]
+}
+
+/// Split the input on semicolons, except those within quotes, dropping any empty commands.
+fn split_commands(input: &str) -> Vec<&str> {
+    let mut start = 0;
+
+    split_commands_pos(input)
+        .into_iter()
+        .chain([input.len()])
+        .map(|pos| {
+            let command = input[start..pos].trim();
+            start = pos + 1;
+            command
+        })
+        .filter(|command| !command.is_empty())
+        .collect()
+}
+
+/// The byte offsets of the semicolons separating chained commands.
+fn split_commands_pos(input: &str) -> Vec<usize> {
+    let mut in_quotes = false;
+
+    input
+        .char_indices()
+        .filter(|&(_, c)| {
+            if c == '"' {
+                in_quotes = !in_quotes;
+            }
+            c == ';' && !in_quotes
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn split_commands_test() {
+        assert_eq!(vec!["npc"], split_commands("npc"));
+        assert_eq!(vec!["npc", "roll d20"], split_commands("npc; roll d20"));
+        assert_eq!(vec!["npc", "npc"], split_commands(" npc ;; npc; "));
+        assert_eq!(
+            vec!["an inn named \"Bed; Breakfast\"", "npc"],
+            split_commands("an inn named \"Bed; Breakfast\"; npc"),
+        );
+        assert!(split_commands(" ; ").is_empty());
+    }
+
+    #[test]
+    fn truncate_motd_test() {
+        assert_eq!("Déjà vu", truncate_motd("Déjà vu\n\n! Warning", 9));
+        assert_eq!("Déjà vu", truncate_motd("Déjà vu", 100));
+
+        // "é" and "à" are two bytes each, so these lengths fall inside them.
+        assert_eq!("D", truncate_motd("Déjà vu", 2));
+        assert_eq!("Déj", truncate_motd("Déjà vu", 5));
+        assert_eq!("", truncate_motd("é", 1));
+
+        let (motd, motd_len) = motd!("! Ünïcödé wärnïng");
+        assert_eq!(motd_len, truncate_motd(motd, motd_len).len());
+        assert!(motd.ends_with("\n\n! Ünïcödé wärnïng"));
+    }
+}
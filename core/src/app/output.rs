@@ -0,0 +1,189 @@
+use super::DiceFormula;
+use serde::Serialize;
+use std::ops::Range;
+
+/// The output of a command along with the links found in it, so that a frontend can make them
+/// clickable without re-parsing the Markdown. Returned by [`super::App::command_structured`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct CommandOutput {
+    pub text: String,
+    pub links: Vec<Link>,
+}
+
+/// A clickable span of a command's output. `range` is the byte range of the entire span within
+/// the output text, including its delimiters.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Link {
+    /// A command in `backticks` that will always work, eg. `journal`.
+    Command {
+        command: String,
+        range: Range<usize>,
+    },
+
+    /// A command in ~tildes~ that only works until the next command is run, eg. ~more~.
+    Temporary {
+        command: String,
+        range: Range<usize>,
+    },
+
+    /// A dice formula in `backticks`, eg. `2d6+3`, which rolls the dice when run as a command.
+    Dice {
+        formula: String,
+        range: Range<usize>,
+    },
+
+    /// A Markdown link to an external page, eg. [the SRD](https://...).
+    External {
+        text: String,
+        url: String,
+        range: Range<usize>,
+    },
+}
+
+impl Link {
+    pub fn range(&self) -> &Range<usize> {
+        match self {
+            Self::Command { range, .. }
+            | Self::Temporary { range, .. }
+            | Self::Dice { range, .. }
+            | Self::External { range, .. } => range,
+        }
+    }
+}
+
+impl From<String> for CommandOutput {
+    fn from(text: String) -> Self {
+        let links = find_links(&text);
+        Self { text, links }
+    }
+}
+
+fn find_links(text: &str) -> Vec<Link> {
+    let mut links = Vec::new();
+    let mut pos = 0;
+
+    while let Some(offset) = text[pos..].find(['`', '~', '[']) {
+        let start = pos + offset;
+        let rest = &text[start..];
+
+        // Double tildes are strikethrough (eg. dropped dice), not links.
+        if let Some(struck) = rest.strip_prefix("~~") {
+            pos = start + 2 + struck.find("~~").map_or(struck.len(), |end| end + 2);
+            continue;
+        }
+
+        let delimiter = rest.chars().next().unwrap();
+        let inner = &rest[1..];
+
+        let link = match delimiter {
+            '[' => inner
+                .find("](")
+                .filter(|&text_end| !inner[..text_end].contains(['[', ']', '\n']))
+                .and_then(|text_end| {
+                    let url_start = text_end + 2;
+                    inner[url_start..].find(')').map(|url_len| {
+                        let end = start + 1 + url_start + url_len + 1;
+                        Link::External {
+                            text: inner[..text_end].to_string(),
+                            url: inner[url_start..url_start + url_len].to_string(),
+                            range: start..end,
+                        }
+                    })
+                }),
+            _ => inner
+                .find(delimiter)
+                .filter(|&len| len > 0 && !inner[..len].contains('\n'))
+                .map(|len| {
+                    let content = inner[..len].to_string();
+                    let range = start..start + len + 2;
+
+                    if delimiter == '~' {
+                        Link::Temporary {
+                            command: content,
+                            range,
+                        }
+                    } else if DiceFormula::parse(&content).is_ok() {
+                        Link::Dice {
+                            formula: content,
+                            range,
+                        }
+                    } else {
+                        Link::Command {
+                            command: content,
+                            range,
+                        }
+                    }
+                }),
+        };
+
+        if let Some(link) = link {
+            pos = link.range().end;
+            links.push(link);
+        } else {
+            pos = start + 1;
+        }
+    }
+
+    links
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_string_test() {
+        let output: CommandOutput =
+            "Roll `2d6+3` or type ~more~, see `journal` or [the SRD](https://example.com)."
+                .to_string()
+                .into();
+
+        assert_eq!(
+            vec![
+                Link::Dice {
+                    formula: "2d6+3".to_string(),
+                    range: 5..12,
+                },
+                Link::Temporary {
+                    command: "more".to_string(),
+                    range: 21..27,
+                },
+                Link::Command {
+                    command: "journal".to_string(),
+                    range: 33..42,
+                },
+                Link::External {
+                    text: "the SRD".to_string(),
+                    url: "https://example.com".to_string(),
+                    range: 46..76,
+                },
+            ],
+            output.links,
+        );
+
+        for link in &output.links {
+            let span = &output.text[link.range().clone()];
+            assert!(span.ends_with(['`', '~', ')']), "{}", span);
+        }
+    }
+
+    #[test]
+    fn from_string_test_strikethrough() {
+        let output: CommandOutput = "`4d6d1` = [~~1~~ + 4 + 5 + 6] = 15".to_string().into();
+
+        assert_eq!(
+            vec![Link::Dice {
+                formula: "4d6d1".to_string(),
+                range: 0..7,
+            }],
+            output.links,
+        );
+    }
+
+    #[test]
+    fn from_string_test_unmatched() {
+        let output: CommandOutput = "A ` stray [backtick] and ~tilde".to_string().into();
+        assert!(output.links.is_empty(), "{:?}", output.links);
+    }
+}
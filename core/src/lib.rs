@@ -10,7 +10,11 @@ pub mod app;
 
 pub use app::{App, Event};
 pub use storage::backup::BackupData;
+#[cfg(feature = "file")]
+pub use storage::FileDataStore;
 pub use storage::{DataStore, MemoryDataStore, NullDataStore};
+#[cfg(feature = "sqlite")]
+pub use storage::{SqliteDataStore, SqliteError};
 pub use uuid::Uuid;
 pub use world::Thing;
 
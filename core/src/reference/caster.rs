@@ -0,0 +1,217 @@
+use crate::utils::capitalize;
+use initiative_macros::WordList;
+use std::fmt;
+
+/// The classes that cast spells using spell slots.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, WordList)]
+pub enum CasterClass {
+    Bard,
+    Cleric,
+    Druid,
+    Paladin,
+    Ranger,
+    Sorcerer,
+    Warlock,
+    Wizard,
+}
+
+const SPELL_LEVELS: [&str; 9] = [
+    "1st", "2nd", "3rd", "4th", "5th", "6th", "7th", "8th", "9th",
+];
+
+/// Spell slots of each level for a bard, cleric, druid, sorcerer or wizard of each class level.
+/// Paladins and rangers use the same table at half their level, rounded up, from 2nd level on.
+const FULL_CASTER_SLOTS: [[u8; 9]; 20] = [
+    [2, 0, 0, 0, 0, 0, 0, 0, 0],
+    [3, 0, 0, 0, 0, 0, 0, 0, 0],
+    [4, 2, 0, 0, 0, 0, 0, 0, 0],
+    [4, 3, 0, 0, 0, 0, 0, 0, 0],
+    [4, 3, 2, 0, 0, 0, 0, 0, 0],
+    [4, 3, 3, 0, 0, 0, 0, 0, 0],
+    [4, 3, 3, 1, 0, 0, 0, 0, 0],
+    [4, 3, 3, 2, 0, 0, 0, 0, 0],
+    [4, 3, 3, 3, 1, 0, 0, 0, 0],
+    [4, 3, 3, 3, 2, 0, 0, 0, 0],
+    [4, 3, 3, 3, 2, 1, 0, 0, 0],
+    [4, 3, 3, 3, 2, 1, 0, 0, 0],
+    [4, 3, 3, 3, 2, 1, 1, 0, 0],
+    [4, 3, 3, 3, 2, 1, 1, 0, 0],
+    [4, 3, 3, 3, 2, 1, 1, 1, 0],
+    [4, 3, 3, 3, 2, 1, 1, 1, 0],
+    [4, 3, 3, 3, 2, 1, 1, 1, 1],
+    [4, 3, 3, 3, 3, 1, 1, 1, 1],
+    [4, 3, 3, 3, 3, 2, 1, 1, 1],
+    [4, 3, 3, 3, 3, 2, 2, 1, 1],
+];
+
+/// A warlock's Pact Magic: the number of slots, and the spell level they are all cast at.
+const WARLOCK_SLOTS: [(u8, usize); 20] = [
+    (1, 1),
+    (2, 1),
+    (2, 2),
+    (2, 2),
+    (2, 3),
+    (2, 3),
+    (2, 4),
+    (2, 4),
+    (2, 5),
+    (2, 5),
+    (3, 5),
+    (3, 5),
+    (3, 5),
+    (3, 5),
+    (3, 5),
+    (3, 5),
+    (4, 5),
+    (4, 5),
+    (4, 5),
+    (4, 5),
+];
+
+/// The spell slots of a character of the given class and level, as a Markdown table.
+pub fn spell_slots(class: &str, level: u8) -> Result<String, String> {
+    let caster: CasterClass = class.parse().map_err(|_| {
+        format!(
+            "\"{}\" is not a spellcasting class. Try one of: {}.",
+            class,
+            CasterClass::get_words().collect::<Vec<_>>().join(", "),
+        )
+    })?;
+
+    if !(1..=20).contains(&level) {
+        return Err(format!(
+            "There is no level {} {}. Character levels go from 1 to 20.",
+            level, caster,
+        ));
+    }
+
+    let index = usize::from(level - 1);
+    let mut output = format!(
+        "# Level {} {} Spell Slots\n\n",
+        level,
+        capitalize(caster.as_str())
+    );
+
+    let slots = match caster {
+        CasterClass::Warlock => {
+            let (count, slot_level) = WARLOCK_SLOTS[index];
+            output.push_str("| Slots | Slot level |\n|--:|--:|\n");
+            output.push_str(&format!("| {} | {} |", count, SPELL_LEVELS[slot_level - 1]));
+            output.push_str("\n\n*Pact Magic slots are regained on a short or long rest.*");
+
+            // Mystic Arcanum grants one spell of each of these levels, castable once per long rest.
+            let arcana: Vec<&str> = [(11, 6), (13, 7), (15, 8), (17, 9)]
+                .into_iter()
+                .filter(|&(min_level, _)| level >= min_level)
+                .map(|(_, spell_level)| SPELL_LEVELS[spell_level - 1])
+                .collect();
+            if !arcana.is_empty() {
+                output.push_str(&format!(
+                    "\\\n*Mystic Arcanum (each once per long rest): {}.*",
+                    arcana.join(", "),
+                ));
+            }
+
+            return Ok(output);
+        }
+        CasterClass::Paladin | CasterClass::Ranger if level == 1 => {
+            return Ok(format!(
+                "A level 1 {} has no spell slots yet. {}s gain spellcasting at level 2.",
+                caster,
+                capitalize(caster.as_str()),
+            ));
+        }
+        CasterClass::Paladin | CasterClass::Ranger => FULL_CASTER_SLOTS[index / 2],
+        _ => FULL_CASTER_SLOTS[index],
+    };
+
+    let slots: Vec<(&str, u8)> = SPELL_LEVELS
+        .into_iter()
+        .zip(slots)
+        .filter(|&(_, count)| count > 0)
+        .collect();
+
+    output.push('|');
+    slots
+        .iter()
+        .for_each(|(spell_level, _)| output.push_str(&format!(" {} |", spell_level)));
+    output.push_str("\n|");
+    slots.iter().for_each(|_| output.push_str("--:|"));
+    output.push_str("\n|");
+    slots
+        .iter()
+        .for_each(|(_, count)| output.push_str(&format!(" {} |", count)));
+
+    Ok(output)
+}
+
+impl fmt::Display for CasterClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn spell_slots_test() {
+        assert_eq!(
+            Ok(
+                "# Level 5 Wizard Spell Slots\n\n| 1st | 2nd | 3rd |\n|--:|--:|--:|\n| 4 | 3 | 2 |"
+                    .to_string()
+            ),
+            spell_slots("wizard", 5),
+        );
+        assert_eq!(
+            Ok("# Level 1 Cleric Spell Slots\n\n| 1st |\n|--:|\n| 2 |".to_string()),
+            spell_slots("Cleric", 1),
+        );
+        assert_eq!(
+            Ok("# Level 9 Paladin Spell Slots\n\n| 1st | 2nd | 3rd |\n|--:|--:|--:|\n| 4 | 3 | 2 |"
+                .to_string()),
+            spell_slots("paladin", 9),
+        );
+        assert!(spell_slots("wizard", 20)
+            .unwrap()
+            .ends_with("| 4 | 3 | 3 | 3 | 3 | 2 | 2 | 1 | 1 |"));
+        assert!(spell_slots("ranger", 20)
+            .unwrap()
+            .ends_with("| 4 | 3 | 3 | 3 | 2 |"));
+    }
+
+    #[test]
+    fn spell_slots_warlock_test() {
+        assert_eq!(
+            Ok("# Level 5 Warlock Spell Slots\n\n| Slots | Slot level |\n|--:|--:|\n| 2 | 3rd |\n\n*Pact Magic slots are regained on a short or long rest.*".to_string()),
+            spell_slots("warlock", 5),
+        );
+        assert!(spell_slots("warlock", 15)
+            .unwrap()
+            .ends_with("| 3 | 5th |\n\n*Pact Magic slots are regained on a short or long rest.*\\\n*Mystic Arcanum (each once per long rest): 6th, 7th, 8th.*"));
+    }
+
+    #[test]
+    fn spell_slots_error_test() {
+        assert_eq!(
+            Ok(
+                "A level 1 ranger has no spell slots yet. Rangers gain spellcasting at level 2."
+                    .to_string()
+            ),
+            spell_slots("ranger", 1),
+        );
+        assert_eq!(
+            Err("There is no level 21 wizard. Character levels go from 1 to 20.".to_string()),
+            spell_slots("wizard", 21),
+        );
+        assert_eq!(
+            Err("There is no level 0 bard. Character levels go from 1 to 20.".to_string()),
+            spell_slots("bard", 0),
+        );
+        assert_eq!(
+            Err("\"fighter\" is not a spellcasting class. Try one of: bard, cleric, druid, paladin, ranger, sorcerer, warlock, wizard.".to_string()),
+            spell_slots("fighter", 5),
+        );
+    }
+}
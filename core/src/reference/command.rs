@@ -1,41 +1,76 @@
-use super::{Condition, Item, ItemCategory, MagicItem, Spell, Trait};
+use super::caster::spell_slots;
+use super::treasure::generate_treasure;
+use super::{Condition, Item, ItemCategory, MagicItem, Monster, Spell, Trait};
 use crate::app::{
-    AppMeta, Autocomplete, AutocompleteSuggestion, CommandMatches, ContextAwareParse, Runnable,
+    AppMeta, Autocomplete, AutocompleteSuggestion, CommandMatches, ContextAwareParse, DiceFormula,
+    Runnable,
 };
-use crate::utils::CaseInsensitiveStr;
+use crate::utils::{capitalize, CaseInsensitiveStr};
 use async_trait::async_trait;
-use caith::Roller;
 use std::fmt;
 use std::iter::repeat;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ReferenceCommand {
+    Caster {
+        class: String,
+        level: u8,
+    },
     Condition(Condition),
+    Conditions,
     Item(Item),
     ItemCategory(ItemCategory),
+    ItemCategories,
     MagicItem(MagicItem),
+    MagicItems {
+        rarity: Option<String>,
+    },
+    Monster(Monster),
+    Monsters,
     OpenGameLicense,
     Spell(Spell),
-    Spells,
+    Spells {
+        level: Option<u8>,
+        school: Option<String>,
+    },
     Trait(Trait),
+    Treasure {
+        challenge_rating: String,
+    },
 }
 
 #[async_trait(?Send)]
 impl Runnable for ReferenceCommand {
-    async fn run(self, _input: &str, _app_meta: &mut AppMeta) -> Result<String, String> {
+    async fn run(self, _input: &str, app_meta: &mut AppMeta) -> Result<String, String> {
         let (output, name) = match self {
+            Self::Caster { class, level } => (spell_slots(&class, level)?, "This table"),
             Self::Condition(condition) => (format!("{}", condition), condition.get_name()),
+            Self::Conditions => (Condition::get_list().to_string(), "This listing"),
             Self::Item(item) => (format!("{}", item), item.get_name()),
             Self::ItemCategory(category) => (format!("{}", category), "This listing"),
+            Self::ItemCategories => (ItemCategory::get_list().to_string(), "This listing"),
             Self::MagicItem(magic_item) => (format!("{}", magic_item), magic_item.get_name()),
+            Self::MagicItems { rarity } => (magic_item_list(rarity.as_deref())?, "This listing"),
+            Self::Monster(monster) => (format!("{}", monster), monster.get_name()),
+            Self::Monsters => (Monster::get_list().to_string(), "This listing"),
             Self::OpenGameLicense => {
                 return Ok(include_str!("../../../data/ogl-1.0a.md")
                     .trim_end()
                     .to_string());
             }
             Self::Spell(spell) => (format!("{}", spell), spell.get_name()),
-            Self::Spells => (Spell::get_list().to_string(), "This listing"),
+            Self::Spells {
+                level: None,
+                school: None,
+            } => (Spell::get_list().to_string(), "This listing"),
+            Self::Spells { level, school } => {
+                (spell_list(level, school.as_deref())?, "This listing")
+            }
             Self::Trait(t) => (t.to_string(), t.get_name()),
+            Self::Treasure { challenge_rating } => {
+                app_meta.reseed();
+                return generate_treasure(&challenge_rating, &mut app_meta.rng);
+            }
         };
 
         Ok(format!(
@@ -51,8 +86,39 @@ impl ContextAwareParse for ReferenceCommand {
     async fn parse_input(input: &str, _app_meta: &AppMeta) -> CommandMatches<Self> {
         let mut matches = if input.eq_ci("Open Game License") {
             CommandMatches::new_canonical(Self::OpenGameLicense)
+        } else if input.eq_ci("srd conditions") {
+            CommandMatches::new_canonical(Self::Conditions)
+        } else if input.eq_ci("srd item categories") {
+            CommandMatches::new_canonical(Self::ItemCategories)
+        } else if input.eq_ci("srd monsters") {
+            CommandMatches::new_canonical(Self::Monsters)
         } else if input.eq_ci("srd spells") {
-            CommandMatches::new_canonical(Self::Spells)
+            CommandMatches::new_canonical(Self::Spells {
+                level: None,
+                school: None,
+            })
+        } else if let Some((level, school)) = input
+            .strip_prefix_ci("srd spells ")
+            .and_then(parse_spell_filters)
+        {
+            CommandMatches::new_canonical(Self::Spells { level, school })
+        } else if let Some(challenge_rating) = input
+            .strip_prefix_ci("generate treasure ")
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+        {
+            CommandMatches::new_canonical(Self::Treasure {
+                challenge_rating: challenge_rating.to_lowercase(),
+            })
+        } else if let Some((class, level)) =
+            input.strip_prefix_ci("srd caster ").and_then(parse_caster)
+        {
+            CommandMatches::new_canonical(Self::Caster { class, level })
+        } else if let Some(rarity) = input
+            .strip_prefix_ci("srd magic items")
+            .and_then(parse_rarity_filter)
+        {
+            CommandMatches::new_canonical(Self::MagicItems { rarity })
         } else if let Some(condition) = input
             .strip_prefix_ci("srd condition ")
             .and_then(|s| s.parse().ok())
@@ -73,6 +139,11 @@ impl ContextAwareParse for ReferenceCommand {
             .and_then(|s| s.parse().ok())
         {
             CommandMatches::new_canonical(Self::MagicItem(magic_item))
+        } else if let Some(monster) = input
+            .strip_prefix_ci("srd monster ")
+            .and_then(|s| s.parse().ok())
+        {
+            CommandMatches::new_canonical(Self::Monster(monster))
         } else if let Some(spell) = input
             .strip_prefix_ci("srd spell ")
             .and_then(|s| s.parse().ok())
@@ -99,14 +170,43 @@ impl ContextAwareParse for ReferenceCommand {
         if let Ok(magic_item) = input.parse() {
             matches.push_fuzzy(Self::MagicItem(magic_item));
         }
+        if let Ok(monster) = input.parse() {
+            matches.push_fuzzy(Self::Monster(monster));
+        }
         if let Ok(spell) = input.parse() {
             matches.push_fuzzy(Self::Spell(spell));
         }
         if let Ok(character_trait) = input.parse() {
             matches.push_fuzzy(Self::Trait(character_trait));
         }
+        if let Some((class, level)) = input.strip_prefix_ci("caster ").and_then(parse_caster) {
+            matches.push_fuzzy(Self::Caster { class, level });
+        }
+        if input.eq_ci("conditions") {
+            matches.push_fuzzy(Self::Conditions);
+        }
+        if input.eq_ci("item categories") {
+            matches.push_fuzzy(Self::ItemCategories);
+        }
+        if input.eq_ci("monsters") {
+            matches.push_fuzzy(Self::Monsters);
+        }
+        if let Some(rarity) = input
+            .strip_prefix_ci("magic items")
+            .and_then(parse_rarity_filter)
+        {
+            matches.push_fuzzy(Self::MagicItems { rarity });
+        }
         if input.eq_ci("spells") {
-            matches.push_fuzzy(Self::Spells);
+            matches.push_fuzzy(Self::Spells {
+                level: None,
+                school: None,
+            });
+        } else if let Some((level, school)) = input
+            .strip_prefix_ci("spells ")
+            .and_then(parse_spell_filters)
+        {
+            matches.push_fuzzy(Self::Spells { level, school });
         }
 
         matches
@@ -118,6 +218,12 @@ impl Autocomplete for ReferenceCommand {
     async fn autocomplete(input: &str, _app_meta: &AppMeta) -> Vec<AutocompleteSuggestion> {
         [
             ("Open Game License", "SRD license"),
+            ("caster [class] [level]", "SRD spell slots"),
+            ("generate treasure [CR]", "coins and magic items"),
+            ("conditions", "SRD index"),
+            ("item categories", "SRD index"),
+            ("magic items", "SRD index"),
+            ("monsters", "SRD index"),
             ("spells", "SRD index"),
         ]
         .into_iter()
@@ -126,10 +232,38 @@ impl Autocomplete for ReferenceCommand {
         .chain(Item::get_words().zip(repeat("SRD item")))
         .chain(ItemCategory::get_words().zip(repeat("SRD item category")))
         .chain(MagicItem::get_words().zip(repeat("SRD magic item")))
+        .chain(Monster::get_words().zip(repeat("SRD monster")))
         .chain(Trait::get_words().zip(repeat("SRD trait")))
         .filter(|(term, _)| term.starts_with_ci(input))
-        .take(10)
+        .fold(
+            Vec::new(),
+            |mut suggestions: Vec<(&str, &str)>, (term, summary)| {
+                // A name in more than one category (eg. the Darkvision spell and trait, or the
+                // Shield spell and item) is only suggested once. Spells are what's looked up most
+                // often mid-game, so they win; otherwise a magic item is more specific than an
+                // item, and any other collision goes to whichever category came first.
+                const PRECEDENCE: [&str; 3] = ["SRD spell", "SRD magic item", "SRD item"];
+                let rank = |summary: &str| {
+                    PRECEDENCE
+                        .iter()
+                        .position(|s| *s == summary)
+                        .unwrap_or(PRECEDENCE.len())
+                };
+
+                if let Some(existing) = suggestions.iter_mut().find(|(t, _)| *t == term) {
+                    if rank(summary) < rank(existing.1) {
+                        existing.1 = summary;
+                    }
+                } else if suggestions.len() < 10 {
+                    suggestions.push((term, summary));
+                }
+                suggestions
+            },
+        )
+        .into_iter()
         .map(|(term, summary)| AutocompleteSuggestion::new(term, summary))
+        .chain(autocomplete_spell_filters(input))
+        .chain(autocomplete_rarity_filter(input))
         .collect()
     }
 }
@@ -137,95 +271,403 @@ impl Autocomplete for ReferenceCommand {
 impl fmt::Display for ReferenceCommand {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         match self {
+            Self::Caster { class, level } => write!(f, "srd caster {} {}", class, level),
             Self::Condition(condition) => write!(f, "srd condition {}", condition.get_name()),
+            Self::Conditions => write!(f, "srd conditions"),
             Self::Item(item) => write!(f, "srd item {}", item.get_name()),
             Self::ItemCategory(category) => write!(f, "srd item category {}", category.get_name()),
+            Self::ItemCategories => write!(f, "srd item categories"),
             Self::MagicItem(item) => write!(f, "srd magic item {}", item.get_name()),
+            Self::MagicItems { rarity: None } => write!(f, "srd magic items"),
+            Self::MagicItems {
+                rarity: Some(rarity),
+            } => write!(f, "srd magic items rarity {}", rarity),
+            Self::Monster(monster) => write!(f, "srd monster {}", monster.get_name()),
+            Self::Monsters => write!(f, "srd monsters"),
             Self::OpenGameLicense => write!(f, "Open Game License"),
             Self::Spell(spell) => write!(f, "srd spell {}", spell.get_name()),
-            Self::Spells => write!(f, "srd spells"),
+            Self::Spells { level, school } => {
+                write!(f, "srd spells")?;
+                if let Some(level) = level {
+                    write!(f, " level {}", level)?;
+                }
+                if let Some(school) = school {
+                    write!(f, " school {}", school)?;
+                }
+                Ok(())
+            }
             Self::Trait(species_trait) => write!(f, "srd trait {}", species_trait.get_name()),
+            Self::Treasure { challenge_rating } => {
+                write!(f, "generate treasure {}", challenge_rating)
+            }
         }
     }
 }
 
-fn linkify_dice(input: &str) -> String {
-    let mut result = String::with_capacity(input.len());
-    let mut input_offset = 0;
+/// Parse the filters following `srd spells`, eg. "level 3 school evocation". Returns `None` if
+/// there are no filters or any of them can't be understood.
+fn parse_spell_filters(input: &str) -> Option<(Option<u8>, Option<String>)> {
+    let mut words = input.split_whitespace();
+    let (mut level, mut school) = (None, None);
 
-    let mut hold = String::new();
-    let mut hold_offset = 0;
-    let mut hold_active = false;
+    while let Some(word) = words.next() {
+        if word.eq_ci("level") && level.is_none() {
+            level = Some(words.next()?.parse().ok()?);
+        } else if word.eq_ci("school") && school.is_none() {
+            school = Some(words.next()?.to_lowercase());
+        } else {
+            return None;
+        }
+    }
 
-    for part in input.split_inclusive(|c: char| c.is_whitespace() || c.is_ascii_punctuation()) {
-        if !hold_active
-            && part.contains(|c: char| c.is_ascii_digit())
-            && part.contains(&['d', 'D'][..])
-        {
-            hold_active = true;
-            hold_offset = input_offset;
-        } else if hold_active && part.contains(char::is_alphabetic) {
-            hold_active = false;
+    if level.is_some() || school.is_some() {
+        Some((level, school))
+    } else {
+        None
+    }
+}
+
+/// Parse the class and level following `srd caster`, eg. "wizard 5". The level is checked when
+/// the command is run, so that an out-of-range level gets a helpful error.
+fn parse_caster(input: &str) -> Option<(String, u8)> {
+    let (class, level) = input.trim().rsplit_once(' ')?;
+    let class = class.trim();
+
+    if class.is_empty() {
+        None
+    } else {
+        Some((class.to_lowercase(), level.parse().ok()?))
+    }
+}
+
+fn spell_list(level: Option<u8>, school: Option<&str>) -> Result<String, String> {
+    if let Some(school) = school {
+        if !Spell::get_all().any(|spell| spell.get_school() == school) {
+            return Err(format!(
+                "\"{}\" is not a school of magic. Try one of: {}.",
+                school,
+                spell_schools().join(", "),
+            ));
         }
+    }
 
-        if hold_active {
-            hold.push_str(part);
-        } else {
-            while !hold.is_empty() {
-                let hold_trimmed = hold.trim();
-                if hold_trimmed.contains(&['d', 'D'][..])
-                    && Roller::new(hold_trimmed).map_or(false, |r| r.roll().is_ok())
-                {
-                    result.push('`');
-                    result.push_str(hold_trimmed);
-                    result.push('`');
-                    result.push_str(&input[hold_offset + hold_trimmed.len()..input_offset]);
-                    hold.clear();
-                    break;
-                }
+    let title = match (level, school) {
+        (Some(0), Some(school)) => format!("{} Cantrips", capitalize(school)),
+        (Some(0), None) => "Cantrips".to_string(),
+        (Some(level), Some(school)) => format!("Level {} {} Spells", level, capitalize(school)),
+        (Some(level), None) => format!("Level {} Spells", level),
+        (None, Some(school)) => format!("{} Spells", capitalize(school)),
+        (None, None) => "Spells".to_string(),
+    };
 
-                if let Some(pos) =
-                    hold.rfind(|c: char| c.is_whitespace() || c.is_ascii_punctuation())
-                {
-                    hold.truncate(pos);
+    let mut output = format!("# {}", title);
+    let mut is_empty = true;
 
-                    if hold.is_empty() {
-                        result.push_str(&input[hold_offset..input_offset]);
-                    }
-                } else {
-                    result.push_str(&input[hold_offset..input_offset]);
-                    hold.clear();
-                }
+    for spell in Spell::get_all().filter(|spell| {
+        level.is_none_or(|level| spell.get_level() == level)
+            && school.is_none_or(|school| spell.get_school() == school)
+    }) {
+        output.push_str("\n* ");
+        output.push_str(spell.get_summary());
+        is_empty = false;
+    }
+
+    if is_empty {
+        Err(format!("There are no {} in the SRD.", title.to_lowercase()))
+    } else {
+        Ok(output)
+    }
+}
+
+fn spell_schools() -> Vec<&'static str> {
+    let mut schools: Vec<_> = Spell::get_all().map(|spell| spell.get_school()).collect();
+    schools.sort_unstable();
+    schools.dedup();
+    schools
+}
+
+fn autocomplete_spell_filters(input: &str) -> Vec<AutocompleteSuggestion> {
+    let (prefix, filters) = if let Some(filters) = input.strip_prefix_ci("srd spells ") {
+        (&input[..input.len() - filters.len()], filters)
+    } else if let Some(filters) = input.strip_prefix_ci("spells ") {
+        (&input[..input.len() - filters.len()], filters)
+    } else {
+        return Vec::new();
+    };
+
+    if let Some(school_start) = filters.strip_prefix_ci("school ") {
+        spell_schools()
+            .into_iter()
+            .filter(|school| school.starts_with_ci(school_start))
+            .map(|school| {
+                AutocompleteSuggestion::new(
+                    format!("{}school {}", prefix, school),
+                    format!("SRD {} spells", school),
+                )
+            })
+            .collect()
+    } else {
+        [
+            ("level [0-9]", "SRD spells of a level"),
+            ("school [school]", "SRD spells of a school"),
+        ]
+        .into_iter()
+        .filter(|(term, _)| term.starts_with_ci(filters))
+        .map(|(term, summary)| AutocompleteSuggestion::new(format!("{}{}", prefix, term), summary))
+        .collect()
+    }
+}
+
+/// Parse what follows `srd magic items`: either nothing, or a rarity such as " rarity very rare".
+fn parse_rarity_filter(input: &str) -> Option<Option<String>> {
+    if input.is_empty() {
+        Some(None)
+    } else {
+        input
+            .strip_prefix_ci(" rarity ")
+            .map(|rarity| rarity.trim())
+            .filter(|rarity| !rarity.is_empty())
+            .map(|rarity| {
+                Some(
+                    rarity
+                        .split_whitespace()
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                        .to_lowercase(),
+                )
+            })
+    }
+}
+
+fn magic_item_list(rarity: Option<&str>) -> Result<String, String> {
+    let mut output = if let Some(rarity) = rarity {
+        if !MagicItem::RARITIES.contains(&rarity) {
+            return Err(format!(
+                "\"{}\" is not a magic item rarity. Try one of: {}.",
+                rarity,
+                MagicItem::RARITIES.join(", "),
+            ));
+        }
+
+        format!("# {} Magic Items", capitalize(rarity))
+    } else {
+        "# Magic Items".to_string()
+    };
+
+    let mut magic_items: Vec<_> = MagicItem::get_all()
+        .filter(|item| rarity.is_none_or(|rarity| item.get_rarities().contains(&rarity)))
+        .map(|item| item.get_name())
+        .collect();
+    magic_items.sort_unstable();
+
+    if magic_items.is_empty() {
+        return Err(format!(
+            "There are no {} magic items in the SRD.",
+            rarity.unwrap_or_default(),
+        ));
+    }
+
+    for name in magic_items {
+        output.push_str(&format!("\n* `{}`", name));
+    }
+
+    Ok(output)
+}
+
+fn autocomplete_rarity_filter(input: &str) -> Vec<AutocompleteSuggestion> {
+    let (prefix, filter) = if let Some(filter) = input.strip_prefix_ci("srd magic items ") {
+        (&input[..input.len() - filter.len()], filter)
+    } else if let Some(filter) = input.strip_prefix_ci("magic items ") {
+        (&input[..input.len() - filter.len()], filter)
+    } else {
+        return Vec::new();
+    };
+
+    if let Some(rarity_start) = filter.strip_prefix_ci("rarity ") {
+        MagicItem::RARITIES
+            .iter()
+            .filter(|rarity| rarity.starts_with_ci(rarity_start))
+            .map(|rarity| {
+                AutocompleteSuggestion::new(
+                    format!("{}rarity {}", prefix, rarity),
+                    format!("SRD {} magic items", rarity),
+                )
+            })
+            .collect()
+    } else if "rarity [rarity]".starts_with_ci(filter) {
+        vec![AutocompleteSuggestion::new(
+            format!("{}rarity [rarity]", prefix),
+            "SRD magic items of a rarity",
+        )]
+    } else {
+        Vec::new()
+    }
+}
+
+/// Wrap dice formulas in backticks so that clicking them rolls the dice. Only formulas that the
+/// `roll` command understands are linked.
+fn linkify_dice(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+    let mut input_offset = 0;
+    let mut hold_offset = None;
+
+    for part in input.split_inclusive(|c: char| c.is_whitespace() || c.is_ascii_punctuation()) {
+        // Formulas with several groups of dice, like 2d6+1d4, continue through each group.
+        let is_dice = part.contains(|c: char| c.is_ascii_digit()) && part.contains(&['d', 'D'][..]);
+
+        match hold_offset {
+            None if is_dice => hold_offset = Some(input_offset),
+            Some(offset) if !is_dice && part.contains(char::is_alphabetic) => {
+                push_linkified(&input[offset..input_offset], &mut result);
+                hold_offset = None;
             }
+            _ => {}
+        }
 
+        if hold_offset.is_none() {
             result.push_str(part);
         }
 
         input_offset += part.len();
     }
 
-    result.push_str(&hold);
+    if let Some(offset) = hold_offset {
+        push_linkified(&input[offset..], &mut result);
+    }
+
     result
 }
 
+/// Push `text` to `result`, wrapping the longest dice formula at its start in backticks. Trailing
+/// whitespace and punctuation (other than a closing parenthesis) are never part of the formula.
+fn push_linkified(text: &str, result: &mut String) {
+    let mut end = text.len();
+
+    loop {
+        let formula = text[..end].trim_end_matches(|c: char| {
+            c.is_whitespace() || (c.is_ascii_punctuation() && c != ')')
+        });
+
+        if formula.contains(&['d', 'D'][..]) && DiceFormula::parse(formula).is_ok() {
+            result.push('`');
+            result.push_str(formula);
+            result.push('`');
+            result.push_str(&text[formula.len()..]);
+            return;
+        }
+
+        if let Some(pos) = formula.rfind(|c: char| c.is_whitespace() || c.is_ascii_punctuation()) {
+            end = pos;
+        } else {
+            break;
+        }
+    }
+
+    result.push_str(text);
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::app::{assert_autocomplete, AppCommand, Command};
     use crate::storage::NullDataStore;
     use crate::Event;
     use tokio_test::block_on;
 
+    #[test]
+    fn linkify_dice_test() {
+        assert_eq!(
+            "A target takes `8d6` fire damage, or `2d6+1d4+3` if it's wet.",
+            linkify_dice("A target takes 8d6 fire damage, or 2d6+1d4+3 if it's wet."),
+        );
+        assert_eq!(
+            "It has `1d4 + 1` charges and regains `1d6` daily.",
+            linkify_dice("It has 1d4 + 1 charges and regains 1d6 daily."),
+        );
+        assert_eq!("Dd and 3rd", linkify_dice("Dd and 3rd"));
+    }
+
+    #[test]
+    fn linkify_dice_punctuation_test() {
+        assert_eq!("It deals `1d6`.", linkify_dice("It deals 1d6."));
+        assert_eq!(
+            "Roll `2d6`, then add `1d4` (or `1d8`): whichever is higher.",
+            linkify_dice("Roll 2d6, then add 1d4 (or 1d8): whichever is higher."),
+        );
+        assert_eq!(
+            "It deals `3d6` damage. Then it rests.",
+            linkify_dice("It deals 3d6 damage. Then it rests."),
+        );
+        assert_eq!(
+            "Regains `1d4+1`; it can't exceed `4d4`!",
+            linkify_dice("Regains 1d4+1; it can't exceed 4d4!"),
+        );
+        assert_eq!("(`4d4`)", linkify_dice("(4d4)"));
+    }
+
+    #[test]
+    fn linkify_dice_single_die_test() {
+        assert_eq!("roll `d20`", linkify_dice("roll d20"));
+        assert_eq!("Roll a `D6`.", linkify_dice("Roll a D6."));
+        assert_eq!("`D20 + 2`, or `d4`", linkify_dice("D20 + 2, or d4"));
+        assert_eq!("the 2nd floor", linkify_dice("the 2nd floor"));
+        assert_eq!("a 3D model", linkify_dice("a 3D model"));
+        assert_eq!("3d or D&D", linkify_dice("3d or D&D"));
+    }
+
+    #[test]
+    fn linkified_dice_can_be_rolled_test() {
+        let mut app_meta = app_meta();
+        let output =
+            linkify_dice("It deals 2d6+1d4+3 damage plus 1d8 poison damage. Roll a D20 or d6.");
+
+        let formulas: Vec<&str> = output.split('`').skip(1).step_by(2).collect();
+        assert_eq!(vec!["2d6+1d4+3", "1d8", "D20", "d6"], formulas);
+
+        for formula in formulas {
+            assert_eq!(
+                CommandMatches::new_fuzzy(AppCommand::Roll(formula.to_string())),
+                block_on(AppCommand::parse_input(formula, &app_meta)),
+            );
+
+            let command = block_on(Command::parse_input_irrefutable(formula, &app_meta));
+            assert!(
+                block_on(command.run(formula, &mut app_meta)).is_ok(),
+                "{}",
+                formula,
+            );
+        }
+    }
+
     #[test]
     fn display_test() {
         let app_meta = app_meta();
 
         [
+            ReferenceCommand::Caster {
+                class: "wizard".to_string(),
+                level: 5,
+            },
+            ReferenceCommand::Condition(Condition::Blinded),
+            ReferenceCommand::Conditions,
             ReferenceCommand::Spell(Spell::Shield),
-            ReferenceCommand::Spells,
+            spells(None, None),
+            spells(Some(3), None),
+            spells(Some(0), Some("evocation")),
             ReferenceCommand::Item(Item::Shield),
             ReferenceCommand::ItemCategory(ItemCategory::Shields),
+            ReferenceCommand::ItemCategories,
             ReferenceCommand::MagicItem(MagicItem::DeckOfManyThings),
+            ReferenceCommand::MagicItems { rarity: None },
+            ReferenceCommand::MagicItems {
+                rarity: Some("very rare".to_string()),
+            },
+            ReferenceCommand::Monster(Monster::Goblin),
+            ReferenceCommand::Monsters,
             ReferenceCommand::OpenGameLicense,
+            ReferenceCommand::Treasure {
+                challenge_rating: "1/4".to_string(),
+            },
         ]
         .into_iter()
         .for_each(|command| {
@@ -251,6 +693,223 @@ mod test {
         });
     }
 
+    #[test]
+    fn parse_input_test() {
+        let app_meta = app_meta();
+
+        for input in ["spells", "Spells", "SPELLS"] {
+            assert_eq!(
+                CommandMatches::new_fuzzy(spells(None, None)),
+                block_on(ReferenceCommand::parse_input(input, &app_meta)),
+                "{}",
+                input,
+            );
+        }
+
+        assert_eq!(
+            CommandMatches::new_fuzzy(spells(Some(1), Some("abjuration"))),
+            block_on(ReferenceCommand::parse_input(
+                "spells school Abjuration level 1",
+                &app_meta,
+            )),
+        );
+
+        for input in [
+            "srd spells level",
+            "srd spells level one",
+            "srd spells level 1 level 2",
+            "srd spells potato",
+        ] {
+            assert_eq!(
+                CommandMatches::default(),
+                block_on(ReferenceCommand::parse_input(input, &app_meta)),
+                "{}",
+                input,
+            );
+        }
+
+        assert_eq!(
+            CommandMatches::new_fuzzy(ReferenceCommand::Monsters),
+            block_on(ReferenceCommand::parse_input("Monsters", &app_meta)),
+        );
+    }
+
+    #[test]
+    fn spell_list_test() {
+        let mut app_meta = app_meta();
+
+        let output = block_on(spells(Some(1), None).run("", &mut app_meta)).unwrap();
+        assert!(output.starts_with("# Level 1 Spells\n* "), "{}", output);
+        assert!(
+            output.contains("* `Alarm` (1st-level abjuration)"),
+            "{}",
+            output
+        );
+        assert!(!output.contains("2nd-level"), "{}", output);
+
+        let output = block_on(spells(Some(0), Some("conjuration")).run("", &mut app_meta)).unwrap();
+        assert!(
+            output.starts_with("# Conjuration Cantrips\n* "),
+            "{}",
+            output
+        );
+        assert!(
+            output.contains("* `Acid Splash` (conjuration cantrip)"),
+            "{}",
+            output
+        );
+        assert!(!output.contains("-level"), "{}", output);
+
+        assert_eq!(
+            Err("There are no level 10 spells in the SRD.".to_string()),
+            block_on(spells(Some(10), None).run("", &mut app_meta)),
+        );
+
+        let error = block_on(spells(None, Some("potato")).run("", &mut app_meta)).unwrap_err();
+        assert!(
+            error.starts_with("\"potato\" is not a school of magic. Try one of: abjuration, "),
+            "{}",
+            error,
+        );
+
+        assert_autocomplete(
+            &[
+                ("spells school enchantment", "SRD enchantment spells"),
+                ("spells school evocation", "SRD evocation spells"),
+            ][..],
+            block_on(ReferenceCommand::autocomplete("spells school e", &app_meta)),
+        );
+        assert_autocomplete(
+            &[
+                ("srd spells level [0-9]", "SRD spells of a level"),
+                ("srd spells school [school]", "SRD spells of a school"),
+            ][..],
+            block_on(ReferenceCommand::autocomplete("srd spells ", &app_meta)),
+        );
+    }
+
+    #[test]
+    fn caster_test() {
+        let mut app_meta = app_meta();
+
+        assert_eq!(
+            CommandMatches::new_fuzzy(ReferenceCommand::Caster {
+                class: "wizard".to_string(),
+                level: 5,
+            }),
+            block_on(ReferenceCommand::parse_input("Caster Wizard 5", &app_meta)),
+        );
+
+        for input in [
+            "srd caster wizard",
+            "srd caster 5",
+            "srd caster wizard five",
+        ] {
+            assert_eq!(
+                CommandMatches::default(),
+                block_on(ReferenceCommand::parse_input(input, &app_meta)),
+                "{}",
+                input,
+            );
+        }
+
+        let output = block_on(
+            ReferenceCommand::Caster {
+                class: "wizard".to_string(),
+                level: 5,
+            }
+            .run("", &mut app_meta),
+        )
+        .unwrap();
+        assert!(
+            output.starts_with("# Level 5 Wizard Spell Slots\n\n| 1st | 2nd | 3rd |"),
+            "{}",
+            output,
+        );
+        assert!(
+            output
+                .ends_with("*This table is Open Game Content subject to the `Open Game License`.*"),
+            "{}",
+            output,
+        );
+
+        assert_eq!(
+            Err("There is no level 25 wizard. Character levels go from 1 to 20.".to_string()),
+            block_on(
+                ReferenceCommand::Caster {
+                    class: "wizard".to_string(),
+                    level: 25,
+                }
+                .run("", &mut app_meta),
+            ),
+        );
+    }
+
+    #[test]
+    fn magic_item_list_test() {
+        let mut app_meta = app_meta();
+
+        let output = block_on(
+            ReferenceCommand::MagicItems {
+                rarity: Some("legendary".to_string()),
+            }
+            .run("", &mut app_meta),
+        )
+        .unwrap();
+        assert!(
+            output.starts_with("# Legendary Magic Items\n* "),
+            "{}",
+            output,
+        );
+        assert!(output.contains("* `Deck of Many Things`"), "{}", output);
+        assert!(!output.contains("* `Flame Tongue`"), "{}", output);
+
+        assert_eq!(
+            Err("\"mythic\" is not a magic item rarity. Try one of: common, uncommon, rare, very rare, legendary, artifact.".to_string()),
+            block_on(
+                ReferenceCommand::MagicItems {
+                    rarity: Some("mythic".to_string()),
+                }
+                .run("", &mut app_meta),
+            ),
+        );
+
+        assert_eq!(
+            CommandMatches::new_fuzzy(ReferenceCommand::MagicItems {
+                rarity: Some("very rare".to_string()),
+            }),
+            block_on(ReferenceCommand::parse_input(
+                "Magic Items rarity Very  Rare",
+                &app_meta,
+            )),
+        );
+
+        assert_autocomplete(
+            &[("magic items rarity uncommon", "SRD uncommon magic items")][..],
+            block_on(ReferenceCommand::autocomplete(
+                "magic items rarity u",
+                &app_meta,
+            )),
+        );
+        assert_autocomplete(
+            &[(
+                "srd magic items rarity [rarity]",
+                "SRD magic items of a rarity",
+            )][..],
+            block_on(ReferenceCommand::autocomplete(
+                "srd magic items ",
+                &app_meta,
+            )),
+        );
+    }
+
+    fn spells(level: Option<u8>, school: Option<&str>) -> ReferenceCommand {
+        ReferenceCommand::Spells {
+            level,
+            school: school.map(|s| s.to_string()),
+        }
+    }
+
     fn event_dispatcher(_event: Event) {}
 
     fn app_meta() -> AppMeta {
@@ -1,6 +1,8 @@
 pub use command::ReferenceCommand;
 
+mod caster;
 mod command;
+mod treasure;
 
 use initiative_macros::reference_enum;
 
@@ -12,6 +14,8 @@ reference_enum!(ItemCategory);
 
 reference_enum!(MagicItem);
 
+reference_enum!(Monster);
+
 reference_enum!(Spell);
 
 reference_enum!(Trait);
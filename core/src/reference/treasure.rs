@@ -0,0 +1,168 @@
+use super::{MagicItem, ReferenceCommand};
+use crate::app::DiceFormula;
+use rand::prelude::*;
+
+/// Treasure carried by creatures of a range of challenge ratings, loosely following the usual
+/// guidance for each tier of play.
+struct TreasureTier {
+    max_challenge_rating: u8,
+
+    /// A dice formula and denomination for each kind of coin.
+    coins: &'static [(&'static str, &'static str)],
+
+    /// Percent chance that the treasure includes a magic item.
+    magic_item_chance: u8,
+
+    /// Rarities that a magic item in the treasure may have.
+    rarities: &'static [&'static str],
+}
+
+const TREASURE_TIERS: &[TreasureTier] = &[
+    TreasureTier {
+        max_challenge_rating: 4,
+        coins: &[("5d6", "cp"), ("4d6", "sp"), ("3d6", "gp")],
+        magic_item_chance: 10,
+        rarities: &["common", "uncommon"],
+    },
+    TreasureTier {
+        max_challenge_rating: 10,
+        coins: &[("4d6 * 10", "sp"), ("2d6 * 10", "gp"), ("1d6", "pp")],
+        magic_item_chance: 25,
+        rarities: &["uncommon", "rare"],
+    },
+    TreasureTier {
+        max_challenge_rating: 16,
+        coins: &[("4d6 * 100", "gp"), ("2d6 * 10", "pp")],
+        magic_item_chance: 40,
+        rarities: &["rare", "very rare"],
+    },
+    TreasureTier {
+        max_challenge_rating: 30,
+        coins: &[("2d6 * 1000", "gp"), ("8d6 * 100", "pp")],
+        magic_item_chance: 60,
+        rarities: &["very rare", "legendary"],
+    },
+];
+
+/// Roll coins, and perhaps a magic item, for a creature of the given challenge rating, eg. "5"
+/// or "1/4".
+pub fn generate_treasure(challenge_rating: &str, rng: &mut impl Rng) -> Result<String, String> {
+    let tier = parse_challenge_rating(challenge_rating)
+        .and_then(|cr| {
+            TREASURE_TIERS
+                .iter()
+                .find(|tier| cr <= tier.max_challenge_rating)
+        })
+        .ok_or_else(|| {
+            format!(
+                "\"{}\" is not a challenge rating. Try a number from 0 to 30, eg. `generate treasure 5`.",
+                challenge_rating,
+            )
+        })?;
+
+    let mut output = format!("# Treasure (CR {})\n\n**Coins:** ", challenge_rating);
+
+    let coins = tier
+        .coins
+        .iter()
+        .map(|(formula, denomination)| {
+            DiceFormula::parse(formula)
+                .ok()
+                .and_then(|formula| formula.roll_total(rng))
+                .map(|total| format!("{} {}", total, denomination))
+        })
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| "Couldn't roll the coins.".to_string())?;
+    output.push_str(&coins.join(", "));
+
+    if rng.gen_range(0..100) < tier.magic_item_chance {
+        let magic_items: Vec<(MagicItem, &str)> = MagicItem::get_all()
+            .filter_map(|item| {
+                item.get_rarities()
+                    .iter()
+                    .find(|rarity| tier.rarities.contains(rarity))
+                    .map(|&rarity| (item, rarity))
+            })
+            .collect();
+
+        if let Some((item, rarity)) = magic_items.choose(rng) {
+            output.push_str(&format!(
+                "\\\n**Magic item:** `{}` ({})",
+                ReferenceCommand::MagicItem(item.clone()),
+                rarity,
+            ));
+        }
+    }
+
+    Ok(output)
+}
+
+/// Challenge ratings are whole numbers from 0 to 30, or one of the fractions below 1. Fractions
+/// are rounded down, since they share the lowest tier anyway.
+fn parse_challenge_rating(input: &str) -> Option<u8> {
+    match input {
+        "1/8" | "1/4" | "1/2" => Some(0),
+        _ => input.parse().ok().filter(|cr| *cr <= 30),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn generate_treasure_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        for _ in 0..20 {
+            let output = generate_treasure("1/4", &mut rng).unwrap();
+            assert!(
+                output.starts_with("# Treasure (CR 1/4)\n\n**Coins:** "),
+                "{}",
+                output,
+            );
+            assert!(output.contains(" cp, "), "{}", output);
+            assert!(!output.contains(" pp"), "{}", output);
+        }
+
+        let mut magic_items = 0;
+        for _ in 0..50 {
+            let output = generate_treasure("20", &mut rng).unwrap();
+            assert!(output.contains(" gp, "), "{}", output);
+
+            if let Some((_, item)) = output.split_once("\\\n**Magic item:** `srd magic item ") {
+                assert!(
+                    item.ends_with("` (very rare)") || item.ends_with("` (legendary)"),
+                    "{}",
+                    output,
+                );
+                magic_items += 1;
+            }
+        }
+        assert!(magic_items > 0);
+    }
+
+    #[test]
+    fn generate_treasure_error_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        for input in ["31", "-1", "1/3", "potato"] {
+            assert_eq!(
+                Err(format!(
+                    "\"{}\" is not a challenge rating. Try a number from 0 to 30, eg. `generate treasure 5`.",
+                    input,
+                )),
+                generate_treasure(input, &mut rng),
+            );
+        }
+    }
+
+    #[test]
+    fn parse_challenge_rating_test() {
+        assert_eq!(Some(0), parse_challenge_rating("0"));
+        assert_eq!(Some(0), parse_challenge_rating("1/8"));
+        assert_eq!(Some(30), parse_challenge_rating("30"));
+        assert_eq!(None, parse_challenge_rating("31"));
+        assert_eq!(None, parse_challenge_rating("2/3"));
+    }
+}
@@ -31,6 +31,7 @@ pub struct ImportStats {
 struct ImportStat {
     created: usize,
     updated: usize,
+    skipped: usize,
     failed: usize,
 }
 
@@ -46,55 +47,90 @@ pub async fn export(repo: &Repository) -> BackupData {
     }
 }
 
+/// Import the contents of a backup into the repository. Entries that collide with an existing
+/// entry (by UUID or by name), and key/values that are already set, are skipped unless
+/// `overwrite` is set, in which case the existing entry is replaced.
 pub async fn import(
     repo: &mut Repository,
     mut data: BackupData,
+    overwrite: bool,
 ) -> Result<ImportStats, RepositoryError> {
     let mut stats = ImportStats::default();
 
     for thing in data.things.into_iter() {
-        match (
-            match thing {
-                Thing::Npc(_) => &mut stats.npc_stats,
-                Thing::Place(_) => &mut stats.place_stats,
-            },
-            repo.modify_without_undo(Change::CreateAndSave { thing })
-                .await,
-        ) {
-            (stat, Ok(_)) => stat.created += 1,
-            (stat, Err((Change::CreateAndSave { thing }, RepositoryError::NameAlreadyExists))) => {
-                let name = thing.name().to_string();
-                match repo
-                    .modify_without_undo(Change::Edit {
-                        name,
-                        uuid: None,
-                        diff: thing,
-                    })
-                    .await
-                {
+        let stat = match thing {
+            Thing::Npc(_) => &mut stats.npc_stats,
+            Thing::Place(_) => &mut stats.place_stats,
+        };
+
+        let existing = if let Some(uuid) = thing.uuid() {
+            repo.get_by_uuid(uuid).await.ok()
+        } else {
+            None
+        };
+
+        let result = if let Some(existing) = existing {
+            Err((
+                Change::Edit {
+                    name: existing.name().to_string(),
+                    uuid: existing.uuid().cloned(),
+                    diff: thing,
+                },
+                RepositoryError::NameAlreadyExists,
+            ))
+        } else {
+            match repo
+                .modify_without_undo(Change::CreateAndSave { thing })
+                .await
+            {
+                Err((Change::CreateAndSave { thing }, RepositoryError::NameAlreadyExists)) => {
+                    Err((
+                        Change::Edit {
+                            name: thing.name().to_string(),
+                            uuid: None,
+                            diff: thing,
+                        },
+                        RepositoryError::NameAlreadyExists,
+                    ))
+                }
+                result => result,
+            }
+        };
+
+        match result {
+            Ok(_) => stat.created += 1,
+            Err((change, RepositoryError::NameAlreadyExists)) if overwrite => {
+                match repo.modify_without_undo(change).await {
                     Ok(_) => stat.updated += 1,
                     Err(_) => stat.failed += 1,
                 }
             }
-            (stat, Err(_)) => stat.failed += 1,
+            Err((_, RepositoryError::NameAlreadyExists)) => stat.skipped += 1,
+            Err(_) => stat.failed += 1,
         }
     }
 
     if let Some(time) = data.key_value.time.take().and_then(|s| s.parse().ok()) {
-        match repo
-            .modify_without_undo(Change::SetKeyValue {
-                key_value: KeyValue::Time(Some(time)),
-            })
-            .await
-        {
-            Ok(Change::SetKeyValue {
-                key_value: KeyValue::Time(None),
-            }) => stats.key_value_stats.created += 1,
-            Ok(Change::SetKeyValue {
-                key_value: KeyValue::Time(Some(_)),
-            }) => stats.key_value_stats.updated += 1,
-            Ok(_) => unreachable!(),
-            Err(_) => stats.key_value_stats.failed += 1,
+        let existing_time = repo.get_key_value(&KeyValue::Time(None)).await;
+
+        if !overwrite && matches!(existing_time, Ok(KeyValue::Time(Some(_)))) {
+            stats.key_value_stats.skipped += 1;
+        } else {
+            match repo
+                .modify_without_undo(Change::SetKeyValue {
+                    key_value: KeyValue::Time(Some(time)),
+                })
+                .await
+            {
+                Ok(Change::SetKeyValue {
+                    key_value: KeyValue::Time(None),
+                }) => stats.key_value_stats.created += 1,
+                Ok(Change::SetKeyValue {
+                    key_value: KeyValue::Time(Some(_)),
+                }) => stats.key_value_stats.updated += 1,
+                Ok(_) => unreachable!(),
+                Err(_) => stats.key_value_stats.failed += 1,
+            }
         }
     }
 
@@ -128,6 +164,11 @@ impl fmt::Display for ImportStats {
 
         if first {
             write!(f, "Nothing to import.")?;
+        } else if self.place_stats.skipped != 0
+            || self.npc_stats.skipped != 0
+            || self.key_value_stats.skipped != 0
+        {
+            write!(f, "\n\n_Entries that already exist in your journal were skipped. To replace them, use `import overwrite`._")?;
         }
 
         Ok(())
@@ -136,7 +177,7 @@ impl fmt::Display for ImportStats {
 
 impl ImportStat {
     fn is_empty(&self) -> bool {
-        self.created == 0 && self.updated == 0 && self.failed == 0
+        self.created == 0 && self.updated == 0 && self.skipped == 0 && self.failed == 0
     }
 }
 
@@ -157,6 +198,14 @@ impl fmt::Display for ImportStat {
             first = false;
         }
 
+        if self.skipped != 0 {
+            if !first {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} skipped", self.skipped)?;
+            first = false;
+        }
+
         if self.failed != 0 {
             if !first {
                 write!(f, ", ")?;
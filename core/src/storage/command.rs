@@ -4,26 +4,51 @@ use crate::app::{
     AppMeta, Autocomplete, AutocompleteSuggestion, CommandAlias, CommandMatches, ContextAwareParse,
     Event, Runnable,
 };
-use crate::utils::CaseInsensitiveStr;
-use crate::world::Thing;
+use crate::utils::{capitalize, CaseInsensitiveStr};
+use crate::world::place::PlaceType;
+use crate::world::{parse_tags, Thing};
+use crate::Uuid;
 use async_trait::async_trait;
 use futures::join;
-use std::cmp::Ordering;
+use std::cmp::{Ordering, Reverse};
+use std::collections::HashMap;
 use std::fmt;
 use std::iter::repeat;
+use std::str::FromStr;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum StorageCommand {
+    Clear { confirm: bool },
     Delete { name: String },
     Export,
-    Import,
+    Import { overwrite: bool },
     Journal,
+    List { kind: ListKind, page: usize },
     Load { name: String },
+    Recent,
     Redo,
     Save { name: String },
+    Search { query: String, page: usize },
+    Stats,
     Undo,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ListKind {
+    Npcs,
+    Places,
+    Buildings,
+    Locations,
+    Regions,
+    Tagged(Vec<String>),
+}
+
+/// Search results are shown this many at a time, with the rest on later pages.
+const MAX_SEARCH_RESULTS: usize = 10;
+
+/// Likewise for `list [kind]`, which is less specific and so allows more.
+const MAX_LIST_RESULTS: usize = 50;
+
 #[async_trait(?Send)]
 impl Runnable for StorageCommand {
     async fn run(self, _input: &str, app_meta: &mut AppMeta) -> Result<String, String> {
@@ -79,20 +104,118 @@ impl Runnable for StorageCommand {
 
                 Ok(output)
             }
+            Self::Clear { confirm: false } => {
+                let journal_count = app_meta
+                    .repository
+                    .journal()
+                    .await
+                    .map_err(|_| "Couldn't access the journal.".to_string())?
+                    .len();
+
+                Err(format!(
+                    "This will permanently delete {}, forget your recent results and reset the time. It can't be undone, so to go ahead, use `clear world confirm`.",
+                    match journal_count {
+                        1 => "the 1 entry in your journal".to_string(),
+                        n => format!("all {} entries in your journal", n),
+                    },
+                ))
+            }
+            Self::Clear { confirm: true } => {
+                // Combatants named after something in the world go along with it.
+                let wiped_names: Vec<String> = app_meta
+                    .repository
+                    .journal()
+                    .await
+                    .map_err(|_| "Couldn't clear the world.".to_string())?
+                    .iter()
+                    .chain(app_meta.repository.recent())
+                    .map(|thing| thing.name().to_string())
+                    .collect();
+
+                let journal_count = app_meta
+                    .repository
+                    .clear()
+                    .await
+                    .map_err(|_| "Couldn't clear the world.".to_string())?;
+                app_meta.last_thing = None;
+                app_meta.generated.clear();
+                app_meta.generated_forgotten = 0;
+
+                for name in wiped_names {
+                    if app_meta.initiative.get(&name).is_some() {
+                        let _ = app_meta.initiative.remove(&name);
+                    }
+                }
+
+                Ok(format!(
+                    "The world was cleared. {} deleted from your journal, and the time was reset.",
+                    match journal_count {
+                        1 => "1 entry was".to_string(),
+                        n => format!("{} entries were", n),
+                    },
+                ))
+            }
             Self::Delete { name } => {
-                let name = app_meta
-                        .repository
-                        .get_by_name(&name)
-                        .await
-                        .map(|t| t.name().value().map(|s| s.to_string()))
-                        .unwrap_or(None)
-                        .unwrap_or(name);
+                let name = app_meta.resolve_pronoun(&name)?;
+
+                // Names take precedence, but fall back to treating the input as a UUID.
+                let (name, uuid, place_uuid) =
+                    if let Ok(thing) = app_meta.repository.get_by_name(&name).await {
+                        (
+                            thing.name().to_string(),
+                            None,
+                            thing.place().and(thing.uuid().cloned()),
+                        )
+                    } else if let Some(thing) = match Uuid::parse_str(&name) {
+                        Ok(uuid) => app_meta.repository.get_by_uuid(&uuid).await.ok(),
+                        Err(_) => None,
+                    } {
+                        (
+                            thing.name().to_string(),
+                            thing.uuid().cloned(),
+                            thing.place().and(thing.uuid().cloned()),
+                        )
+                    } else {
+                        (name, None, None)
+                    };
+
+                let result = app_meta
+                    .repository
+                    .modify(Change::Delete {
+                        name: name.clone(),
+                        uuid,
+                    })
+                    .await;
 
-                app_meta
+                // Anything located in a deleted place keeps its reference, so that `undo` can
+                // restore the relationship, but it will no longer be shown.
+                let orphan_count = if let (Ok(_), Some(place_uuid)) = (&result, place_uuid) {
+                    app_meta
                         .repository
-                        .modify(Change::Delete { name: name.clone(), uuid: None })
+                        .get_by_location(&place_uuid)
                         .await
-                        .map(|_| format!("{} was successfully deleted. Use `undo` to reverse this.", name))
+                        .map(|things| things.len())
+                        .unwrap_or_default()
+                } else {
+                    0
+                };
+
+                // `it` shouldn't keep pointing at something that no longer exists.
+                if result.is_ok()
+                    && app_meta
+                        .last_thing
+                        .as_ref()
+                        .is_some_and(|last_thing| last_thing.eq_ci(&name))
+                {
+                    app_meta.last_thing = None;
+                }
+
+                result
+                        .map(|_| match orphan_count {
+                            0 => format!("{} was successfully deleted. Use `undo` to reverse this.", name),
+                            1 => format!("{} was successfully deleted. Use `undo` to reverse this.\n\n! There was 1 entry located in {}, which no longer has a location.", name, name),
+                            n => format!("{} was successfully deleted. Use `undo` to reverse this.\n\n! There were {} entries located in {}, which no longer have a location.", name, n, name),
+                        })
                         .map_err(|(_, e)| match e {
                             RepositoryError::NotFound => {
                                 format!("There is no entity named \"{}\".", name)
@@ -105,6 +228,7 @@ impl Runnable for StorageCommand {
                         })
             }
             Self::Save { name } => {
+                let name = app_meta.resolve_pronoun(&name)?;
                 let name = app_meta
                     .repository
                     .get_by_name(&name)
@@ -117,7 +241,10 @@ impl Runnable for StorageCommand {
                     .repository
                     .modify(Change::Save { name: name.clone() })
                     .await
-                    .map(|_| format!("{} was successfully saved. Use `undo` to reverse this.", name))
+                    .map(|_| {
+                        app_meta.last_thing = Some(name.clone());
+                        format!("{} was successfully saved. Use `undo` to reverse this.", name)
+                    })
                     .map_err(|(_, e)| match e {
                         RepositoryError::NotFound => {
                             format!("There is no entity named \"{}\".", name)
@@ -129,18 +256,187 @@ impl Runnable for StorageCommand {
                         }
                     })
             }
+            Self::List { kind, page } => {
+                let offset = page_offset(page, MAX_LIST_RESULTS)?;
+                let (things, total) = app_meta
+                    .repository
+                    .journal_page(
+                        &|thing| kind.includes(thing).then_some(0),
+                        offset,
+                        MAX_LIST_RESULTS,
+                    )
+                    .await
+                    .map_err(|_| "Couldn't access the journal.".to_string())?;
+
+                if total == 0 {
+                    return Ok(format!("*There are no {} in your journal.*", kind));
+                }
+
+                let window = page_window(page, MAX_LIST_RESULTS, total, &kind.to_string())?;
+
+                let mut output = format!("# {}", capitalize(&kind.to_string()));
+                things.iter().enumerate().for_each(|(i, thing)| {
+                    output.push_str(if i == 0 { "\n\n" } else { "\\\n" });
+                    output.push_str(&format!(
+                        "{} ({})",
+                        thing.display_summary(),
+                        thing.uuid().map(|uuid| uuid.to_string()).unwrap_or_default(),
+                    ));
+                });
+
+                if total > MAX_LIST_RESULTS {
+                    output.push_str(&format!(
+                        "\n\n*Showing {}-{} of {} {}.",
+                        window.start + 1,
+                        window.end,
+                        total,
+                        kind,
+                    ));
+
+                    if window.end < total {
+                        output.push_str(&format!(
+                            " Use `{}` to see more, or `search [name]` to narrow it down.",
+                            Self::List {
+                                kind,
+                                page: page + 1,
+                            },
+                        ));
+                    }
+
+                    output.push('*');
+                } else {
+                    output.push_str(&format!("\n\n*{} in total.*", total));
+                }
+
+                Ok(output)
+            }
+            Self::Stats => {
+                let journal = app_meta
+                    .repository
+                    .journal()
+                    .await
+                    .map_err(|_| "Couldn't access the journal.".to_string())?;
+
+                if journal.is_empty() {
+                    return Ok(
+                        "*Your journal is empty, so there's nothing to count yet.*".to_string(),
+                    );
+                }
+
+                // A single pass over the journal, counting each thing under its kind and type.
+                let mut npcs = Tally::default();
+                let mut buildings = Tally::default();
+                let mut locations = Tally::default();
+                let mut regions = Tally::default();
+                let mut other_places = Tally::default();
+
+                for thing in journal.iter() {
+                    if let Some(npc) = thing.npc() {
+                        npcs.add(npc.species.value().map(|species| species.as_str()));
+                    } else if let Some(place) = thing.place() {
+                        let subtype = place.subtype.value();
+                        let tally = match subtype {
+                            Some(PlaceType::Building(_)) => &mut buildings,
+                            Some(PlaceType::Location(_)) => &mut locations,
+                            Some(PlaceType::Region(_)) => &mut regions,
+                            Some(PlaceType::Any) | None => &mut other_places,
+                        };
+                        tally.add(subtype.map(|subtype| subtype.as_str()));
+                    }
+                }
+
+                let mut output = "# World stats".to_string();
+                [
+                    ("Characters", npcs),
+                    ("Buildings", buildings),
+                    ("Locations", locations),
+                    ("Regions", regions),
+                ]
+                .into_iter()
+                // Places without a type are rare, so they're only listed if there are any.
+                .chain(Some(("Other places", other_places)).filter(|(_, tally)| !tally.0.is_empty()))
+                .enumerate()
+                .for_each(|(i, (kind, tally))| {
+                    output.push_str(if i == 0 { "\n\n" } else { "\\\n" });
+                    output.push_str(&format!("**{}:** {}", kind, tally));
+                });
+                output.push_str(&match journal.len() {
+                    1 => "\n\n*1 journal entry in total.*".to_string(),
+                    n => format!("\n\n*{} journal entries in total.*", n),
+                });
+
+                Ok(output)
+            }
+            Self::Search { query, page } => {
+                let offset = page_offset(page, MAX_SEARCH_RESULTS)?;
+                let (results, total) = app_meta
+                    .repository
+                    .journal_page(
+                        &|thing| search_rank(&thing.name().to_string(), &query),
+                        offset,
+                        MAX_SEARCH_RESULTS,
+                    )
+                    .await
+                    .map_err(|_| "Couldn't access the journal.".to_string())?;
+
+                if total == 0 {
+                    return Ok(format!(
+                        "Nothing in your journal matches \"{}\".",
+                        query,
+                    ));
+                }
+
+                let window = page_window(page, MAX_SEARCH_RESULTS, total, "results")?;
+
+                let mut output = format!("# Search results for \"{}\"", query);
+                results.iter().enumerate().for_each(|(i, thing)| {
+                    output.push_str(if i == 0 { "\n\n" } else { "\\\n" });
+                    output.push_str(&format!(
+                        "{} ({}, {})",
+                        thing.display_summary(),
+                        thing.as_str(),
+                        thing.uuid().map(|uuid| uuid.to_string()).unwrap_or_default(),
+                    ));
+                });
+
+                if total > MAX_SEARCH_RESULTS {
+                    output.push_str(&format!(
+                        "\n\n*Showing {}-{} of {} results.",
+                        window.start + 1,
+                        window.end,
+                        total,
+                    ));
+
+                    if window.end < total {
+                        output.push_str(&format!(
+                            " Use `{}` to see more, or try a more specific search.",
+                            Self::Search {
+                                query,
+                                page: page + 1,
+                            },
+                        ));
+                    }
+
+                    output.push('*');
+                }
+
+                Ok(output)
+            }
             Self::Export => {
                 (app_meta.event_dispatcher)(Event::Export(export(&app_meta.repository).await));
                 Ok("The journal is exporting. Your download should begin shortly.".to_string())
             }
-            Self::Import => {
-                (app_meta.event_dispatcher)(Event::Import);
+            Self::Import { overwrite } => {
+                (app_meta.event_dispatcher)(Event::Import { overwrite });
                 Ok("The file upload popup should appear momentarily. Please select a compatible JSON file, such as that produced by the `export` command.".to_string())
             }
             Self::Load { name } => {
+                let name = app_meta.resolve_pronoun(&name)?;
                 let thing = app_meta.repository.get_by_name(&name).await;
                 let mut save_command = None;
                 let output = if let Ok(thing) = thing {
+                    app_meta.last_thing = Some(thing.name().to_string());
+
                     if thing.uuid().is_none() {
                         save_command = Some(CommandAlias::literal(
                             "save",
@@ -150,12 +446,12 @@ impl Runnable for StorageCommand {
 
                         Ok(format!(
                             "{}\n\n_{} has not yet been saved. Use ~save~ to save {} to your `journal`._",
-                            thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default()),
+                            thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default()).with_units(app_meta.units),
                             thing.name(),
                             thing.gender().them(),
                         ))
                     } else {
-                        Ok(format!("{}", thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default())))
+                        Ok(format!("{}", thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default()).with_units(app_meta.units)))
                     }
                 } else {
                     Err(format!("No matches for \"{}\"", name))
@@ -167,6 +463,33 @@ impl Runnable for StorageCommand {
 
                 output
             }
+            Self::Recent => {
+                if app_meta.generated.is_empty() {
+                    return Err(
+                        "You haven't generated anything yet. Try `npc` first.".to_string(),
+                    );
+                }
+
+                let mut output = "# Recently generated".to_string();
+                let mut newest = 0;
+                for (number, name) in app_meta.numbered_generated() {
+                    output.push_str(if newest == 0 { "\n\n" } else { "\\\n" });
+                    newest = number;
+
+                    // Keep the numbering even if something has since been deleted.
+                    if let Ok(thing) = app_meta.repository.get_by_name(name).await {
+                        output.push_str(&format!("**{}** {}", number, thing.display_summary()));
+                    } else {
+                        output.push_str(&format!("**{}** ~~{}~~", number, name));
+                    }
+                }
+                output.push_str(&format!(
+                    "\n\n_Refer to these by number, eg. `save {}` saves the most recent. The numbers stay the same as you generate more._",
+                    newest,
+                ));
+
+                Ok(output)
+            }
             Self::Redo => match app_meta.repository.redo().await {
                 Some(Ok(thing)) => {
                     let action = app_meta
@@ -179,7 +502,7 @@ impl Runnable for StorageCommand {
                     if let Some(thing) = thing {
                         Ok(format!(
                             "{}\n\n_Successfully redid {}. Use `undo` to reverse this._",
-                            thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default()),
+                            thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default()).with_units(app_meta.units),
                             action,
                         ))
                     } else {
@@ -199,7 +522,7 @@ impl Runnable for StorageCommand {
                     if let Some(thing) = thing {
                         Ok(format!(
                             "{}\n\n_Successfully undid {}. Use `redo` to reverse this._",
-                            thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default()),
+                            thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default()).with_units(app_meta.units),
                             action,
                         ))
                     } else {
@@ -227,7 +550,10 @@ impl ContextAwareParse for StorageCommand {
     async fn parse_input(input: &str, app_meta: &AppMeta) -> CommandMatches<Self> {
         let mut matches = CommandMatches::default();
 
-        if app_meta.repository.get_by_name(input).await.is_ok() {
+        if app_meta.repository.get_by_name(input).await.is_ok()
+            || input.eq_ci("it")
+            || input.eq_ci("that")
+        {
             matches.push_fuzzy(Self::Load {
                 name: input.to_string(),
             });
@@ -241,20 +567,46 @@ impl ContextAwareParse for StorageCommand {
             matches.push_canonical(Self::Load {
                 name: name.to_string(),
             });
+        } else if let Some((kind, page)) = input
+            .strip_prefix_ci("list ")
+            .map(split_page)
+            .and_then(|(s, page)| Some((s.trim().parse::<ListKind>().ok()?, page)))
+        {
+            matches.push_canonical(Self::List { kind, page });
+        } else if let Some((query, page)) = input
+            .strip_prefix_ci("search ")
+            .map(split_page)
+            .map(|(s, page)| (s.trim(), page))
+            .filter(|(s, _)| !s.is_empty())
+        {
+            matches.push_canonical(Self::Search {
+                query: query.to_string(),
+                page,
+            });
         } else if let Some(name) = input.strip_prefix_ci("save ") {
             matches.push_canonical(Self::Save {
                 name: name.to_string(),
             });
         } else if input.eq_ci("journal") {
             matches.push_canonical(Self::Journal);
+        } else if input.eq_ci("world stats") || input.eq_ci("stats") {
+            matches.push_canonical(Self::Stats);
+        } else if input.eq_ci("clear world") {
+            matches.push_canonical(Self::Clear { confirm: false });
+        } else if input.eq_ci("clear world confirm") {
+            matches.push_canonical(Self::Clear { confirm: true });
         } else if input.eq_ci("undo") {
             matches.push_canonical(Self::Undo);
+        } else if input.eq_ci("recent") {
+            matches.push_canonical(Self::Recent);
         } else if input.eq_ci("redo") {
             matches.push_canonical(Self::Redo);
         } else if input.eq_ci("export") {
             matches.push_canonical(Self::Export);
         } else if input.eq_ci("import") {
-            matches.push_canonical(Self::Import);
+            matches.push_canonical(Self::Import { overwrite: false });
+        } else if input.eq_ci("import overwrite") {
+            matches.push_canonical(Self::Import { overwrite: true });
         }
 
         matches
@@ -265,12 +617,35 @@ impl ContextAwareParse for StorageCommand {
 impl Autocomplete for StorageCommand {
     async fn autocomplete(input: &str, app_meta: &AppMeta) -> Vec<AutocompleteSuggestion> {
         let mut suggestions: Vec<AutocompleteSuggestion> = [
+            (
+                "clear world",
+                "clear world",
+                "delete everything and start over",
+            ),
             ("delete", "delete [name]", "remove an entry from journal"),
             ("export", "export", "export the journal contents"),
             ("import", "import", "import a journal backup"),
+            (
+                "import overwrite",
+                "import overwrite",
+                "import a backup, replacing existing entries",
+            ),
             ("journal", "journal", "list journal contents"),
+            (
+                "list",
+                "list [npcs|places|regions]",
+                "list journal entries by type",
+            ),
+            (
+                "list tag",
+                "list tag [tag]",
+                "list journal entries with a tag",
+            ),
             ("load", "load [name]", "load an entry"),
+            ("recent", "recent", "list recently generated things"),
             ("save", "save [name]", "save an entry to journal"),
+            ("search", "search [name]", "find journal entries by name"),
+            ("world stats", "world stats", "count what's in your journal"),
         ]
         .into_iter()
         .filter(|(s, _, _)| s.starts_with_ci(input))
@@ -308,15 +683,22 @@ impl Autocomplete for StorageCommand {
         {
             (
                 join!(
-                    app_meta.repository.get_by_name_start(input, Some(10)),
-                    app_meta.repository.get_by_name_start(name, Some(10)),
+                    app_meta
+                        .repository
+                        .get_by_name_start(input, Some(app_meta.autocomplete_limit)),
+                    app_meta
+                        .repository
+                        .get_by_name_start(name, Some(app_meta.autocomplete_limit)),
                 ),
                 prefix,
             )
         } else {
             (
                 (
-                    app_meta.repository.get_by_name_start(input, Some(10)).await,
+                    app_meta
+                        .repository
+                        .get_by_name_start(input, Some(app_meta.autocomplete_limit))
+                        .await,
                     Ok(Vec::new()),
                 ),
                 "",
@@ -370,18 +752,188 @@ impl Autocomplete for StorageCommand {
 impl fmt::Display for StorageCommand {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         match self {
+            Self::Clear { confirm: false } => write!(f, "clear world"),
+            Self::Clear { confirm: true } => write!(f, "clear world confirm"),
             Self::Delete { name } => write!(f, "delete {}", name),
             Self::Export => write!(f, "export"),
-            Self::Import => write!(f, "import"),
+            Self::Import { overwrite: false } => write!(f, "import"),
+            Self::Import { overwrite: true } => write!(f, "import overwrite"),
             Self::Journal => write!(f, "journal"),
+            Self::List {
+                kind: ListKind::Tagged(tag),
+                page: 1,
+            } => write!(f, "list tag {}", tag.join(" ")),
+            Self::List {
+                kind: ListKind::Tagged(tag),
+                page,
+            } => write!(f, "list tag {} page {}", tag.join(" "), page),
+            Self::List { kind, page: 1 } => write!(f, "list {}", kind),
+            Self::List { kind, page } => write!(f, "list {} page {}", kind, page),
             Self::Load { name } => write!(f, "load {}", name),
+            Self::Recent => write!(f, "recent"),
             Self::Redo => write!(f, "redo"),
             Self::Save { name } => write!(f, "save {}", name),
+            Self::Search { query, page: 1 } => write!(f, "search {}", query),
+            Self::Search { query, page } => write!(f, "search {} page {}", query, page),
+            Self::Stats => write!(f, "world stats"),
             Self::Undo => write!(f, "undo"),
         }
     }
 }
 
+impl ListKind {
+    fn includes(&self, thing: &Thing) -> bool {
+        let subtype = thing.place().and_then(|place| place.subtype.value());
+
+        match self {
+            Self::Npcs => thing.npc().is_some(),
+            Self::Places => thing.place().is_some(),
+            Self::Buildings => matches!(subtype, Some(PlaceType::Building(_))),
+            Self::Locations => matches!(subtype, Some(PlaceType::Location(_))),
+            Self::Regions => matches!(subtype, Some(PlaceType::Region(_))),
+            Self::Tagged(tags) => tags
+                .iter()
+                .all(|tag| thing.tags().value().is_some_and(|t| t.contains(tag))),
+        }
+    }
+}
+
+/// The number of journal entries of each type within a kind, eg. species of character.
+#[derive(Default)]
+struct Tally(HashMap<&'static str, usize>);
+
+impl Tally {
+    fn add(&mut self, subtype: Option<&'static str>) {
+        *self.0.entry(subtype.unwrap_or("unknown")).or_default() += 1;
+    }
+}
+
+impl fmt::Display for Tally {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0.values().sum::<usize>())?;
+
+        let mut counts: Vec<_> = self.0.iter().collect();
+        counts.sort_by_key(|&(subtype, count)| (Reverse(count), subtype));
+
+        for (i, (subtype, count)) in counts.into_iter().enumerate() {
+            write!(
+                f,
+                "{}{} {}",
+                if i == 0 { " (" } else { ", " },
+                count,
+                subtype,
+            )?;
+        }
+
+        if !self.0.is_empty() {
+            write!(f, ")")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for ListKind {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "npcs" | "characters" => Ok(Self::Npcs),
+            "places" => Ok(Self::Places),
+            "buildings" => Ok(Self::Buildings),
+            "locations" => Ok(Self::Locations),
+            "regions" => Ok(Self::Regions),
+            s => s
+                .strip_prefix("tag ")
+                .or_else(|| s.strip_prefix("tagged "))
+                .map(parse_tags)
+                .filter(|tags| !tags.is_empty())
+                .map(Self::Tagged)
+                .ok_or(()),
+        }
+    }
+}
+
+impl fmt::Display for ListKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Npcs => write!(f, "NPCs"),
+            Self::Places => write!(f, "places"),
+            Self::Buildings => write!(f, "buildings"),
+            Self::Locations => write!(f, "locations"),
+            Self::Regions => write!(f, "regions"),
+            Self::Tagged(tags) => write!(f, "things tagged {}", tags.join(", ")),
+        }
+    }
+}
+
+/// Split a trailing page number from the input, eg. `"npcs page 2"` => `("npcs", 2)`. Pages are
+/// numbered from 1, which is also the default. Page 0 is split off too, so that running it can
+/// explain the numbering rather than failing to parse.
+fn split_page(input: &str) -> (&str, usize) {
+    input
+        .trim_end()
+        .rsplit_once(' ')
+        .and_then(|(rest, number)| {
+            let page = number.parse::<usize>().ok()?;
+            let rest = rest.trim_end();
+
+            if rest.ends_with_ci(" page") {
+                Some((&rest[..rest.len() - " page".len()], page))
+            } else {
+                None
+            }
+        })
+        .unwrap_or((input, 1))
+}
+
+/// The number of results that come before a given page, or an error for page 0.
+fn page_offset(page: usize, per_page: usize) -> Result<usize, String> {
+    page.checked_sub(1)
+        .map(|page| page.saturating_mul(per_page))
+        .ok_or_else(|| "There is no page 0. Pages start at 1.".to_string())
+}
+
+/// The range of results to show on a given page, or an error if there aren't that many pages.
+fn page_window(
+    page: usize,
+    per_page: usize,
+    total: usize,
+    noun: &str,
+) -> Result<std::ops::Range<usize>, String> {
+    let page_count = total.div_ceil(per_page);
+
+    if page > page_count {
+        Err(match page_count {
+            1 => format!("There is only 1 page of {}.", noun),
+            n => format!("There are only {} pages of {}.", n, noun),
+        })
+    } else {
+        let start = (page - 1) * per_page;
+        Ok(start..total.min(start + per_page))
+    }
+}
+
+/// Lower is better: an exact match, then a match at the start of the name, then at the start of
+/// a word, then anywhere. `None` if the name doesn't contain the query at all.
+fn search_rank(name: &str, query: &str) -> Option<u8> {
+    if name.eq_ci(query) {
+        Some(0)
+    } else if name.starts_with_ci(query) {
+        Some(1)
+    } else if name
+        .split(' ')
+        .skip(1)
+        .any(|word| word.starts_with_ci(query))
+    {
+        Some(2)
+    } else if name.find_ci(query).is_some() {
+        Some(3)
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -474,6 +1026,11 @@ mod test {
             block_on(StorageCommand::parse_input("JOURNAL", &app_meta)),
         );
 
+        assert_eq!(
+            CommandMatches::new_canonical(StorageCommand::Stats),
+            block_on(StorageCommand::parse_input("stats", &app_meta)),
+        );
+
         assert_eq!(
             CommandMatches::default(),
             block_on(StorageCommand::parse_input("potato", &app_meta)),
@@ -572,12 +1129,18 @@ mod test {
         );
 
         assert_autocomplete(
-            &[("save [name]", "save an entry to journal")][..],
+            &[
+                ("save [name]", "save an entry to journal"),
+                ("search [name]", "find journal entries by name"),
+            ][..],
             block_on(StorageCommand::autocomplete("s", &app_meta)),
         );
 
         assert_autocomplete(
-            &[("save [name]", "save an entry to journal")][..],
+            &[
+                ("save [name]", "save an entry to journal"),
+                ("search [name]", "find journal entries by name"),
+            ][..],
             block_on(StorageCommand::autocomplete("S", &app_meta)),
         );
 
@@ -602,12 +1165,24 @@ mod test {
         );
 
         assert_autocomplete(
-            &[("import", "import a journal backup")][..],
+            &[
+                ("import", "import a journal backup"),
+                (
+                    "import overwrite",
+                    "import a backup, replacing existing entries",
+                ),
+            ][..],
             block_on(StorageCommand::autocomplete("i", &app_meta)),
         );
 
         assert_autocomplete(
-            &[("import", "import a journal backup")][..],
+            &[
+                ("import", "import a journal backup"),
+                (
+                    "import overwrite",
+                    "import a backup, replacing existing entries",
+                ),
+            ][..],
             block_on(StorageCommand::autocomplete("I", &app_meta)),
         );
 
@@ -666,6 +1241,8 @@ mod test {
         let app_meta = app_meta();
 
         [
+            StorageCommand::Clear { confirm: false },
+            StorageCommand::Clear { confirm: true },
             StorageCommand::Delete {
                 name: "Potato Johnson".to_string(),
             },
@@ -673,11 +1250,30 @@ mod test {
                 name: "Potato Johnson".to_string(),
             },
             StorageCommand::Export,
-            StorageCommand::Import,
+            StorageCommand::Import { overwrite: false },
+            StorageCommand::Import { overwrite: true },
             StorageCommand::Journal,
+            StorageCommand::Recent,
+            StorageCommand::Stats,
             StorageCommand::Load {
                 name: "Potato Johnson".to_string(),
             },
+            StorageCommand::Search {
+                query: "Potato".to_string(),
+                page: 1,
+            },
+            StorageCommand::Search {
+                query: "Potato page".to_string(),
+                page: 3,
+            },
+            StorageCommand::List {
+                kind: ListKind::Npcs,
+                page: 1,
+            },
+            StorageCommand::List {
+                kind: ListKind::Regions,
+                page: 2,
+            },
         ]
         .into_iter()
         .for_each(|command| {
@@ -692,6 +1288,49 @@ mod test {
         });
     }
 
+    #[test]
+    fn split_page_test() {
+        assert_eq!(("npcs", 2), split_page("npcs page 2"));
+        assert_eq!(("npcs", 2), split_page("npcs PAGE 2 "));
+        assert_eq!(("npcs", 1), split_page("npcs"));
+        assert_eq!(("npcs", 0), split_page("npcs page 0"));
+        assert_eq!(("front page", 1), split_page("front page"));
+        assert_eq!(("Page", 3), split_page("Page page 3"));
+    }
+
+    #[test]
+    fn page_offset_test() {
+        assert_eq!(Ok(0), page_offset(1, 10));
+        assert_eq!(Ok(20), page_offset(3, 10));
+        assert_eq!(
+            Err("There is no page 0. Pages start at 1.".to_string()),
+            page_offset(0, 10),
+        );
+    }
+
+    #[test]
+    fn page_window_test() {
+        assert_eq!(Ok(0..10), page_window(1, 10, 12, "results"));
+        assert_eq!(Ok(10..12), page_window(2, 10, 12, "results"));
+        assert_eq!(
+            Err("There are only 2 pages of results.".to_string()),
+            page_window(3, 10, 12, "results"),
+        );
+        assert_eq!(
+            Err("There is only 1 page of results.".to_string()),
+            page_window(2, 10, 10, "results"),
+        );
+    }
+
+    #[test]
+    fn search_rank_test() {
+        assert_eq!(Some(0), search_rank("Potato Johnson", "potato johnson"));
+        assert_eq!(Some(1), search_rank("Potato Johnson", "POT"));
+        assert_eq!(Some(2), search_rank("Potato Johnson", "john"));
+        assert_eq!(Some(3), search_rank("Potato Johnson", "tato"));
+        assert_eq!(None, search_rank("Potato Johnson", "carrot"));
+    }
+
     fn event_dispatcher(_event: Event) {}
 
     fn app_meta() -> AppMeta {
@@ -1,6 +1,7 @@
 use crate::utils::CaseInsensitiveStr;
 use crate::{Thing, Uuid};
 use async_trait::async_trait;
+use std::cmp::Ordering;
 use std::collections::HashMap;
 
 #[derive(Default)]
@@ -123,6 +124,17 @@ impl DataStore for MemoryDataStore {
             .collect())
     }
 
+    async fn get_things_page(
+        &self,
+        rank: &dyn for<'a> Fn(&'a Thing) -> Option<u8>,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(Vec<Thing>, usize), ()> {
+        let things = self.things.borrow();
+        let (page, total) = take_page(things.values(), rank, offset, limit);
+        Ok((page.into_iter().cloned().collect(), total))
+    }
+
     async fn save_thing(&mut self, thing: &Thing) -> Result<(), ()> {
         if let Some(uuid) = thing.uuid() {
             let mut things = self.things.borrow_mut();
@@ -176,6 +188,23 @@ pub trait DataStore {
         limit: Option<usize>,
     ) -> Result<Vec<Thing>, ()>;
 
+    /// One page of the things for which `rank` returns a value, ordered by rank (lowest first)
+    /// and then by name, skipping the first `offset` and returning at most `limit`. The total
+    /// number of matching things is returned alongside the page.
+    async fn get_things_page(
+        &self,
+        rank: &dyn for<'a> Fn(&'a Thing) -> Option<u8>,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(Vec<Thing>, usize), ()> {
+        Ok(take_page(
+            self.get_all_the_things().await?,
+            rank,
+            offset,
+            limit,
+        ))
+    }
+
     async fn save_thing(&mut self, thing: &Thing) -> Result<(), ()>;
 
     async fn set_value(&mut self, key: &str, value: &str) -> Result<(), ()>;
@@ -185,6 +214,90 @@ pub trait DataStore {
     async fn delete_value(&mut self, key: &str) -> Result<(), ()>;
 }
 
+/// Pick one page out of a stream of things for [`DataStore::get_things_page`]. No more than
+/// `offset + limit` things are held at once, so the stream doesn't have to fit in memory.
+pub(crate) fn take_page<T: std::borrow::Borrow<Thing>>(
+    things: impl IntoIterator<Item = T>,
+    rank: &dyn Fn(&Thing) -> Option<u8>,
+    offset: usize,
+    limit: usize,
+) -> (Vec<T>, usize) {
+    let keep = offset.saturating_add(limit);
+    let mut kept: Vec<(u8, T)> = Vec::new();
+    let mut total = 0;
+
+    for thing in things {
+        if let Some(thing_rank) = rank(thing.borrow()) {
+            total += 1;
+
+            let i = kept.partition_point(|(kept_rank, kept_thing)| {
+                kept_rank
+                    .cmp(&thing_rank)
+                    .then_with(|| cmp_by_name(kept_thing.borrow(), thing.borrow()))
+                    .is_le()
+            });
+
+            if i < keep {
+                kept.insert(i, (thing_rank, thing));
+                kept.truncate(keep);
+            }
+        }
+    }
+
+    (
+        kept.into_iter()
+            .skip(offset)
+            .map(|(_, thing)| thing)
+            .collect(),
+        total,
+    )
+}
+
+/// Names are compared case-insensitively, with ties broken by the exact name and finally the UUID,
+/// so that the order is the same every time and nothing repeats or is skipped between pages.
+fn cmp_by_name(a: &Thing, b: &Thing) -> Ordering {
+    let (a_name, b_name) = (a.name().to_string(), b.name().to_string());
+
+    a_name
+        .cmp_ci(&b_name)
+        .then_with(|| a_name.cmp(&b_name))
+        .then_with(|| a.uuid().cmp(&b.uuid()))
+}
+
+/// Fixtures for the data stores that keep things as JSON. Empty fields don't keep their locked
+/// state through serialization, so the fixtures are round-tripped to match what comes back out.
+#[cfg(all(test, any(feature = "file", feature = "sqlite")))]
+pub(crate) mod json_fixtures {
+    use crate::world::{Npc, Place};
+    use crate::{Thing, Uuid};
+
+    pub fn person(uuid: Uuid) -> Thing {
+        round_trip(
+            Npc {
+                uuid: Some(uuid.into()),
+                name: "Frodo Baggins".into(),
+                ..Default::default()
+            }
+            .into(),
+        )
+    }
+
+    pub fn place(uuid: Uuid) -> Thing {
+        round_trip(
+            Place {
+                uuid: Some(uuid.into()),
+                name: "The Shire".into(),
+                ..Default::default()
+            }
+            .into(),
+        )
+    }
+
+    fn round_trip(thing: Thing) -> Thing {
+        serde_json::from_str(&serde_json::to_string(&thing).unwrap()).unwrap()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -275,6 +388,55 @@ mod test {
         );
     }
 
+    #[test]
+    fn memory_get_things_page_test() {
+        let mut ds = MemoryDataStore::default();
+
+        for name in ["Delta", "alpha", "Charlie", "Bravo", "Echo"] {
+            block_on(
+                ds.save_thing(
+                    &Npc {
+                        uuid: Some(Uuid::new_v4().into()),
+                        name: name.into(),
+                        ..Default::default()
+                    }
+                    .into(),
+                ),
+            )
+            .unwrap();
+        }
+
+        let names = |(things, total): (Vec<Thing>, usize)| {
+            (
+                things
+                    .iter()
+                    .map(|thing| thing.name().to_string())
+                    .collect::<Vec<_>>(),
+                total,
+            )
+        };
+
+        assert_eq!(
+            (vec!["alpha".to_string(), "Bravo".to_string()], 5),
+            names(block_on(ds.get_things_page(&|_| Some(0), 0, 2)).unwrap()),
+        );
+        assert_eq!(
+            (vec!["Echo".to_string()], 5),
+            names(block_on(ds.get_things_page(&|_| Some(0), 4, 2)).unwrap()),
+        );
+
+        // Lower ranks come first, and things without a rank are left out entirely.
+        let rank = |thing: &Thing| match thing.name().to_string().as_str() {
+            "Echo" => Some(0),
+            "Bravo" => None,
+            _ => Some(1),
+        };
+        assert_eq!(
+            (vec!["Echo".to_string(), "alpha".to_string()], 4),
+            names(block_on(ds.get_things_page(&rank, 0, 2)).unwrap()),
+        );
+    }
+
     #[test]
     fn memory_edit_thing_test() {
         let mut ds = MemoryDataStore::default();
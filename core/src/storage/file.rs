@@ -0,0 +1,374 @@
+use super::DataStore;
+use crate::utils::CaseInsensitiveStr;
+use crate::{Thing, Uuid};
+use async_trait::async_trait;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Holds the key-value pairs. The leading dot keeps it out of the way of the things themselves.
+const VALUES_FILE_NAME: &str = ".values.json";
+
+/// Distinguishes the temporary files of concurrent writes within the same process.
+static WRITE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// A data store that keeps each thing as a pretty-printed JSON file named after its UUID, so that
+/// a world can be edited by hand or kept under version control.
+///
+/// Every write goes to a temporary file that is then moved into place, so other readers never see
+/// a half-written thing. Files that can't be parsed are skipped rather than failing the whole
+/// load, and a warning is recorded for each; see [`FileDataStore::take_warnings`].
+pub struct FileDataStore {
+    path: PathBuf,
+    warnings: RefCell<Vec<String>>,
+}
+
+impl FileDataStore {
+    /// Use the given directory, creating it if it doesn't exist.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        fs::create_dir_all(&path)?;
+
+        Ok(Self {
+            path,
+            warnings: RefCell::new(Vec::new()),
+        })
+    }
+
+    /// The files skipped since the last call, with the reason that each couldn't be read.
+    pub fn take_warnings(&self) -> Vec<String> {
+        self.warnings.take()
+    }
+
+    fn thing_path(&self, uuid: &Uuid) -> PathBuf {
+        self.path.join(format!("{}.json", uuid))
+    }
+
+    fn warn(&self, path: &Path, error: impl ToString) {
+        self.warnings.borrow_mut().push(format!(
+            "Skipped {}: {}",
+            path.display(),
+            error.to_string(),
+        ));
+    }
+
+    fn read_thing(&self, path: &Path) -> io::Result<Option<Thing>> {
+        let data = fs::read_to_string(path)?;
+
+        match serde_json::from_str(&data) {
+            Ok(thing) => Ok(Some(thing)),
+            Err(e) => {
+                self.warn(path, e);
+                Ok(None)
+            }
+        }
+    }
+
+    fn read_things(&self) -> Result<Vec<Thing>, ()> {
+        let mut things = Vec::new();
+
+        for entry in fs::read_dir(&self.path).map_err(|_| ())? {
+            let path = entry.map_err(|_| ())?.path();
+            let is_thing = path.extension().is_some_and(|ext| ext == "json")
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| !name.starts_with('.'));
+
+            if is_thing {
+                match self.read_thing(&path) {
+                    Ok(Some(thing)) => things.push(thing),
+                    Ok(None) => {}
+                    // Deleted by someone else since the directory was listed.
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                    Err(e) => self.warn(&path, e),
+                }
+            }
+        }
+
+        Ok(things)
+    }
+
+    fn read_values(&self) -> Result<BTreeMap<String, String>, ()> {
+        let path = self.path.join(VALUES_FILE_NAME);
+
+        match fs::read_to_string(&path) {
+            Ok(data) => Ok(serde_json::from_str(&data).unwrap_or_else(|e| {
+                self.warn(&path, e);
+                BTreeMap::new()
+            })),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(_) => Err(()),
+        }
+    }
+
+    fn write_values(&self, values: &BTreeMap<String, String>) -> Result<(), ()> {
+        let data = serde_json::to_string_pretty(values).map_err(|_| ())?;
+        self.write(&self.path.join(VALUES_FILE_NAME), &data, true)
+            .map_err(|_| ())
+    }
+
+    /// Write the file atomically via a temporary file. Unless `overwrite` is set, this fails if the
+    /// destination already exists, even if it was created by another process in the meantime.
+    fn write(&self, path: &Path, data: &str, overwrite: bool) -> io::Result<()> {
+        let temp_path = self.path.join(format!(
+            ".{}.{}.{}.tmp",
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or(""),
+            std::process::id(),
+            WRITE_COUNTER.fetch_add(1, Ordering::Relaxed),
+        ));
+
+        fs::write(&temp_path, data)?;
+
+        let result = if overwrite {
+            fs::rename(&temp_path, path)
+        } else {
+            fs::hard_link(&temp_path, path)
+        };
+
+        if !overwrite || result.is_err() {
+            let _ = fs::remove_file(&temp_path);
+        }
+
+        result
+    }
+
+    fn write_thing(&self, thing: &Thing, overwrite: bool) -> Result<(), ()> {
+        let uuid = thing.uuid().ok_or(())?;
+        let data = serde_json::to_string_pretty(thing).map_err(|_| ())?;
+
+        self.write(&self.thing_path(uuid), &data, overwrite)
+            .map_err(|_| ())
+    }
+}
+
+#[async_trait(?Send)]
+impl DataStore for FileDataStore {
+    async fn health_check(&self) -> Result<(), ()> {
+        if self.path.is_dir() {
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
+
+    async fn delete_thing_by_uuid(&mut self, uuid: &Uuid) -> Result<(), ()> {
+        fs::remove_file(self.thing_path(uuid)).map_err(|_| ())
+    }
+
+    async fn edit_thing(&mut self, thing: &Thing) -> Result<(), ()> {
+        self.write_thing(thing, true)
+    }
+
+    async fn get_all_the_things(&self) -> Result<Vec<Thing>, ()> {
+        self.read_things()
+    }
+
+    async fn get_thing_by_uuid(&self, uuid: &Uuid) -> Result<Option<Thing>, ()> {
+        match self.read_thing(&self.thing_path(uuid)) {
+            Ok(thing) => Ok(thing),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(_) => Err(()),
+        }
+    }
+
+    async fn get_thing_by_name(&self, name: &str) -> Result<Option<Thing>, ()> {
+        Ok(self
+            .read_things()?
+            .into_iter()
+            .find(|thing| thing.name().value().is_some_and(|s| s.eq_ci(name))))
+    }
+
+    async fn get_things_by_name_start(
+        &self,
+        name: &str,
+        limit: Option<usize>,
+    ) -> Result<Vec<Thing>, ()> {
+        Ok(self
+            .read_things()?
+            .into_iter()
+            .filter(|thing| thing.name().value().is_some_and(|s| s.starts_with_ci(name)))
+            .take(limit.unwrap_or(usize::MAX))
+            .collect())
+    }
+
+    async fn save_thing(&mut self, thing: &Thing) -> Result<(), ()> {
+        self.write_thing(thing, false)
+    }
+
+    async fn set_value(&mut self, key: &str, value: &str) -> Result<(), ()> {
+        let mut values = self.read_values()?;
+        values.insert(key.to_string(), value.to_string());
+        self.write_values(&values)
+    }
+
+    async fn get_value(&self, key: &str) -> Result<Option<String>, ()> {
+        Ok(self.read_values()?.remove(key))
+    }
+
+    async fn delete_value(&mut self, key: &str) -> Result<(), ()> {
+        let mut values = self.read_values()?;
+        if values.remove(key).is_some() {
+            self.write_values(&values)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::data_store::json_fixtures::{person, place};
+    use super::*;
+    use crate::world::Npc;
+    use tokio_test::block_on;
+
+    const TEST_UUID: Uuid = Uuid::from_u128(u128::MAX);
+
+    #[test]
+    fn save_edit_delete_test() {
+        let dir = TempDir::new();
+        let mut ds = FileDataStore::open(&dir.0).unwrap();
+
+        assert_eq!(Ok(()), block_on(ds.health_check()));
+        assert_eq!(Ok(()), block_on(ds.save_thing(&person(TEST_UUID))));
+        assert_eq!(Err(()), block_on(ds.save_thing(&person(TEST_UUID))));
+        assert!(dir.0.join(format!("{}.json", TEST_UUID)).is_file());
+        assert_eq!(
+            Ok(Some(person(TEST_UUID))),
+            block_on(ds.get_thing_by_uuid(&TEST_UUID)),
+        );
+
+        assert_eq!(Ok(()), block_on(ds.edit_thing(&place(TEST_UUID))));
+        assert_eq!(
+            Ok(vec![place(TEST_UUID)]),
+            block_on(ds.get_all_the_things())
+        );
+
+        assert_eq!(Err(()), block_on(ds.delete_thing_by_uuid(&Uuid::nil())));
+        assert_eq!(Ok(()), block_on(ds.delete_thing_by_uuid(&TEST_UUID)));
+        assert_eq!(Ok(None), block_on(ds.get_thing_by_uuid(&TEST_UUID)));
+
+        // No temporary files are left behind.
+        assert_eq!(0, fs::read_dir(&dir.0).unwrap().count());
+    }
+
+    #[test]
+    fn get_things_by_name_test() {
+        let dir = TempDir::new();
+        let mut ds = FileDataStore::open(&dir.0).unwrap();
+
+        for name in ["Gandalf the Grey", "gANDALF THE wHITE", "Frodo Baggins"] {
+            block_on(
+                ds.save_thing(
+                    &Npc {
+                        uuid: Some(Uuid::new_v4().into()),
+                        name: name.into(),
+                        ..Default::default()
+                    }
+                    .into(),
+                ),
+            )
+            .unwrap();
+        }
+
+        assert_eq!(
+            Some("Frodo Baggins".to_string()),
+            block_on(ds.get_thing_by_name("FRODO baggins"))
+                .unwrap()
+                .map(|thing| thing.name().to_string()),
+        );
+
+        let mut names: Vec<String> = block_on(ds.get_things_by_name_start("gan", None))
+            .unwrap()
+            .iter()
+            .map(|thing| thing.name().to_string())
+            .collect();
+        names.sort();
+        assert_eq!(vec!["Gandalf the Grey", "gANDALF THE wHITE"], names);
+
+        assert_eq!(
+            Ok(1),
+            block_on(ds.get_things_by_name_start("GAN", Some(1))).map(|v| v.len()),
+        );
+    }
+
+    #[test]
+    fn key_value_test() {
+        let dir = TempDir::new();
+        let mut ds = FileDataStore::open(&dir.0).unwrap();
+
+        assert_eq!(Ok(None), block_on(ds.get_value("somekey")));
+        assert_eq!(Ok(()), block_on(ds.set_value("somekey", "abc")));
+        assert_eq!(Ok(()), block_on(ds.set_value("somekey", "def")));
+        assert_eq!(
+            Ok(Some("def".to_string())),
+            block_on(ds.get_value("somekey")),
+        );
+        assert_eq!(Ok(()), block_on(ds.delete_value("somekey")));
+        assert_eq!(Ok(None), block_on(ds.get_value("somekey")));
+
+        // Key-value pairs don't show up as things.
+        assert_eq!(Ok(Vec::new()), block_on(ds.get_all_the_things()));
+    }
+
+    #[test]
+    fn malformed_file_test() {
+        let dir = TempDir::new();
+        let mut ds = FileDataStore::open(&dir.0).unwrap();
+
+        block_on(ds.save_thing(&person(TEST_UUID))).unwrap();
+        fs::write(dir.0.join("broken.json"), "{\"type\": \"npc\", ").unwrap();
+        fs::write(dir.0.join("README.md"), "Not a thing.").unwrap();
+
+        assert_eq!(
+            Ok(vec![person(TEST_UUID)]),
+            block_on(ds.get_all_the_things())
+        );
+
+        let warnings = ds.take_warnings();
+        assert_eq!(1, warnings.len(), "{:?}", warnings);
+        assert!(warnings[0].contains("broken.json"), "{:?}", warnings);
+        assert!(ds.take_warnings().is_empty());
+    }
+
+    #[test]
+    fn persistence_test() {
+        let dir = TempDir::new();
+
+        block_on(
+            FileDataStore::open(&dir.0)
+                .unwrap()
+                .save_thing(&person(TEST_UUID)),
+        )
+        .unwrap();
+
+        assert_eq!(
+            Ok(Some(person(TEST_UUID))),
+            block_on(
+                FileDataStore::open(&dir.0)
+                    .unwrap()
+                    .get_thing_by_uuid(&TEST_UUID)
+            ),
+        );
+    }
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new() -> Self {
+            Self(std::env::temp_dir().join(format!("initiative-test-{}", Uuid::new_v4())))
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+}
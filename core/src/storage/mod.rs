@@ -2,8 +2,16 @@ pub mod backup;
 
 pub use command::StorageCommand;
 pub use data_store::{DataStore, MemoryDataStore, NullDataStore};
+#[cfg(feature = "file")]
+pub use file::FileDataStore;
 pub use repository::{Change, Error as RepositoryError, KeyValue, Repository};
+#[cfg(feature = "sqlite")]
+pub use sqlite::{Error as SqliteError, SqliteDataStore};
 
 mod command;
 mod data_store;
+#[cfg(feature = "file")]
+mod file;
 mod repository;
+#[cfg(feature = "sqlite")]
+mod sqlite;
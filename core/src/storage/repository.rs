@@ -1,6 +1,7 @@
 use crate::storage::{DataStore, MemoryDataStore};
 use crate::time::Time;
 use crate::utils::CaseInsensitiveStr;
+use crate::world::npc::{Relationship, Uuid as NpcUuid};
 use crate::world::{Npc, NpcRelations, Place, PlaceRelations, Thing, ThingRelations};
 use crate::Uuid;
 use futures::join;
@@ -183,10 +184,23 @@ impl Repository {
         };
 
         match thing {
-            Thing::Npc(Npc { .. }) => Ok(NpcRelations {
-                location: locations,
+            Thing::Npc(npc) => {
+                let mut relationships = Vec::new();
+
+                for relationship in npc.relationships.value().into_iter().flatten() {
+                    match self.get_by_uuid(relationship.uuid.as_ref()).await {
+                        Ok(Thing::Npc(other)) => relationships.push((relationship.kind, other)),
+                        Ok(_) | Err(Error::NotFound) => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+
+                Ok(NpcRelations {
+                    location: locations,
+                    relationships,
+                }
+                .into())
             }
-            .into()),
             Thing::Place(Place { .. }) => Ok(PlaceRelations {
                 location: locations,
             }
@@ -230,6 +244,36 @@ impl Repository {
             .map_err(|_| Error::DataStoreFailed)
     }
 
+    /// One page of the journal, along with the total number of matches. See
+    /// [`DataStore::get_things_page`].
+    pub async fn journal_page(
+        &self,
+        rank: &dyn Fn(&Thing) -> Option<u8>,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(Vec<Thing>, usize), Error> {
+        self.data_store
+            .get_things_page(rank, offset, limit)
+            .await
+            .map_err(|_| Error::DataStoreFailed)
+    }
+
+    /// Everything, saved or recent, whose location is the place with the given UUID.
+    pub async fn get_by_location(&self, uuid: &Uuid) -> Result<Vec<Thing>, Error> {
+        let mut things: Vec<Thing> = self
+            .journal()
+            .await?
+            .into_iter()
+            .filter(|thing| thing.location_uuid() == Some(uuid))
+            .collect();
+
+        self.recent()
+            .filter(|thing| thing.location_uuid() == Some(uuid))
+            .for_each(|thing| things.push(thing.clone()));
+
+        Ok(things)
+    }
+
     pub async fn get_by_name(&self, name: &str) -> Result<Thing, Error> {
         let (saved_thing, recent_thing) = join!(self.data_store.get_thing_by_name(name), async {
             self.recent()
@@ -259,6 +303,9 @@ impl Repository {
         let undo_change = self.modify_without_undo(change).await?;
         let thing = self.get_by_change(&undo_change).await.ok();
 
+        // Any new change invalidates the redo, which may no longer apply cleanly.
+        self.redo_change = None;
+
         while self.undo_history.len() >= UNDO_HISTORY_LEN {
             self.undo_history.pop_front();
         }
@@ -308,6 +355,43 @@ impl Repository {
     }
 
     pub async fn modify_without_undo(&mut self, change: Change) -> Result<Change, (Change, Error)> {
+        // Only look up the before and after states of changes that can affect relationships.
+        let (before, affects_relationships) = match &change {
+            Change::Create { thing } | Change::CreateAndSave { thing } => (
+                None,
+                thing
+                    .npc()
+                    .is_some_and(|npc| npc.relationships.value().is_some()),
+            ),
+            Change::Edit { diff, .. } | Change::EditAndUnsave { diff, .. }
+                if diff.npc().is_some_and(|npc| npc.relationships.is_locked()) =>
+            {
+                (self.get_by_change(&change).await.ok(), true)
+            }
+            _ => (None, false),
+        };
+
+        let reverse = self.apply_change(change).await?;
+
+        // The change itself has already been made, so it can't be reported as failing now. At
+        // worst, a relationship is left showing on only one of the two characters.
+        let _ = match &reverse {
+            // The reverse of a deletion carries the deleted thing.
+            Change::Create { thing } | Change::CreateAndSave { thing } => {
+                self.sync_relationships(Some(thing), None).await
+            }
+            _ if affects_relationships => {
+                let after = self.get_by_change(&reverse).await.ok();
+                self.sync_relationships(before.as_ref(), after.as_ref())
+                    .await
+            }
+            _ => Ok(()),
+        };
+
+        Ok(reverse)
+    }
+
+    async fn apply_change(&mut self, change: Change) -> Result<Change, (Change, Error)> {
         match change {
             Change::Create { thing } => self
                 .create_thing(thing)
@@ -444,6 +528,31 @@ impl Repository {
         }
     }
 
+    /// Delete everything in the journal and recent entries, and reset stored values such as the
+    /// time. This can't be undone, so the undo and redo history is discarded as well. Returns the
+    /// number of journal entries that were deleted.
+    pub async fn clear(&mut self) -> Result<usize, Error> {
+        let journal = self.journal().await?;
+
+        for uuid in journal.iter().filter_map(|thing| thing.uuid()) {
+            self.data_store
+                .delete_thing_by_uuid(uuid)
+                .await
+                .map_err(|_| Error::DataStoreFailed)?;
+        }
+
+        self.data_store
+            .delete_value(KeyValue::Time(None).key_raw())
+            .await
+            .map_err(|_| Error::DataStoreFailed)?;
+
+        self.recent.clear();
+        self.undo_history.clear();
+        self.redo_change = None;
+
+        Ok(journal.len())
+    }
+
     pub async fn get_key_value(&self, key: &KeyValue) -> Result<KeyValue, Error> {
         let value_str = self.data_store.get_value(key.key_raw()).await;
 
@@ -470,6 +579,89 @@ impl Repository {
         .map_err(|_| Error::DataStoreFailed)
     }
 
+    /// Relationships are stored on both characters, so when a character's relationships change,
+    /// including by it being created or deleted, the other side of each one is updated to match.
+    /// These updates are implied by the original change, so they aren't recorded in the undo
+    /// history: undoing the change brings the other characters back in line as well.
+    async fn sync_relationships(
+        &mut self,
+        before: Option<&Thing>,
+        after: Option<&Thing>,
+    ) -> Result<(), Error> {
+        let (before, after) = (before.and_then(Thing::npc), after.and_then(Thing::npc));
+        let relationships_of = |npc: Option<&Npc>| -> Vec<Relationship> {
+            npc.filter(|npc| npc.uuid.is_some())
+                .and_then(|npc| npc.relationships.value())
+                .cloned()
+                .unwrap_or_default()
+        };
+        let (before_relationships, after_relationships) =
+            (relationships_of(before), relationships_of(after));
+
+        if before_relationships == after_relationships {
+            return Ok(());
+        }
+
+        let stale_uuids: Vec<&NpcUuid> = [before, after]
+            .into_iter()
+            .flatten()
+            .filter_map(|npc| npc.uuid.as_ref())
+            .collect();
+        let after_uuid = after.and_then(|npc| npc.uuid.as_ref());
+
+        for other_uuid in before_relationships
+            .iter()
+            .chain(after_relationships.iter())
+            .map(|relationship| &relationship.uuid)
+        {
+            let mut other = match self.data_store.get_thing_by_uuid(other_uuid.as_ref()).await {
+                Ok(Some(Thing::Npc(other))) => other,
+                Ok(_) => continue,
+                Err(()) => return Err(Error::DataStoreFailed),
+            };
+
+            let mut inverse = after_uuid.and_then(|uuid| {
+                after_relationships
+                    .iter()
+                    .find(|relationship| &relationship.uuid == other_uuid)
+                    .map(|relationship| Relationship {
+                        kind: relationship.kind.inverse(),
+                        uuid: uuid.clone(),
+                    })
+            });
+            // A changed relationship keeps its place in the list.
+            let mut other_relationships: Vec<Relationship> = other
+                .relationships
+                .value()
+                .into_iter()
+                .flatten()
+                .filter_map(|relationship| {
+                    if stale_uuids.contains(&&relationship.uuid) {
+                        inverse.take()
+                    } else {
+                        Some(relationship.clone())
+                    }
+                })
+                .collect();
+            other_relationships.extend(inverse);
+
+            if other.relationships.value() != Some(&other_relationships) {
+                other.relationships = if other_relationships.is_empty() {
+                    None.into()
+                } else {
+                    other_relationships.into()
+                };
+
+                self.data_store
+                    .edit_thing(&other.into())
+                    .await
+                    .map_err(|_| Error::DataStoreFailed)?;
+            }
+        }
+
+        Ok(())
+    }
+
     fn push_recent(&mut self, thing: Thing) {
         while self.recent.len() >= RECENT_MAX_LEN {
             self.recent.pop_front();
@@ -787,7 +979,7 @@ impl fmt::Debug for Repository {
 mod test {
     use super::*;
     use crate::storage::data_store::{MemoryDataStore, NullDataStore};
-    use crate::world::npc::{Npc, Species};
+    use crate::world::npc::{Npc, RelationshipKind, Species};
     use crate::world::{Place, PlaceUuid};
     use async_trait::async_trait;
     use std::cell::RefCell;
@@ -855,6 +1047,26 @@ mod test {
         assert_eq!(1, repo.recent().count());
     }
 
+    #[test]
+    fn clear_test() {
+        let mut repo = repo();
+        block_on(repo.modify(Change::SetKeyValue {
+            key_value: KeyValue::Time(Some(Time::try_new(2, 0, 0, 0).unwrap())),
+        }))
+        .unwrap();
+
+        assert_eq!(Ok(4), block_on(repo.clear()));
+        assert_eq!(0, block_on(repo.journal()).unwrap().len());
+        assert_eq!(0, repo.recent().count());
+        assert_eq!(0, repo.undo_history().count());
+        assert_eq!(None, repo.get_redo());
+        assert_eq!(
+            Ok(KeyValue::Time(None)),
+            block_on(repo.get_key_value(&KeyValue::Time(None))),
+        );
+        assert_eq!(Ok(0), block_on(repo.clear()));
+    }
+
     #[test]
     fn get_by_name_test_from_recent() {
         assert_eq!(
@@ -2188,6 +2400,7 @@ mod test {
         match block_on(repo.load_relations(&odysseus)) {
             Ok(ThingRelations::Npc(NpcRelations {
                 location: Some((parent, None)),
+                ..
             })) => {
                 assert_eq!("River Styx", parent.name.value().unwrap());
             }
@@ -2211,6 +2424,133 @@ mod test {
         }
     }
 
+    #[test]
+    fn load_relations_test_with_deleted_parent() {
+        let mut repo = repo();
+
+        block_on(repo.modify(Change::Delete {
+            name: "River Styx".to_string(),
+            uuid: None,
+        }))
+        .unwrap();
+
+        // Odysseus keeps the reference so that undoing restores it, but it no longer resolves.
+        let odysseus = block_on(repo.get_by_name("Odysseus")).unwrap();
+        assert_eq!(Some(&STYX_UUID), odysseus.location_uuid());
+        match block_on(repo.load_relations(&odysseus)) {
+            Ok(ThingRelations::Npc(NpcRelations { location: None, .. })) => {}
+            r => panic!("{:?}", r),
+        }
+
+        block_on(repo.undo()).unwrap().unwrap();
+        match block_on(repo.load_relations(&odysseus)) {
+            Ok(ThingRelations::Npc(NpcRelations {
+                location: Some((parent, None)),
+                ..
+            })) => {
+                assert_eq!("River Styx", parent.name.value().unwrap());
+            }
+            r => panic!("{:?}", r),
+        }
+    }
+
+    #[test]
+    fn change_test_relationships_are_symmetric() {
+        let mut repo = empty_repo();
+        let [telemachus_uuid, odysseus_uuid] = [Uuid::from_u128(5), Uuid::from_u128(6)];
+
+        for (name, uuid) in [("Telemachus", telemachus_uuid), ("Odysseus", odysseus_uuid)] {
+            block_on(
+                repo.modify(Change::CreateAndSave {
+                    thing: Npc {
+                        uuid: Some(uuid.into()),
+                        name: name.into(),
+                        ..Default::default()
+                    }
+                    .into(),
+                }),
+            )
+            .unwrap();
+        }
+
+        let relationships = |repo: &Repository, uuid: &Uuid| {
+            block_on(repo.get_by_uuid(uuid))
+                .unwrap()
+                .npc()
+                .unwrap()
+                .relationships
+                .value()
+                .cloned()
+        };
+
+        block_on(
+            repo.modify(Change::Edit {
+                name: "Telemachus".to_string(),
+                uuid: Some(telemachus_uuid),
+                diff: Npc {
+                    relationships: vec![Relationship {
+                        kind: RelationshipKind::Child,
+                        uuid: odysseus_uuid.into(),
+                    }]
+                    .into(),
+                    ..Default::default()
+                }
+                .into(),
+            }),
+        )
+        .unwrap();
+        assert_eq!(
+            Some(vec![Relationship {
+                kind: RelationshipKind::Parent,
+                uuid: telemachus_uuid.into(),
+            }]),
+            relationships(&repo, &odysseus_uuid),
+        );
+
+        // Deleting one character removes the other side of its relationships, and undoing the
+        // deletion puts it back.
+        block_on(repo.modify(Change::Delete {
+            name: "Telemachus".to_string(),
+            uuid: None,
+        }))
+        .unwrap();
+        assert_eq!(None, relationships(&repo, &odysseus_uuid));
+
+        block_on(repo.undo()).unwrap().unwrap();
+        assert!(relationships(&repo, &odysseus_uuid).is_some());
+
+        // Undoing the relationship itself clears both sides.
+        block_on(repo.undo()).unwrap().unwrap();
+        assert_eq!(None, relationships(&repo, &odysseus_uuid));
+        assert_eq!(None, relationships(&repo, &telemachus_uuid));
+    }
+
+    #[test]
+    fn get_by_location_test() {
+        let repo = repo();
+
+        assert_eq!(
+            vec!["Odysseus"],
+            block_on(repo.get_by_location(&STYX_UUID))
+                .unwrap()
+                .iter()
+                .map(|thing| thing.name().to_string())
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            vec!["Olympus"],
+            block_on(repo.get_by_location(&THESSALY_UUID))
+                .unwrap()
+                .iter()
+                .map(|thing| thing.name().to_string())
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            Ok(0),
+            block_on(repo.get_by_location(&OLYMPUS_UUID)).map(|things| things.len()),
+        );
+    }
+
     #[test]
     fn debug_test() {
         assert_eq!(
@@ -0,0 +1,473 @@
+use super::data_store::take_page;
+use super::DataStore;
+use crate::{Thing, Uuid};
+use async_trait::async_trait;
+use rusqlite::{params, Connection, ErrorCode, OptionalExtension};
+use std::path::Path;
+use std::time::Duration;
+
+/// Schema changes, applied in order. The database's `user_version` records how many of them have
+/// already been run, so new versions must only ever be appended to the end of the list.
+const MIGRATIONS: &[&str] = &["
+    CREATE TABLE things (
+        uuid TEXT PRIMARY KEY NOT NULL,
+        name_lower TEXT NOT NULL,
+        data TEXT NOT NULL
+    );
+    CREATE INDEX things_name_lower ON things (name_lower);
+    CREATE TABLE key_values (
+        key TEXT PRIMARY KEY NOT NULL,
+        value TEXT NOT NULL
+    );
+"];
+
+/// How long to wait for another process to release its lock on the database before giving up.
+const BUSY_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// A durable data store for native builds, persisting things to a SQLite file. Each thing is
+/// stored as JSON alongside its lowercased name, which is indexed for lookups.
+pub struct SqliteDataStore {
+    connection: Connection,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// Another process is holding a lock on the database file.
+    Locked,
+
+    /// The file exists but is not a readable SQLite database.
+    Corrupt,
+
+    /// The database was created by a newer version of initiative.sh, eg. after a downgrade.
+    UnsupportedVersion(u32),
+
+    Other(rusqlite::Error),
+}
+
+impl SqliteDataStore {
+    /// Open the database at the given path, creating it if it doesn't exist and bringing its
+    /// schema up to date.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::init(Connection::open(path)?)
+    }
+
+    /// Open a database that lives only as long as the data store.
+    pub fn open_in_memory() -> Result<Self, Error> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(mut connection: Connection) -> Result<Self, Error> {
+        connection.busy_timeout(BUSY_TIMEOUT)?;
+        migrate(&mut connection)?;
+        Ok(Self { connection })
+    }
+
+    fn query_things(&self, sql: &str, params: impl rusqlite::Params) -> Result<Vec<Thing>, ()> {
+        let mut statement = self.connection.prepare_cached(sql).map_err(|_| ())?;
+        let rows = statement
+            .query_map(params, |row| row.get::<_, String>(0))
+            .map_err(|_| ())?;
+
+        rows.map(|data| data.map_err(|_| ()).and_then(|data| deserialize(&data)))
+            .collect()
+    }
+
+    fn query_thing(&self, sql: &str, params: impl rusqlite::Params) -> Result<Option<Thing>, ()> {
+        self.connection
+            .query_row(sql, params, |row| row.get::<_, String>(0))
+            .optional()
+            .map_err(|_| ())?
+            .map(|data| deserialize(&data))
+            .transpose()
+    }
+}
+
+#[async_trait(?Send)]
+impl DataStore for SqliteDataStore {
+    async fn health_check(&self) -> Result<(), ()> {
+        match self
+            .connection
+            .query_row("PRAGMA quick_check", [], |row| row.get::<_, String>(0))
+        {
+            Ok(result) if result == "ok" => Ok(()),
+            _ => Err(()),
+        }
+    }
+
+    async fn delete_thing_by_uuid(&mut self, uuid: &Uuid) -> Result<(), ()> {
+        match self
+            .connection
+            .execute("DELETE FROM things WHERE uuid = ?1", [uuid.to_string()])
+        {
+            Ok(1) => Ok(()),
+            _ => Err(()),
+        }
+    }
+
+    async fn edit_thing(&mut self, thing: &Thing) -> Result<(), ()> {
+        let (uuid, name_lower, data) = serialize(thing)?;
+
+        self.connection
+            .execute(
+                "INSERT INTO things (uuid, name_lower, data) VALUES (?1, ?2, ?3)
+                ON CONFLICT (uuid) DO UPDATE SET name_lower = excluded.name_lower, data = excluded.data",
+                params![uuid, name_lower, data],
+            )
+            .map(|_| ())
+            .map_err(|_| ())
+    }
+
+    async fn get_all_the_things(&self) -> Result<Vec<Thing>, ()> {
+        self.query_things("SELECT data FROM things", [])
+    }
+
+    async fn get_thing_by_uuid(&self, uuid: &Uuid) -> Result<Option<Thing>, ()> {
+        self.query_thing(
+            "SELECT data FROM things WHERE uuid = ?1",
+            [uuid.to_string()],
+        )
+    }
+
+    async fn get_thing_by_name(&self, name: &str) -> Result<Option<Thing>, ()> {
+        self.query_thing(
+            "SELECT data FROM things WHERE name_lower = ?1 LIMIT 1",
+            [name.to_lowercase()],
+        )
+    }
+
+    async fn get_things_by_name_start(
+        &self,
+        name: &str,
+        limit: Option<usize>,
+    ) -> Result<Vec<Thing>, ()> {
+        // A negative limit means no limit to SQLite.
+        let limit = limit.map_or(-1, |limit| i64::try_from(limit).unwrap_or(i64::MAX));
+
+        self.query_things(
+            "SELECT data FROM things WHERE substr(name_lower, 1, length(?1)) = ?1 LIMIT ?2",
+            params![name.to_lowercase(), limit],
+        )
+    }
+
+    async fn get_things_page(
+        &self,
+        rank: &dyn for<'a> Fn(&'a Thing) -> Option<u8>,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(Vec<Thing>, usize), ()> {
+        let mut statement = self
+            .connection
+            .prepare_cached("SELECT data FROM things")
+            .map_err(|_| ())?;
+        let mut rows = statement
+            .query_map([], |row| row.get::<_, String>(0))
+            .map_err(|_| ())?;
+
+        // Rows are deserialized one at a time as the page is picked out, rather than all up front.
+        let mut failed = false;
+        let things = std::iter::from_fn(|| {
+            match rows
+                .next()?
+                .map_err(|_| ())
+                .and_then(|data| deserialize(&data))
+            {
+                Ok(thing) => Some(thing),
+                Err(()) => {
+                    failed = true;
+                    None
+                }
+            }
+        });
+        let page = take_page(things, rank, offset, limit);
+
+        if failed {
+            Err(())
+        } else {
+            Ok(page)
+        }
+    }
+
+    async fn save_thing(&mut self, thing: &Thing) -> Result<(), ()> {
+        let (uuid, name_lower, data) = serialize(thing)?;
+
+        // Fails on a conflicting UUID, unlike edit_thing.
+        self.connection
+            .execute(
+                "INSERT INTO things (uuid, name_lower, data) VALUES (?1, ?2, ?3)",
+                params![uuid, name_lower, data],
+            )
+            .map(|_| ())
+            .map_err(|_| ())
+    }
+
+    async fn set_value(&mut self, key: &str, value: &str) -> Result<(), ()> {
+        self.connection
+            .execute(
+                "INSERT OR REPLACE INTO key_values (key, value) VALUES (?1, ?2)",
+                [key, value],
+            )
+            .map(|_| ())
+            .map_err(|_| ())
+    }
+
+    async fn get_value(&self, key: &str) -> Result<Option<String>, ()> {
+        self.connection
+            .query_row(
+                "SELECT value FROM key_values WHERE key = ?1",
+                [key],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|_| ())
+    }
+
+    async fn delete_value(&mut self, key: &str) -> Result<(), ()> {
+        self.connection
+            .execute("DELETE FROM key_values WHERE key = ?1", [key])
+            .map(|_| ())
+            .map_err(|_| ())
+    }
+}
+
+impl From<rusqlite::Error> for Error {
+    fn from(e: rusqlite::Error) -> Self {
+        match e.sqlite_error_code() {
+            Some(ErrorCode::DatabaseBusy | ErrorCode::DatabaseLocked) => Self::Locked,
+            Some(ErrorCode::DatabaseCorrupt | ErrorCode::NotADatabase) => Self::Corrupt,
+            _ => Self::Other(e),
+        }
+    }
+}
+
+/// Run any migrations that haven't yet been applied, all in a single transaction so that a failure
+/// leaves the database as it was.
+fn migrate(connection: &mut Connection) -> Result<(), Error> {
+    let latest_version = MIGRATIONS.len() as u32;
+    let transaction = connection.transaction()?;
+    let version: u32 = transaction.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+
+    if version > latest_version {
+        return Err(Error::UnsupportedVersion(version));
+    }
+
+    for migration in &MIGRATIONS[version as usize..] {
+        transaction.execute_batch(migration)?;
+    }
+
+    if version < latest_version {
+        transaction.pragma_update(None, "user_version", latest_version)?;
+    }
+
+    transaction.commit()?;
+    Ok(())
+}
+
+fn serialize(thing: &Thing) -> Result<(String, String, String), ()> {
+    let uuid = thing.uuid().ok_or(())?.to_string();
+    let name_lower = thing
+        .name()
+        .value()
+        .map(|name| name.to_lowercase())
+        .unwrap_or_default();
+    let data = serde_json::to_string(thing).map_err(|_| ())?;
+
+    Ok((uuid, name_lower, data))
+}
+
+fn deserialize(data: &str) -> Result<Thing, ()> {
+    serde_json::from_str(data).map_err(|_| ())
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::data_store::json_fixtures::{person, place};
+    use super::*;
+    use crate::world::Npc;
+    use tokio_test::block_on;
+
+    const TEST_UUID: Uuid = Uuid::from_u128(u128::MAX);
+
+    #[test]
+    fn save_edit_delete_test() {
+        let mut ds = SqliteDataStore::open_in_memory().unwrap();
+
+        assert_eq!(Ok(()), block_on(ds.health_check()));
+        assert_eq!(Ok(()), block_on(ds.save_thing(&person(TEST_UUID))));
+        assert_eq!(Err(()), block_on(ds.save_thing(&person(TEST_UUID))));
+        assert_eq!(
+            Ok(Some(person(TEST_UUID))),
+            block_on(ds.get_thing_by_uuid(&TEST_UUID)),
+        );
+
+        assert_eq!(Ok(()), block_on(ds.edit_thing(&place(TEST_UUID))));
+        assert_eq!(
+            Ok(vec![place(TEST_UUID)]),
+            block_on(ds.get_all_the_things())
+        );
+
+        assert_eq!(Err(()), block_on(ds.delete_thing_by_uuid(&Uuid::nil())));
+        assert_eq!(Ok(()), block_on(ds.delete_thing_by_uuid(&TEST_UUID)));
+        assert_eq!(Ok(None), block_on(ds.get_thing_by_uuid(&TEST_UUID)));
+    }
+
+    #[test]
+    fn get_things_by_name_test() {
+        let mut ds = SqliteDataStore::open_in_memory().unwrap();
+
+        for name in ["Gandalf the Grey", "gANDALF THE wHITE", "Frodo Baggins"] {
+            block_on(
+                ds.save_thing(
+                    &Npc {
+                        uuid: Some(Uuid::new_v4().into()),
+                        name: name.into(),
+                        ..Default::default()
+                    }
+                    .into(),
+                ),
+            )
+            .unwrap();
+        }
+
+        assert_eq!(
+            Ok(Some("Frodo Baggins")),
+            block_on(ds.get_thing_by_name("FRODO baggins"))
+                .as_ref()
+                .map(|thing| thing
+                    .as_ref()
+                    .and_then(|t| t.name().value())
+                    .map(|s| s.as_str())),
+        );
+        assert_eq!(Ok(None), block_on(ds.get_thing_by_name("Frodo")));
+
+        let mut names: Vec<String> = block_on(ds.get_things_by_name_start("gan", None))
+            .unwrap()
+            .iter()
+            .map(|thing| thing.name().to_string())
+            .collect();
+        names.sort();
+        assert_eq!(vec!["Gandalf the Grey", "gANDALF THE wHITE"], names);
+
+        assert_eq!(
+            Ok(1),
+            block_on(ds.get_things_by_name_start("GAN", Some(1))).map(|v| v.len()),
+        );
+    }
+
+    #[test]
+    fn get_things_page_test() {
+        let mut ds = SqliteDataStore::open_in_memory().unwrap();
+
+        for name in ["Delta", "alpha", "Charlie", "Bravo", "Echo"] {
+            block_on(
+                ds.save_thing(
+                    &Npc {
+                        uuid: Some(Uuid::new_v4().into()),
+                        name: name.into(),
+                        ..Default::default()
+                    }
+                    .into(),
+                ),
+            )
+            .unwrap();
+        }
+
+        let (things, total) = block_on(ds.get_things_page(&|_| Some(0), 2, 2)).unwrap();
+        assert_eq!(5, total);
+        assert_eq!(
+            vec!["Charlie", "Delta"],
+            things
+                .iter()
+                .map(|thing| thing.name().to_string())
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn key_value_test() {
+        let mut ds = SqliteDataStore::open_in_memory().unwrap();
+
+        assert_eq!(Ok(None), block_on(ds.get_value("somekey")));
+        assert_eq!(Ok(()), block_on(ds.set_value("somekey", "abc")));
+        assert_eq!(Ok(()), block_on(ds.set_value("somekey", "def")));
+        assert_eq!(
+            Ok(Some("def".to_string())),
+            block_on(ds.get_value("somekey")),
+        );
+        assert_eq!(Ok(()), block_on(ds.delete_value("somekey")));
+        assert_eq!(Ok(None), block_on(ds.get_value("somekey")));
+    }
+
+    #[test]
+    fn persistence_test() {
+        let path = temp_path();
+
+        {
+            let mut ds = SqliteDataStore::open(&path).unwrap();
+            block_on(ds.save_thing(&person(TEST_UUID))).unwrap();
+        }
+
+        // Reopening an up-to-date database must not rerun the migrations.
+        let ds = SqliteDataStore::open(&path).unwrap();
+        assert_eq!(
+            Ok(Some(person(TEST_UUID))),
+            block_on(ds.get_thing_by_uuid(&TEST_UUID)),
+        );
+
+        drop(ds);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn unsupported_version_test() {
+        let path = temp_path();
+
+        Connection::open(&path)
+            .unwrap()
+            .pragma_update(None, "user_version", MIGRATIONS.len() as u32 + 1)
+            .unwrap();
+
+        assert!(matches!(
+            SqliteDataStore::open(&path),
+            Err(Error::UnsupportedVersion(v)) if v == MIGRATIONS.len() as u32 + 1,
+        ));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn corrupt_test() {
+        let path = temp_path();
+        std::fs::write(
+            &path,
+            "This is not a database, it's a text file. ".repeat(100),
+        )
+        .unwrap();
+
+        assert!(matches!(SqliteDataStore::open(&path), Err(Error::Corrupt)));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn locked_test() {
+        let path = temp_path();
+        SqliteDataStore::open(&path).unwrap();
+
+        let mut locker = Connection::open(&path).unwrap();
+        let transaction = locker
+            .transaction_with_behavior(rusqlite::TransactionBehavior::Exclusive)
+            .unwrap();
+
+        let mut connection = Connection::open(&path).unwrap();
+        connection.busy_timeout(Duration::ZERO).unwrap();
+        assert!(matches!(migrate(&mut connection), Err(Error::Locked)));
+
+        drop(transaction);
+        drop(connection);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    fn temp_path() -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("initiative-test-{}.sqlite", Uuid::new_v4()))
+    }
+}
@@ -11,6 +11,10 @@ pub trait CaseInsensitiveStr<'a> {
 
     fn ends_with_ci<S: AsRef<str>>(&self, suffix: S) -> bool;
 
+    fn find_ci<S: AsRef<str>>(&self, needle: S) -> Option<usize>;
+
+    fn rfind_ci<S: AsRef<str>>(&self, needle: S) -> Option<usize>;
+
     fn strip_prefix_ci<S: AsRef<str>>(&'a self, prefix: S) -> Option<&'a str>;
 
     fn strip_suffix_ci<S: AsRef<str>>(&'a self, prefix: S) -> Option<&'a str>;
@@ -84,6 +88,25 @@ impl<'a, T: AsRef<str>> CaseInsensitiveStr<'a> for T {
         }
     }
 
+    fn find_ci<S: AsRef<str>>(&self, needle: S) -> Option<usize> {
+        let (subject, needle) = (self.as_ref(), needle.as_ref());
+
+        subject
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain([subject.len()])
+            .find(|&i| subject.get(i..).is_some_and(|s| s.starts_with_ci(needle)))
+    }
+
+    fn rfind_ci<S: AsRef<str>>(&self, needle: S) -> Option<usize> {
+        let (subject, needle) = (self.as_ref(), needle.as_ref());
+
+        [subject.len()]
+            .into_iter()
+            .chain(subject.char_indices().rev().map(|(i, _)| i))
+            .find(|&i| subject.get(i..).is_some_and(|s| s.starts_with_ci(needle)))
+    }
+
     fn strip_prefix_ci<S: AsRef<str>>(&'a self, prefix: S) -> Option<&'a str> {
         let prefix = prefix.as_ref();
 
@@ -153,6 +176,28 @@ mod test {
         assert!(!"abc".ends_with_ci("abcd"));
     }
 
+    #[test]
+    fn find_ci_test() {
+        assert_eq!(Some(0), "AbC".find_ci("aB"));
+        assert_eq!(Some(1), "AbC".find_ci("Bc"));
+        assert_eq!(Some(0), "AbC".find_ci(""));
+        assert_eq!(Some(5), "p🥔tat🥔".find_ci("TAT"));
+
+        assert_eq!(None, "abc".find_ci("abcd"));
+        assert_eq!(None, "🥔".find_ci("a"));
+    }
+
+    #[test]
+    fn rfind_ci_test() {
+        assert_eq!(Some(2), "AbAb".rfind_ci("aB"));
+        assert_eq!(Some(1), "AbC".rfind_ci("Bc"));
+        assert_eq!(Some(3), "AbC".rfind_ci(""));
+        assert_eq!(Some(5), "p🥔tat🥔".rfind_ci("TAT"));
+
+        assert_eq!(None, "abc".rfind_ci("abcd"));
+        assert_eq!(None, "🥔".rfind_ci("a"));
+    }
+
     #[test]
     fn strip_prefix_suffix_ci_test() {
         assert_eq!(Some("aBC"), "aBCXYz".strip_suffix_ci("xYz"));
@@ -19,6 +19,166 @@ pub fn capitalize(input: &str) -> String {
     result
 }
 
+/// "a" or "an", whichever reads correctly before the given word.
+pub fn indefinite_article(word: &str) -> &'static str {
+    if word.starts_with(['a', 'e', 'i', 'o', 'u']) {
+        "an"
+    } else {
+        "a"
+    }
+}
+
+/// Remove emoji from the input, along with the space that separates an emoji from the following
+/// word, eg. "🏨 `The Beaver` (inn)" => "`The Beaver` (inn)".
+pub fn strip_emoji(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if is_emoji(c) {
+            while chars.next_if(|&c| is_emoji(c)).is_some() {}
+
+            if result.is_empty() || result.ends_with(char::is_whitespace) {
+                chars.next_if_eq(&' ');
+            }
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+fn is_emoji(c: char) -> bool {
+    matches!(
+        c,
+        '\u{1f000}'..='\u{1faff}'
+            | '\u{2600}'..='\u{27bf}'
+            | '\u{2b00}'..='\u{2bff}'
+            | '\u{200d}'
+            | '\u{fe0f}'
+    )
+}
+
+/// Convert Markdown output to plain text for frontends that can't render it. Links and emphasis
+/// lose their markers (eg. "`2d6`" => "2d6" and "**Age:**" => "Age:", but not the `*` in
+/// "[4, 3] * 3"), bullets become hyphens and
+/// HTML tags are dropped. Strikethrough, as used for dropped dice, is drawn with a combining
+/// stroke over each character instead (eg. "~~1~~" => "1\u{336}").
+pub fn strip_markdown(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+
+    for line in input.split('\n') {
+        // A trailing backslash is a hard line break.
+        let line = line.strip_suffix('\\').unwrap_or(line);
+        let trimmed = line.trim_start();
+
+        if trimmed.starts_with('<') && trimmed.ends_with('>') {
+            continue;
+        }
+
+        if let Some(item) = trimmed.strip_prefix("* ") {
+            result.push_str(&line[..line.len() - trimmed.len()]);
+            result.push_str("- ");
+            push_plain_inline(item, &mut result);
+        } else if trimmed.starts_with('#') {
+            push_plain_inline(trimmed.trim_start_matches('#').trim_start(), &mut result);
+        } else {
+            push_plain_inline(line, &mut result);
+        }
+
+        result.push('\n');
+    }
+
+    while result.contains("\n\n\n") {
+        result = result.replace("\n\n\n", "\n\n");
+    }
+
+    result.trim_matches('\n').to_string()
+}
+
+fn push_plain_inline(input: &str, result: &mut String) {
+    let mut chars = input.char_indices().peekable();
+    let mut prev = None;
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '`' => {}
+            '*' => {
+                // Only a matched pair of `*` or `**` is emphasis. Any other `*`, such as the one in
+                // "[4, 3] * 3", is kept as-is.
+                let run = input[i..].len() - input[i..].trim_start_matches('*').len();
+                let delimiter = &input[i..i + run];
+                let emphasized = input[i + run..]
+                    .split_once(delimiter)
+                    .map(|(emphasized, _)| emphasized)
+                    .filter(|emphasized| {
+                        emphasized.starts_with(|c: char| !c.is_whitespace())
+                            && emphasized.ends_with(|c: char| !c.is_whitespace())
+                    });
+
+                let end = if let Some(emphasized) = emphasized {
+                    push_plain_inline(emphasized, result);
+                    i + emphasized.len() + run * 2
+                } else {
+                    result.push_str(delimiter);
+                    i + run
+                };
+                while chars.next_if(|&(j, _)| j < end).is_some() {}
+            }
+            '~' if input[i..].starts_with("~~") => {
+                if let Some(struck) = input[i + 2..].split_once("~~").map(|(struck, _)| struck) {
+                    let mut plain_struck = String::new();
+                    push_plain_inline(struck, &mut plain_struck);
+                    plain_struck.chars().for_each(|c| {
+                        result.push(c);
+                        result.push('\u{336}');
+                    });
+
+                    let end = i + struck.len() + 4;
+                    while chars.next_if(|&(j, _)| j < end).is_some() {}
+                } else {
+                    chars.next();
+                    result.push_str("~~");
+                }
+            }
+            '~' => {}
+            '_' if !prev.is_some_and(char::is_alphanumeric)
+                || !chars
+                    .peek()
+                    .is_some_and(|&(_, next)| next.is_alphanumeric()) => {}
+            '\\' => {
+                if let Some((_, escaped)) = chars.next() {
+                    result.push(escaped);
+                }
+            }
+            '[' => {
+                // [text](url) => text (url)
+                let link = input[i + 1..].split_once("](").and_then(|(text, rest)| {
+                    rest.split_once(')')
+                        .filter(|_| !text.contains(['[', ']']))
+                        .map(|(url, _)| (text, url))
+                });
+
+                if let Some((text, url)) = link {
+                    push_plain_inline(text, result);
+                    result.push_str(" (");
+                    result.push_str(url);
+                    result.push(')');
+
+                    let end = i + text.len() + url.len() + 4;
+                    while chars.next_if(|&(j, _)| j < end).is_some() {}
+                } else {
+                    result.push(c);
+                }
+            }
+            c => result.push(c),
+        }
+
+        prev = Some(c);
+    }
+}
+
 pub fn pluralize(word: &str) -> (&str, &str) {
     match word {
         "Goose" => ("Geese", ""),
@@ -34,6 +194,31 @@ pub fn pluralize(word: &str) -> (&str, &str) {
     }
 }
 
+/// The number of single-character insertions, deletions, or substitutions needed to turn one
+/// string into the other, ignoring case, eg. "Tabel" => "table" is 2.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().flat_map(char::to_lowercase).collect();
+    let b: Vec<char> = b.chars().flat_map(char::to_lowercase).collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, b_char) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if a_char == b_char {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+
+    row[b.len()]
+}
+
 pub struct Word<'a> {
     phrase: &'a str,
     inner_range: Range<usize>,
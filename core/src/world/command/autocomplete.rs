@@ -1,6 +1,6 @@
 use super::ParsedThing;
 use crate::app::{AppMeta, Autocomplete, AutocompleteSuggestion};
-use crate::utils::{quoted_words, CaseInsensitiveStr};
+use crate::utils::{indefinite_article, quoted_words, CaseInsensitiveStr};
 use crate::world::npc::{Age, Ethnicity, Gender, Npc, Species};
 use crate::world::place::{Place, PlaceType};
 use crate::world::Thing;
@@ -8,6 +8,8 @@ use async_trait::async_trait;
 use std::collections::HashSet;
 use std::str::FromStr;
 
+const ARTICLES: &[&str] = &["a", "an"];
+
 struct ParsedInput<'a> {
     name_desc: &'a str,
     name: &'a str,
@@ -21,6 +23,34 @@ impl<'a> ParsedInput<'a> {
         format!("{}{}", self.name_desc, suggestion)
     }
 
+    /// Like `suggestion()`, but if the term directly follows "a" or "an", the article is
+    /// corrected to suit the term (eg. "an islands" => "islands").
+    fn term_suggestion(&self, term: &str) -> String {
+        if let Some(article) = quoted_words(self.desc)
+            .last()
+            .filter(|word| word.as_str().in_ci(ARTICLES))
+        {
+            let article_start = self.name.len() + article.range().start;
+            let new_article = if PlaceType::is_plural_word(term) {
+                ""
+            } else if article.as_str().eq_ci(indefinite_article(term)) {
+                article.as_str()
+            } else {
+                indefinite_article(term)
+            };
+
+            format!(
+                "{}{}{}{}",
+                &self.name_desc[..article_start],
+                new_article,
+                if new_article.is_empty() { "" } else { " " },
+                term,
+            )
+        } else {
+            self.suggestion(term)
+        }
+    }
+
     fn desc_lower(&self) -> &str {
         if let Some(s) = &self.desc_lower {
             s.as_str()
@@ -119,8 +149,6 @@ fn autocomplete_terms<T: Default + FromStr + Into<Thing>>(
         return vec![result];
     }
 
-    const ARTICLES: &[&str] = &["a", "an"];
-
     let parsed: ParsedInput = input.into();
 
     if parsed.partial.is_empty() || parsed.partial.in_ci(ARTICLES) {
@@ -186,7 +214,7 @@ fn autocomplete_terms<T: Default + FromStr + Into<Thing>>(
                 .flat_map(|(_, _, terms)| terms.iter())
                 .chain(basic_terms.iter().filter(|term| !words.contains(*term)))
                 .filter(|term| term.starts_with_ci(parsed.partial))
-                .map(|term| parsed.suggestion(term))
+                .map(|term| parsed.term_suggestion(term))
                 .filter_map(|term| {
                     if let Ok(thing) = term.parse::<T>().map(|t| t.into()) {
                         Some(AutocompleteSuggestion::new(
@@ -416,6 +444,7 @@ mod test {
                 ("inn", "create inn"),
                 ("imports-shop", "create imports-shop"),
                 ("island", "create island"),
+                ("islands", "create archipelago"),
             ][..],
             block_on(Place::autocomplete("i", &app_meta())),
         );
@@ -425,10 +454,19 @@ mod test {
                 ("an inn", "create inn"),
                 ("an imports-shop", "create imports-shop"),
                 ("an island", "create island"),
+                ("islands", "create archipelago"),
             ][..],
             block_on(Place::autocomplete("an i", &app_meta())),
         );
 
+        assert_autocomplete(
+            &[
+                ("Foo, an island", "create island"),
+                ("Foo, islands", "create archipelago"),
+            ][..],
+            block_on(Place::autocomplete("Foo, a isl", &app_meta())),
+        );
+
         assert_autocomplete(
             &[("an inn named [name]", "specify a name")][..],
             block_on(Place::autocomplete("an inn n", &app_meta())),
@@ -1,18 +1,52 @@
-use super::{Field, Npc, Place, Thing};
+use super::npc::{Relationship, RelationshipKind, Species};
+use super::place::PlaceType;
+use super::{parse_tags, Demographics, Field, Npc, Place, Thing, ThingRelations};
 use crate::app::{
-    AppMeta, Autocomplete, AutocompleteSuggestion, CommandAlias, CommandMatches, ContextAwareParse,
-    Runnable,
+    AppMeta, Autocomplete, AutocompleteSuggestion, Command, CommandAlias, CommandMatches,
+    ContextAwareParse, Runnable,
 };
 use crate::storage::{Change, RepositoryError, StorageCommand};
 use crate::utils::{quoted_words, CaseInsensitiveStr};
 use async_trait::async_trait;
 use futures::join;
+use rand::Rng;
+use std::collections::HashSet;
 use std::fmt;
+use std::iter;
 use std::ops::Range;
 
 mod autocomplete;
 mod parse;
 
+/// Field names accepted by `reroll [field]`.
+const REROLL_FIELDS: &[&str] = &[
+    "name",
+    "gender",
+    "age",
+    "species",
+    "ethnicity",
+    "occupation",
+    "personality",
+    "alignment",
+    "ancestry",
+    "quirk",
+    "except name",
+    "body",
+];
+
+/// Values of `reroll [field]` that regenerate everything about a character except their name.
+const REROLL_ALL_BUT_NAME: &[&str] = &["except name", "body"];
+
+/// Upper bound for `[thing] x[count]`, so a typo can't hang the app.
+const MAX_CREATE_COUNT: usize = 20;
+
+/// How many levels of places `what is in [place]` will descend, and how far up `put [name] in [place]`
+/// will look for a cycle before giving up.
+const MAX_TREE_DEPTH: usize = 10;
+
+/// Number of characters generated in each building by `generate town`.
+const TOWN_NPCS_PER_BUILDING: usize = 2;
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum WorldCommand {
     Create {
@@ -21,10 +55,66 @@ pub enum WorldCommand {
     CreateMultiple {
         thing: Thing,
     },
+    CreateCount {
+        thing: Thing,
+        count: usize,
+    },
+    CreateRegion {
+        geography: String,
+        name: Option<String>,
+    },
+    CreateTown {
+        name: Option<String>,
+    },
     Edit {
         name: String,
         diff: ParsedThing<Thing>,
     },
+    Describe {
+        name: String,
+    },
+    LocationsIn {
+        name: String,
+    },
+    MoveInto {
+        name: String,
+        parent: String,
+    },
+    Note {
+        name: String,
+        note: String,
+        replace: bool,
+    },
+    Notes {
+        name: String,
+    },
+    Rename {
+        name: String,
+        new_name: String,
+    },
+    Relate {
+        name: String,
+        other: String,
+        kind: RelationshipKind,
+    },
+    Reroll {
+        field: String,
+    },
+    SetDemographics {
+        weights: String,
+    },
+    ShowDemographics,
+    Tag {
+        name: String,
+        tags: Vec<String>,
+        remove: bool,
+    },
+    Tree {
+        name: String,
+    },
+    WhoIsAt {
+        name: String,
+    },
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -37,112 +127,79 @@ pub struct ParsedThing<T> {
 #[async_trait(?Send)]
 impl Runnable for WorldCommand {
     async fn run(self, input: &str, app_meta: &mut AppMeta) -> Result<String, String> {
+        // `CreateRegion` isn't listed because it runs `Create`, which reseeds on its behalf.
+        if matches!(
+            self,
+            Self::Create { .. }
+                | Self::CreateMultiple { .. }
+                | Self::CreateCount { .. }
+                | Self::CreateTown { .. }
+                | Self::Reroll { .. }
+        ) {
+            app_meta.reseed();
+        }
+
         match self {
             Self::Create {
                 thing: parsed_thing,
             } => {
                 let diff = parsed_thing.thing;
                 let unknown_words = parsed_thing.unknown_words.to_owned();
-                let mut output = None;
-
-                for _ in 0..10 {
-                    let mut thing = diff.clone();
-                    thing.regenerate(&mut app_meta.rng, &app_meta.demographics);
-                    let mut temp_output = format!(
-                        "{}",
-                        thing.display_details(
+
+                if let Some(word) = find_unknown_species(&diff, input, &unknown_words) {
+                    return Err(Species::not_recognized(word));
+                }
+
+                let thing = create_thing(&diff, false, app_meta).await?;
+                let mut output = format!(
+                    "{}",
+                    thing
+                        .display_details(
                             app_meta
                                 .repository
                                 .load_relations(&thing)
                                 .await
                                 .unwrap_or_default()
                         )
-                    );
-                    let mut command_alias = None;
-
-                    let change = match thing.name() {
-                        Field::Locked(Some(name)) => {
-                            temp_output.push_str(&format!(
-                                    "\n\n_Because you specified a name, {name} has been automatically added to your `journal`. Use `undo` to remove {them}._",
-                                    name = name,
-                                    them = thing.gender().them(),
-                                ));
-
-                            Change::CreateAndSave { thing }
-                        }
-                        Field::Unlocked(Some(name)) => {
-                            temp_output.push_str(&format!(
-                                    "\n\n_{name} has not yet been saved. Use ~save~ to save {them} to your `journal`. For more suggestions, type ~more~._",
-                                    name = name,
-                                    them = thing.gender().them(),
-                                ));
-
-                            command_alias = Some(CommandAlias::literal(
-                                "save",
-                                format!("save {}", name),
-                                StorageCommand::Save {
-                                    name: name.to_string(),
-                                }
-                                .into(),
-                            ));
-
-                            app_meta.command_aliases.insert(CommandAlias::literal(
-                                "more",
-                                format!("create {}", diff.display_description()),
-                                WorldCommand::CreateMultiple {
-                                    thing: diff.clone(),
-                                }
-                                .into(),
-                            ));
-
-                            Change::Create { thing }
-                        }
-                        _ => Change::Create { thing },
-                    };
-
-                    match app_meta.repository.modify(change).await {
-                        Ok(_) => {
-                            output = Some(temp_output);
+                        .with_units(app_meta.units)
+                );
+                let name = thing.name().to_string();
 
-                            if let Some(alias) = command_alias {
-                                app_meta.command_aliases.insert(alias);
-                            }
+                if matches!(diff.name(), Field::Locked(Some(_))) {
+                    output.push_str(&format!(
+                        "\n\n_Because you specified a name, {name} has been automatically added to your `journal`. Use `undo` to remove {them}._",
+                        name = name,
+                        them = thing.gender().them(),
+                    ));
+                } else {
+                    output.push_str(&format!(
+                        "\n\n_{name} has not yet been saved. Use ~save~ to save {them} to your `journal`. For more suggestions, type ~more~._",
+                        name = name,
+                        them = thing.gender().them(),
+                    ));
 
-                            break;
-                        }
-                        Err((Change::Create { thing }, RepositoryError::NameAlreadyExists))
-                        | Err((
-                            Change::CreateAndSave { thing },
-                            RepositoryError::NameAlreadyExists,
-                        )) => {
-                            if thing.name().is_locked() {
-                                if let Ok(other_thing) = app_meta
-                                    .repository
-                                    .get_by_name(thing.name().value().unwrap())
-                                    .await
-                                {
-                                    return Err(format!(
-                                        "That name is already in use by {}.",
-                                        other_thing.display_summary(),
-                                    ));
-                                } else {
-                                    return Err("That name is already in use.".to_string());
-                                }
-                            }
+                    app_meta.command_aliases.insert(CommandAlias::literal(
+                        "save",
+                        format!("save {}", name),
+                        StorageCommand::Save { name: name.clone() }.into(),
+                    ));
+                    app_meta.command_aliases.insert(CommandAlias::literal(
+                        "more",
+                        format!("create {}", diff.display_description()),
+                        WorldCommand::CreateMultiple {
+                            thing: diff.clone(),
                         }
-                        Err((Change::Create { thing }, RepositoryError::MissingName)) => return Err(format!("There is no name generator implemented for that type. You must specify your own name using `{} named [name]`.", thing.display_description())),
-                        Err(_) => return Err("An error occurred.".to_string()),
-                    }
+                        .into(),
+                    ));
+                    app_meta.push_generated(&name);
                 }
 
-                if let Some(output) = output {
-                    Ok(append_unknown_words_notice(output, input, unknown_words))
-                } else {
-                    Err(format!(
-                        "Couldn't create a unique {} name.",
-                        diff.display_description(),
-                    ))
+                if let Some(notice) = age_range_notice(&diff, &thing) {
+                    output.push_str(&notice);
                 }
+                app_meta.last_thing = Some(name);
+
+                Ok(append_unknown_words_notice(output, input, unknown_words))
             }
             Self::CreateMultiple { thing } => {
                 let mut output = format!(
@@ -150,54 +207,161 @@ impl Runnable for WorldCommand {
                     thing.display_description(),
                 );
 
-                for i in 1..=10 {
-                    let mut thing_output = None;
+                output.push_str(&create_list(&thing, 10, app_meta).await?);
+
+                app_meta.command_aliases.insert(CommandAlias::literal(
+                    "more",
+                    format!("create {}", thing.display_description()),
+                    Self::CreateMultiple { thing }.into(),
+                ));
+
+                output.push_str("\n\n_For even more suggestions, type ~more~._");
+
+                Ok(output)
+            }
+            Self::CreateCount { thing, count } => {
+                if !(1..=MAX_CREATE_COUNT).contains(&count) {
+                    return Err(format!(
+                        "You can only create between 1 and {} at a time.",
+                        MAX_CREATE_COUNT,
+                    ));
+                }
+
+                let mut output = format!("# {} × {}", count, thing.display_description());
+
+                output.push_str(&create_list(&thing, count, app_meta).await?);
+                output.push_str(
+                    "\n\n_None of these have been saved yet. Use `save [name]` to save any of them to your `journal`._",
+                );
+
+                Ok(output)
+            }
+            Self::CreateRegion { geography, name } => {
+                let geographies = || {
+                    PlaceType::variants()
+                        .filter(PlaceType::is_geography)
+                        .map(|subtype| subtype.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+
+                if geography.is_empty() {
+                    return Err(format!(
+                        "Regions are generated by type, eg. `region forest`. Try one of: {}.",
+                        geographies(),
+                    ));
+                }
+
+                let subtype = geography
+                    .parse::<PlaceType>()
+                    .ok()
+                    .filter(PlaceType::is_geography)
+                    .ok_or_else(|| {
+                        format!(
+                            "\"{}\" is not a recognized type of region. Try one of: {}.",
+                            geography,
+                            geographies(),
+                        )
+                    })?;
+
+                Self::Create {
+                    thing: ParsedThing {
+                        thing: Place {
+                            subtype: Field::new(subtype),
+                            name: name.map(Field::new).unwrap_or_default(),
+                            ..Default::default()
+                        }
+                        .into(),
+                        unknown_words: Vec::new(),
+                        word_count: 0,
+                    },
+                }
+                .run(input, app_meta)
+                .await
+            }
+            Self::CreateTown { name } => {
+                let mut diff: Thing = Place {
+                    subtype: "town".parse::<PlaceType>().ok().into(),
+                    ..Default::default()
+                }
+                .into();
+
+                // Settle on the town's name and check every name that it and its buildings will
+                // need before saving anything, so that a clash can't leave half a town behind.
+                let town_name = if let Some(name) = name {
+                    if let Some(other_thing) = town_name_in_use(&name, app_meta).await {
+                        return Err(format!(
+                            "That name is already in use by {}.",
+                            other_thing.display_summary(),
+                        ));
+                    }
+                    name
+                } else {
+                    let demographics = local_demographics(&diff, app_meta).await;
+                    let mut town_name = None;
 
                     for _ in 0..10 {
-                        let mut thing = thing.clone();
-                        thing.regenerate(&mut app_meta.rng, &app_meta.demographics);
-                        let temp_thing_output = format!(
-                            "{}~{}~ {}",
-                            if i == 1 { "\n\n" } else { "\\\n" },
-                            i % 10,
-                            thing.display_summary(),
-                        );
-                        let command_alias = CommandAlias::literal(
-                            (i % 10).to_string(),
-                            format!("load {}", thing.name()),
-                            StorageCommand::Load {
-                                name: thing.name().to_string(),
-                            }
-                            .into(),
-                        );
+                        let mut thing = diff.clone();
+                        thing.regenerate(&mut app_meta.rng, &demographics);
+                        let name = thing.name().to_string();
 
-                        match app_meta.repository.modify(Change::Create { thing }).await {
-                            Ok(_) => {
-                                app_meta.command_aliases.insert(command_alias);
-                                thing_output = Some(temp_thing_output);
-                                break;
-                            }
-                            Err((_, RepositoryError::NameAlreadyExists)) => {}
-                            Err(_) => return Err("An error occurred.".to_string()),
+                        if town_name_in_use(&name, app_meta).await.is_none() {
+                            town_name = Some(name);
+                            break;
                         }
                     }
 
-                    if let Some(thing_output) = thing_output {
-                        output.push_str(&thing_output);
-                    } else {
-                        output.push_str("\n\n! An error occurred generating additional results.");
-                        break;
+                    town_name.ok_or_else(|| "Couldn't create a unique town name.".to_string())?
+                };
+
+                if let Thing::Place(place) = &mut diff {
+                    place.name = Field::new(town_name);
+                }
+                let town = create_and_save(diff, app_meta).await?;
+                let town_name = town.name().to_string();
+                app_meta.last_thing = Some(town_name.clone());
+
+                let mut output = format!("# {}\n\n{}", town_name, town.display_summary());
+
+                for (subtype, name) in town_buildings(&town_name) {
+                    let building = create_and_save(
+                        Place {
+                            location_uuid: town.place().and_then(|place| place.uuid.clone()).into(),
+                            subtype: subtype.parse::<PlaceType>().ok().into(),
+                            name: name.map(Field::new).unwrap_or_default(),
+                            ..Default::default()
+                        }
+                        .into(),
+                        app_meta,
+                    )
+                    .await?;
+
+                    output.push_str(&format!("\n\n## {}\n", building.display_summary()));
+
+                    for i in 0..TOWN_NPCS_PER_BUILDING {
+                        let npc = create_and_save(
+                            Npc {
+                                location_uuid: building
+                                    .place()
+                                    .and_then(|place| place.uuid.clone())
+                                    .into(),
+                                ..Default::default()
+                            }
+                            .into(),
+                            app_meta,
+                        )
+                        .await?;
+
+                        output.push_str(if i == 0 { "\n" } else { "\\\n" });
+                        output.push_str(&npc.display_summary().to_string());
                     }
                 }
 
-                app_meta.command_aliases.insert(CommandAlias::literal(
-                    "more",
-                    format!("create {}", thing.display_description()),
-                    Self::CreateMultiple { thing }.into(),
+                output.push_str(&format!(
+                    "\n\n_{} and everyone in it have been saved to your `journal`. Use `who is at [place]` to see who is where._",
+                    town_name,
                 ));
 
-                output.push_str("\n\n_For even more suggestions, type ~more~._");
-
                 Ok(output)
             }
             Self::Edit { name, diff } => {
@@ -207,107 +371,1414 @@ impl Runnable for WorldCommand {
                     word_count: _,
                 } = diff;
 
+                let name = app_meta.resolve_pronoun(&name)?;
                 let thing_type = diff.as_str();
 
-                match app_meta.repository.modify(Change::Edit {
+                let result = app_meta
+                    .repository
+                    .modify(Change::Edit {
                         name: name.clone(),
                         uuid: None,
                         diff,
-                    }).await {
-                    Ok(Some(thing)) if matches!(app_meta.repository.undo_history().next(), Some(Change::EditAndUnsave { .. })) => Ok(format!(
-                        "{}\n\n_{} was successfully edited and automatically saved to your `journal`. Use `undo` to reverse this._",
-                        thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default()),
-                        name,
-                    )),
-                    Ok(Some(thing)) => Ok(format!(
-                        "{}\n\n_{} was successfully edited. Use `undo` to reverse this._",
-                        thing.display_details(app_meta.repository.load_relations(&thing).await.unwrap_or_default()),
-                        name,
-                    )),
-                    Err((_, RepositoryError::NotFound)) => Err(format!(r#"There is no {} named "{}"."#, thing_type, name)),
+                    })
+                    .await;
+                if let Ok(Some(thing)) = &result {
+                    app_meta.last_thing = Some(thing.name().to_string());
+                }
+
+                match result {
+                    Ok(Some(thing)) => Ok(display_edited(
+                        &thing,
+                        &format!("{} was successfully edited", name),
+                        " and automatically saved to your `journal`",
+                        app_meta,
+                    )
+                    .await),
+                    Err((_, RepositoryError::NotFound)) => {
+                        Err(format!(r#"There is no {} named "{}"."#, thing_type, name))
+                    }
                     _ => Err(format!("Couldn't edit `{}`.", name)),
                 }
                 .map(|s| append_unknown_words_notice(s, input, unknown_words))
             }
-        }
-    }
-}
+            Self::Reroll { field } => {
+                if !REROLL_FIELDS.contains(&field.as_str()) {
+                    return Err(format!(
+                        "\"{}\" can't be rerolled. Try one of: {}.",
+                        field,
+                        REROLL_FIELDS.join(", "),
+                    ));
+                }
 
-#[async_trait(?Send)]
-impl ContextAwareParse for WorldCommand {
-    async fn parse_input(input: &str, app_meta: &AppMeta) -> CommandMatches<Self> {
-        let mut matches = CommandMatches::default();
+                let thing = if let Some(change) = app_meta.repository.undo_history().next() {
+                    app_meta.repository.get_by_change(change).await.ok()
+                } else {
+                    None
+                }
+                .ok_or_else(|| "There is nothing to reroll.".to_string())?;
 
-        if let Some(Ok(thing)) = input
-            .strip_prefix_ci("create ")
-            .map(|s| s.parse::<ParsedThing<Thing>>())
-        {
-            if thing.unknown_words.is_empty() {
-                matches.push_canonical(Self::Create { thing });
-            } else {
-                matches.push_fuzzy(Self::Create { thing });
+                let name = thing.name().to_string();
+
+                // Only dragonborn have a draconic ancestry.
+                if field == "ancestry" {
+                    if let Some(species) = thing
+                        .npc()
+                        .and_then(|npc| npc.species.value())
+                        .filter(|species| **species != Species::Dragonborn)
+                    {
+                        return Err(format!(
+                            "{}'s species is {}, and only dragonborn have an ancestry to reroll.",
+                            name, species,
+                        ));
+                    }
+                }
+
+                let demographics = local_demographics(&thing, app_meta).await;
+                let old_species = thing.npc().and_then(|npc| npc.species.value().copied());
+                let mut diff = None;
+
+                for _ in 0..100 {
+                    let rerolled = reroll_field(&thing, &field, &mut app_meta.rng, &demographics)
+                        .ok_or_else(|| {
+                        format!("There is no {} to reroll for {}.", field, name)
+                    })?;
+
+                    if field == "species"
+                        && rerolled.npc().and_then(|npc| npc.species.value().copied())
+                            == old_species
+                    {
+                        continue;
+                    }
+
+                    // The old name counts as taken, so this also guarantees a new name.
+                    if let Some(new_name) = rerolled.name().value() {
+                        if app_meta.repository.get_by_name(new_name).await.is_ok() {
+                            continue;
+                        }
+                    }
+
+                    diff = Some(rerolled);
+                    break;
+                }
+
+                let diff = diff.ok_or_else(|| {
+                    if field == "species" {
+                        format!("Couldn't find a different species for {}.", name)
+                    } else {
+                        format!(
+                            "Couldn't create a unique {} name.",
+                            thing.display_description(),
+                        )
+                    }
+                })?;
+
+                let old_gender = thing.npc().and_then(|npc| npc.gender.value().copied());
+                let result = app_meta
+                    .repository
+                    .modify(Change::Edit {
+                        name: name.clone(),
+                        uuid: thing.uuid().cloned(),
+                        diff,
+                    })
+                    .await;
+                if let Ok(Some(thing)) = &result {
+                    app_meta.last_thing = Some(thing.name().to_string());
+                }
+
+                let rerolled = if REROLL_ALL_BUT_NAME.contains(&field.as_str()) {
+                    format!("Everything but {}'s name was rerolled", name)
+                } else {
+                    format!("{}'s {} was rerolled", name, field)
+                };
+
+                match result {
+                    Ok(Some(thing)) => {
+                        let mut output = format!(
+                            "{}\n\n_{}",
+                            thing
+                                .display_details(
+                                    app_meta
+                                        .repository
+                                        .load_relations(&thing)
+                                        .await
+                                        .unwrap_or_default()
+                                )
+                                .with_units(app_meta.units),
+                            rerolled,
+                        );
+
+                        if matches!(
+                            app_meta.repository.undo_history().next(),
+                            Some(Change::EditAndUnsave { .. }),
+                        ) {
+                            output.push_str(&format!(
+                                " and {} was automatically saved to your `journal`",
+                                thing.name(),
+                            ));
+                        }
+
+                        output.push_str(". Use `undo` to reverse this._");
+
+                        // Names are generated to suit a character's gender, so a name that
+                        // outlives a change of gender may no longer fit.
+                        if let Some(npc) = thing.npc().filter(|_| field != "name") {
+                            if let (Some(old_gender), Some(new_gender)) =
+                                (old_gender, npc.gender.value())
+                            {
+                                if &old_gender != new_gender {
+                                    output.push_str(&format!(
+                                        "\n\n_{} is now {}, but kept a name chosen for someone {}. Use `reroll name` if it no longer fits._",
+                                        name,
+                                        new_gender.name(),
+                                        old_gender.name(),
+                                    ));
+                                }
+                            }
+                        }
+
+                        Ok(output)
+                    }
+                    _ => Err(format!("Couldn't reroll the {} of `{}`.", field, name)),
+                }
             }
-        } else if let Ok(thing) = input.parse::<ParsedThing<Thing>>() {
-            matches.push_fuzzy(Self::Create { thing });
-        }
+            Self::Rename { name, new_name } => {
+                let name = app_meta.resolve_pronoun(&name)?;
+                let thing = app_meta
+                    .repository
+                    .get_by_name(&name)
+                    .await
+                    .map_err(|_| format!("There is no entity named \"{}\".", name))?;
+                let name = thing.name().to_string();
+
+                // Changing only the capitalization of a name doesn't collide with anything.
+                if !new_name.eq_ci(&name) {
+                    if let Ok(other) = app_meta.repository.get_by_name(&new_name).await {
+                        return Err(format!(
+                            "There is already an entity named `{}`. Try a different name, or `delete {}` first.",
+                            other.name(),
+                            other.name(),
+                        ));
+                    }
 
-        if let Some(word) = quoted_words(input)
-            .skip(1)
-            .find(|word| word.as_str().eq_ci("is"))
-        {
-            let (name, description) = (
-                input[..word.range().start].trim(),
-                input[word.range().end..].trim(),
-            );
+                    // Typing a name that is also a command (eg. `help`) would run the command
+                    // instead of loading the entry.
+                    if !Command::parse_input_irrefutable(&new_name, app_meta)
+                        .await
+                        .is_empty()
+                    {
+                        return Err(format!(
+                            "`{}` is already a command, so it can't be used as a name.",
+                            new_name,
+                        ));
+                    }
+                }
 
-            let (diff, thing) = if let Ok(thing) = app_meta.repository.get_by_name(name).await {
-                (
-                    match thing {
-                        Thing::Npc(_) => description
-                            .parse::<ParsedThing<Npc>>()
-                            .map(|npc| npc.into_thing()),
-                        Thing::Place(_) => description
-                            .parse::<ParsedThing<Place>>()
-                            .map(|npc| npc.into_thing()),
-                    }
-                    .or_else(|_| description.parse()),
-                    Some(thing),
-                )
-            } else {
-                // This will be an error when we try to run the command, but for now we'll pretend
-                // it's valid so that we can provide a more coherent message.
-                (description.parse(), None)
-            };
+                let diff: Thing = match thing {
+                    Thing::Npc(_) => Npc {
+                        name: new_name.as_str().into(),
+                        ..Default::default()
+                    }
+                    .into(),
+                    Thing::Place(_) => Place {
+                        name: new_name.as_str().into(),
+                        ..Default::default()
+                    }
+                    .into(),
+                };
 
-            if let Ok(mut diff) = diff {
-                let name = thing
-                    .map(|t| t.name().to_string())
-                    .unwrap_or_else(|| name.to_string());
+                let result = app_meta
+                    .repository
+                    .modify(Change::Edit {
+                        name: name.clone(),
+                        uuid: thing.uuid().cloned(),
+                        diff,
+                    })
+                    .await;
+                if let Ok(Some(thing)) = &result {
+                    app_meta.last_thing = Some(thing.name().to_string());
+
+                    if let Some(generated) = app_meta
+                        .generated
+                        .iter_mut()
+                        .find(|generated| generated.eq_ci(&name))
+                    {
+                        *generated = thing.name().to_string();
+                    }
+                }
 
-                diff.unknown_words.iter_mut().for_each(|range| {
-                    *range = range.start + word.range().end + 1..range.end + word.range().end + 1
-                });
+                match result {
+                    Ok(Some(thing)) => Ok(display_edited(
+                        &thing,
+                        &format!("{} was renamed to {}", name, thing.name()),
+                        " and automatically saved to your `journal`",
+                        app_meta,
+                    )
+                    .await),
+                    _ => Err(format!("Couldn't rename `{}`.", name)),
+                }
+            }
+            Self::MoveInto { name, parent } => {
+                let (name, parent) = (
+                    app_meta.resolve_pronoun(&name)?,
+                    app_meta.resolve_pronoun(&parent)?,
+                );
+                let thing = app_meta
+                    .repository
+                    .get_by_name(&name)
+                    .await
+                    .map_err(|_| format!("There is no entity named \"{}\".", name))?;
+                let name = thing.name().to_string();
+
+                let parent = match app_meta.repository.get_by_name(&parent).await {
+                    Ok(Thing::Place(place)) => place,
+                    Ok(thing) => {
+                        return Err(format!(
+                            "`{}` is not a place, so nothing can be put in it.",
+                            thing.name(),
+                        ))
+                    }
+                    Err(_) => return Err(format!("There is no place named \"{}\".", parent)),
+                };
+                let parent_name = parent.name.to_string();
+
+                let parent_uuid = parent.uuid.clone().ok_or_else(|| {
+                    format!(
+                        "Nothing can be put in `{}` until it has been saved. Use `save {}` to save it to your `journal`.",
+                        parent_name, parent_name,
+                    )
+                })?;
 
-                matches.push_fuzzy(Self::Edit { name, diff });
+                if thing.uuid() == Some(parent_uuid.as_ref()) {
+                    return Err(format!("`{}` can't be put inside itself.", name));
+                }
+
+                // Walk up from the new parent to make sure the thing isn't one of its ancestors,
+                // which would make the thing its own ancestor. The whole chain is walked, however
+                // deep, stopping only if it loops back on itself.
+                if let Some(uuid) = thing.uuid() {
+                    let mut ancestor_uuid = parent.location_uuid.value().cloned();
+                    let mut visited = HashSet::new();
+
+                    while let Some(location_uuid) = ancestor_uuid.take() {
+                        if location_uuid.as_ref() == uuid {
+                            return Err(format!(
+                                "`{}` is inside `{}`, so `{}` can't also be inside `{}`.",
+                                parent_name, name, name, parent_name,
+                            ));
+                        } else if !visited.insert(location_uuid.clone()) {
+                            break;
+                        }
+
+                        ancestor_uuid = app_meta
+                            .repository
+                            .get_by_uuid(location_uuid.as_ref())
+                            .await
+                            .ok()
+                            .and_then(|thing| {
+                                thing
+                                    .place()
+                                    .and_then(|place| place.location_uuid.value().cloned())
+                            });
+                    }
+                }
+
+                let diff: Thing = match thing {
+                    Thing::Npc(_) => Npc {
+                        location_uuid: Field::new(parent_uuid),
+                        ..Default::default()
+                    }
+                    .into(),
+                    Thing::Place(_) => Place {
+                        location_uuid: Field::new(parent_uuid),
+                        ..Default::default()
+                    }
+                    .into(),
+                };
+
+                let result = app_meta
+                    .repository
+                    .modify(Change::Edit {
+                        name: name.clone(),
+                        uuid: thing.uuid().cloned(),
+                        diff,
+                    })
+                    .await;
+                if let Ok(Some(thing)) = &result {
+                    app_meta.last_thing = Some(thing.name().to_string());
+                }
+
+                match result {
+                    Ok(Some(thing)) => Ok(display_edited(
+                        &thing,
+                        &format!("{} was put in {}", name, parent_name),
+                        " and automatically saved to your `journal`",
+                        app_meta,
+                    )
+                    .await),
+                    _ => Err(format!("Couldn't put `{}` in `{}`.", name, parent_name)),
+                }
+            }
+            Self::Relate { name, other, kind } => {
+                let (name, other) = (
+                    app_meta.resolve_pronoun(&name)?,
+                    app_meta.resolve_pronoun(&other)?,
+                );
+
+                let mut npcs = Vec::with_capacity(2);
+                for name in [&name, &other] {
+                    match app_meta.repository.get_by_name(name).await {
+                        Ok(Thing::Npc(npc)) => npcs.push(npc),
+                        Ok(thing) => {
+                            return Err(format!(
+                                "`{}` is not a character, so it can't be related to anyone.",
+                                thing.name(),
+                            ))
+                        }
+                        Err(_) => return Err(format!("There is no character named \"{}\".", name)),
+                    }
+                }
+                let (npc, other) = (npcs.remove(0), npcs.remove(0));
+                let (name, other_name) = (npc.name.to_string(), other.name.to_string());
+
+                let other_uuid = other.uuid.as_ref().ok_or_else(|| {
+                    format!(
+                        "Nobody can be related to `{}` until they have been saved. Use `save {}` to save them to your `journal`.",
+                        other_name, other_name,
+                    )
+                })?;
+
+                if npc.uuid.as_ref() == Some(other_uuid) {
+                    return Err(format!("`{}` can't be related to themself.", name));
+                }
+
+                // Relating the same pair again replaces the relationship in place rather than
+                // adding a second one. The other character's side is updated by the repository.
+                let mut relationships: Vec<Relationship> =
+                    npc.relationships.value().cloned().unwrap_or_default();
+                let relationship = Relationship {
+                    kind,
+                    uuid: other_uuid.clone(),
+                };
+                let replaced = if let Some(existing) = relationships
+                    .iter_mut()
+                    .find(|relationship| &relationship.uuid == other_uuid)
+                {
+                    Some(std::mem::replace(existing, relationship).kind)
+                } else {
+                    relationships.push(relationship);
+                    None
+                };
+
+                let result = app_meta
+                    .repository
+                    .modify(Change::Edit {
+                        name: name.clone(),
+                        uuid: npc.uuid.clone().map(|uuid| uuid.into()),
+                        diff: Npc {
+                            relationships: Field::new(relationships),
+                            ..Default::default()
+                        }
+                        .into(),
+                    })
+                    .await;
+                let autosaved = matches!(
+                    app_meta.repository.undo_history().next(),
+                    Some(Change::EditAndUnsave { .. }),
+                );
+
+                match (result, app_meta.repository.get_by_name(&name).await) {
+                    (Ok(_), Ok(thing)) => Ok(format!(
+                        "{}\n\n_{} is now {}'s {}{}{}. Use `undo` to reverse this._",
+                        thing
+                            .display_details(
+                                app_meta
+                                    .repository
+                                    .load_relations(&thing)
+                                    .await
+                                    .unwrap_or_default()
+                            )
+                            .with_units(app_meta.units),
+                        name,
+                        other_name,
+                        kind.noun(&npc.gender()),
+                        replaced
+                            .filter(|replaced| replaced != &kind)
+                            .map(|replaced| format!(" instead of {}", replaced.noun(&npc.gender())))
+                            .unwrap_or_default(),
+                        if autosaved {
+                            " and was automatically saved to your `journal`"
+                        } else {
+                            ""
+                        },
+                    )),
+                    _ => Err(format!("Couldn't relate `{}` to `{}`.", name, other_name)),
+                }
+            }
+            Self::Note {
+                name,
+                note,
+                replace,
+            } => {
+                let name = app_meta.resolve_pronoun(&name)?;
+                let thing = app_meta
+                    .repository
+                    .get_by_name(&name)
+                    .await
+                    .map_err(|_| format!("There is no entity named \"{}\".", name))?;
+                let name = thing.name().to_string();
+
+                let (notes, action) = if replace && note.is_empty() {
+                    (None, format!("{}'s notes were cleared", name))
+                } else if replace {
+                    (Some(note), format!("{}'s notes were replaced", name))
+                } else if note.is_empty() {
+                    return Err(format!(
+                        "There is nothing to note. Try `note add {}: [note]`.",
+                        name,
+                    ));
+                } else if let Some(existing) =
+                    thing.notes().value().filter(|notes| !notes.is_empty())
+                {
+                    (
+                        Some(format!("{}\n{}", existing, note)),
+                        format!("A note was added to {}", name),
+                    )
+                } else {
+                    (Some(note), format!("A note was added to {}", name))
+                };
+
+                let diff: Thing = match thing {
+                    Thing::Npc(_) => Npc {
+                        notes: Field::Locked(notes),
+                        ..Default::default()
+                    }
+                    .into(),
+                    Thing::Place(_) => Place {
+                        notes: Field::Locked(notes),
+                        ..Default::default()
+                    }
+                    .into(),
+                };
+
+                let result = app_meta
+                    .repository
+                    .modify(Change::Edit {
+                        name: name.clone(),
+                        uuid: thing.uuid().cloned(),
+                        diff,
+                    })
+                    .await;
+                if let Ok(Some(thing)) = &result {
+                    app_meta.last_thing = Some(thing.name().to_string());
+                }
+
+                match result {
+                    Ok(Some(thing)) => Ok(display_edited(
+                        &thing,
+                        &action,
+                        &format!(" and {} was automatically saved to your `journal`", name),
+                        app_meta,
+                    )
+                    .await),
+                    _ => Err(format!("Couldn't change the notes on `{}`.", name)),
+                }
+            }
+            Self::Notes { name } => {
+                let name = app_meta.resolve_pronoun(&name)?;
+                let thing = app_meta
+                    .repository
+                    .get_by_name(&name)
+                    .await
+                    .map_err(|_| format!("There is no entity named \"{}\".", name))?;
+                let name = thing.name().to_string();
+                app_meta.last_thing = Some(name.clone());
+
+                if let Some(notes) = thing.notes().value().filter(|notes| !notes.is_empty()) {
+                    Ok(format!(
+                        "# Notes on {}\n\n{}",
+                        name,
+                        notes.lines().collect::<Vec<_>>().join("\\\n"),
+                    ))
+                } else {
+                    Ok(format!(
+                        "There are no notes on `{}` yet. To add one, try `note add {}: [note]`.",
+                        name, name,
+                    ))
+                }
+            }
+            Self::Tag { name, tags, remove } => {
+                let name = app_meta.resolve_pronoun(&name)?;
+                let thing = app_meta
+                    .repository
+                    .get_by_name(&name)
+                    .await
+                    .map_err(|_| format!("There is no entity named \"{}\".", name))?;
+                let name = thing.name().to_string();
+                let mut all_tags = thing.tags().value().cloned().unwrap_or_default();
+
+                let action = if remove {
+                    if let Some(tag) = tags.iter().find(|tag| !all_tags.contains(tag)) {
+                        return Err(format!("`{}` isn't tagged {}.", name, tag));
+                    }
+
+                    all_tags.retain(|tag| !tags.contains(tag));
+                    format!("{} is no longer tagged {}", name, tags.join(", "))
+                } else {
+                    let new_tags: Vec<String> = tags
+                        .into_iter()
+                        .filter(|tag| !all_tags.contains(tag))
+                        .collect();
+
+                    if new_tags.is_empty() {
+                        return Err(format!("`{}` already has those tags.", name));
+                    }
+
+                    let action = format!("{} was tagged {}", name, new_tags.join(", "));
+                    all_tags.extend(new_tags);
+                    action
+                };
+
+                // Removing the last tag clears the field rather than leaving an empty list behind.
+                let tags = Some(all_tags).filter(|tags| !tags.is_empty());
+                let diff: Thing = match thing {
+                    Thing::Npc(_) => Npc {
+                        tags: Field::Locked(tags),
+                        ..Default::default()
+                    }
+                    .into(),
+                    Thing::Place(_) => Place {
+                        tags: Field::Locked(tags),
+                        ..Default::default()
+                    }
+                    .into(),
+                };
+
+                let result = app_meta
+                    .repository
+                    .modify(Change::Edit {
+                        name: name.clone(),
+                        uuid: thing.uuid().cloned(),
+                        diff,
+                    })
+                    .await;
+                let autosaved = matches!(
+                    app_meta.repository.undo_history().next(),
+                    Some(Change::EditAndUnsave { .. }),
+                );
+
+                match result {
+                    Ok(Some(thing)) => {
+                        app_meta.last_thing = Some(thing.name().to_string());
+
+                        let mut output = thing
+                            .display_details(
+                                app_meta
+                                    .repository
+                                    .load_relations(&thing)
+                                    .await
+                                    .unwrap_or_default(),
+                            )
+                            .with_units(app_meta.units)
+                            .to_string();
+                        output.push_str(&format!("\n\n_{}", action));
+                        if autosaved {
+                            output.push_str(&format!(
+                                " and {} was automatically saved to your `journal`",
+                                name,
+                            ));
+                        }
+                        output.push_str(". Use `undo` to reverse this._");
+
+                        Ok(output)
+                    }
+                    _ => Err(format!("Couldn't change the tags on `{}`.", name)),
+                }
+            }
+            Self::Tree { name } => {
+                let name = app_meta.resolve_pronoun(&name)?;
+                let place = match app_meta.repository.get_by_name(&name).await {
+                    Ok(Thing::Place(place)) => place,
+                    Ok(thing) => return Err(format!("`{}` is not a place.", thing.name())),
+                    Err(_) => return Err(format!("There is no place named \"{}\".", name)),
+                };
+                let name = place.name.to_string();
+
+                let mut output = format!("# {}\n\n{}", name, place.display_summary());
+                let mut is_empty = true;
+
+                // Depth-first, so every place is listed directly beneath its parent.
+                let mut stack = Vec::new();
+                if let Some(uuid) = &place.uuid {
+                    stack.extend(
+                        child_places(uuid.as_ref(), app_meta)
+                            .await?
+                            .into_iter()
+                            .map(|place| (place, 0)),
+                    );
+                }
+
+                while let Some((place, depth)) = stack.pop() {
+                    output.push_str(if is_empty { "\n\n" } else { "\n" });
+                    output.push_str(&format!(
+                        "{}* {}",
+                        "  ".repeat(depth),
+                        place.display_summary()
+                    ));
+                    is_empty = false;
+
+                    if let (Some(uuid), true) = (&place.uuid, depth + 1 < MAX_TREE_DEPTH) {
+                        stack.extend(
+                            child_places(uuid.as_ref(), app_meta)
+                                .await?
+                                .into_iter()
+                                .map(|place| (place, depth + 1)),
+                        );
+                    }
+                }
+
+                if is_empty {
+                    output.push_str(&format!(
+                        "\n\n_There are no places in `{}` yet. To add one, try `put [name] in {}`._",
+                        name, name,
+                    ));
+                }
+
+                Ok(output)
+            }
+            Self::LocationsIn { name } => {
+                let name = app_meta.resolve_pronoun(&name)?;
+                let place = match app_meta.repository.get_by_name(&name).await {
+                    Ok(Thing::Place(place)) => place,
+                    Ok(thing) => return Err(format!("`{}` is not a place.", thing.name())),
+                    Err(_) => return Err(format!("There is no place named \"{}\".", name)),
+                };
+                let name = place.name.to_string();
+
+                if !place
+                    .subtype
+                    .value()
+                    .is_some_and(|subtype| subtype.is_region())
+                {
+                    return Err(format!(
+                        "`{}` is not a region. Try `what is in {}` instead.",
+                        name, name,
+                    ));
+                }
+
+                let uuid = place.uuid.ok_or_else(|| {
+                    format!(
+                        "Nothing can be in `{}` until it has been saved. Use `save {}` to save it to your `journal`.",
+                        name, name,
+                    )
+                })?;
+
+                // Locations inside smaller regions (such as a town in a county in a kingdom) belong
+                // to the larger region too.
+                let mut locations = Vec::new();
+                let mut stack: Vec<_> = child_places(uuid.as_ref(), app_meta)
+                    .await?
+                    .into_iter()
+                    .map(|place| (place, 0))
+                    .collect();
+
+                while let Some((place, depth)) = stack.pop() {
+                    if let (Some(uuid), true) = (&place.uuid, depth + 1 < MAX_TREE_DEPTH) {
+                        stack.extend(
+                            child_places(uuid.as_ref(), app_meta)
+                                .await?
+                                .into_iter()
+                                .map(|place| (place, depth + 1)),
+                        );
+                    }
+
+                    if place
+                        .subtype
+                        .value()
+                        .is_some_and(|subtype| subtype.is_location())
+                    {
+                        locations.push(place);
+                    }
+                }
+
+                if locations.is_empty() {
+                    return Ok(format!(
+                        "There are no locations in `{}` yet. To add one, try `put [name] in {}`.",
+                        name, name,
+                    ));
+                }
+
+                locations.sort_by(|a, b| a.name.to_string().cmp_ci(b.name.to_string()));
+
+                let mut output = format!("# Locations in {}", name);
+                locations.iter().enumerate().for_each(|(i, place)| {
+                    output.push_str(if i == 0 { "\n\n" } else { "\\\n" });
+                    output.push_str(&place.display_summary().to_string());
+                });
+
+                Ok(output)
+            }
+            Self::Describe { name } => match app_meta
+                .repository
+                .get_by_name(&app_meta.resolve_pronoun(&name)?)
+                .await
+            {
+                Ok(Thing::Npc(npc)) => {
+                    app_meta.last_thing = Some(npc.name.to_string());
+                    Ok(npc.display_prose().with_units(app_meta.units).to_string())
+                }
+                Ok(thing) => Err(format!(
+                    "`{}` is not a character. Try `{}` to see its details instead.",
+                    thing.name(),
+                    thing.name(),
+                )),
+                Err(_) => Err(format!("There is no character named \"{}\".", name)),
+            },
+            Self::SetDemographics { weights } => {
+                app_meta.demographics = Demographics::parse_weights(&weights)?;
+
+                Ok(format!(
+                    "Characters will now be generated with these demographics: {}.",
+                    app_meta.demographics,
+                ))
+            }
+            Self::ShowDemographics => Ok(format!(
+                "Characters are currently generated with these demographics: {}.",
+                app_meta.demographics,
+            )),
+            Self::WhoIsAt { name } => {
+                let name = app_meta.resolve_pronoun(&name)?;
+                let place = match app_meta.repository.get_by_name(&name).await {
+                    Ok(Thing::Place(place)) => place,
+                    Ok(thing) => return Err(format!("`{}` is not a place.", thing.name())),
+                    Err(_) => return Err(format!("There is no place named \"{}\".", name)),
+                };
+                let name = place.name.to_string();
+
+                let uuid = place.uuid.ok_or_else(|| {
+                    format!(
+                        "Nobody can be at `{}` until it has been saved. Use `save {}` to save it to your `journal`.",
+                        name, name,
+                    )
+                })?;
+
+                let mut npcs: Vec<Thing> = app_meta
+                    .repository
+                    .get_by_location(uuid.as_ref())
+                    .await
+                    .map_err(|_| "Couldn't access the journal.".to_string())?
+                    .into_iter()
+                    .filter(|thing| thing.npc().is_some())
+                    .collect();
+
+                if npcs.is_empty() {
+                    return Ok(format!(
+                        "Nobody is at `{}` yet. To add someone, try `npc in {}`.",
+                        name, name,
+                    ));
+                }
+
+                npcs.sort_by(|a, b| a.name().to_string().cmp_ci(b.name().to_string()));
+
+                let mut output = format!("# Characters at {}", name);
+                npcs.iter().enumerate().for_each(|(i, npc)| {
+                    output.push_str(if i == 0 { "\n\n" } else { "\\\n" });
+                    output.push_str(&npc.display_summary().to_string());
+                });
+
+                Ok(output)
+            }
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl ContextAwareParse for WorldCommand {
+    async fn parse_input(input: &str, app_meta: &AppMeta) -> CommandMatches<Self> {
+        // `clear world` wipes the journal and `world stats` counts what's in it; neither is a
+        // request to generate a world.
+        if input.in_ci(&["clear world", "clear world confirm", "world stats"]) {
+            return CommandMatches::default();
+        }
+
+        // Otherwise "town" would also be picked up as a fuzzy match for a single place.
+        if let Some(rest) = input.strip_prefix_ci("generate town") {
+            if rest.is_empty() {
+                return CommandMatches::new_canonical(Self::CreateTown { name: None });
+            } else if let Some(name) = rest
+                .strip_prefix_ci(" named ")
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+            {
+                return CommandMatches::new_canonical(Self::CreateTown {
+                    name: Some(name.to_string()),
+                });
+            }
+        }
+
+        // Likewise, "region forest" would otherwise be read as two competing place types. The
+        // word "region" always asks for a geographical region, so "generate cave region" is an
+        // error rather than a cave. Only a name ("region named Gondor") or a count ("region x3")
+        // is parsed like any other place.
+        if input.eq_ci("region") || input.eq_ci("generate region") {
+            return CommandMatches::new_canonical(Self::CreateRegion {
+                geography: String::new(),
+                name: None,
+            });
+        }
+
+        if let Some((geography, name)) = input
+            .strip_prefix_ci("generate region ")
+            .or_else(|| input.strip_prefix_ci("region "))
+            .map(split_named)
+            .or_else(|| {
+                input
+                    .strip_prefix_ci("generate ")
+                    .map(split_named)
+                    .filter(|(geography, _)| geography.trim_end().ends_with_ci(" region"))
+                    .map(|(geography, name)| {
+                        let geography = geography.trim_end();
+                        (&geography[..geography.len() - " region".len()], name)
+                    })
+            })
+        {
+            let geography = geography.trim();
+            let is_name_or_count = geography.starts_with_ci("named ")
+                || geography.starts_with_ci("called ")
+                || geography
+                    .strip_prefix(['x', 'X'])
+                    .is_some_and(|count| count.parse::<usize>().is_ok());
+
+            if !geography.is_empty() && !is_name_or_count && name != Some("") {
+                return CommandMatches::new_canonical(Self::CreateRegion {
+                    geography: geography.to_lowercase(),
+                    name: name.map(|s| s.to_string()),
+                });
+            }
+        }
+
+        let mut matches = CommandMatches::default();
+
+        if let Some((description, count)) = split_count(input) {
+            // `generate` is a synonym for `create`, so `generate tavern` makes an inn.
+            let (description, is_canonical) = match description
+                .strip_prefix_ci("create ")
+                .or_else(|| description.strip_prefix_ci("generate "))
+            {
+                Some(description) => (description, true),
+                None => (description, false),
+            };
+
+            if let Ok(thing) = parse_thing(description, app_meta).await {
+                if thing.unknown_words.is_empty() {
+                    let command = Self::CreateCount {
+                        thing: thing.thing,
+                        count,
+                    };
+
+                    if is_canonical {
+                        matches.push_canonical(command);
+                    } else {
+                        matches.push_fuzzy(command);
+                    }
+                }
+            }
+        } else if let Some(description) = input
+            .strip_prefix_ci("create ")
+            .or_else(|| input.strip_prefix_ci("generate "))
+        {
+            if let Ok(mut thing) = parse_thing(description, app_meta).await {
+                if thing.unknown_words.is_empty() {
+                    matches.push_canonical(Self::Create { thing });
+                } else {
+                    // Unknown word ranges are relative to the stripped input.
+                    let offset = input.len() - description.len();
+                    thing.unknown_words.iter_mut().for_each(|range| {
+                        *range = range.start + offset..range.end + offset;
+                    });
+
+                    matches.push_fuzzy(Self::Create { thing });
+                }
+            }
+        } else if let Ok(thing) = parse_thing(input, app_meta).await {
+            matches.push_fuzzy(Self::Create { thing });
+        }
+
+        if let Some(field) = input
+            .strip_prefix_ci("reroll ")
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+        {
+            matches.push_canonical(Self::Reroll {
+                field: field.to_lowercase(),
+            });
+        }
+
+        // The existing name may itself contain " to " (eg. "rename Tower to Stone to Keep"), so
+        // split on the last one.
+        if let Some((name, new_name)) = input.strip_prefix_ci("rename ").and_then(|s| {
+            s.rfind_ci(" to ")
+                .map(|i| (s[..i].trim(), s[i + " to ".len()..].trim()))
+        }) {
+            if !name.is_empty() && !new_name.is_empty() {
+                matches.push_canonical(Self::Rename {
+                    name: name.to_string(),
+                    new_name: new_name.to_string(),
+                });
+            }
+        }
+
+        if let Some(name) = input
+            .strip_prefix_ci("locations in ")
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+        {
+            matches.push_canonical(Self::LocationsIn {
+                name: name.to_string(),
+            });
+        }
+
+        if let Some(rest) = input.strip_prefix_ci("put ") {
+            if let Some(word) = quoted_words(rest)
+                .skip(1)
+                .filter(|word| word.as_str().eq_ci("in"))
+                .last()
+            {
+                let name = rest[..word.range().start].trim().trim_matches('"');
+                let parent = rest[word.range().end..].trim().trim_matches('"');
+
+                if !name.is_empty() && !parent.is_empty() {
+                    matches.push_canonical(Self::MoveInto {
+                        name: name.to_string(),
+                        parent: parent.to_string(),
+                    });
+                }
+            }
+        }
+
+        if let Some(rest) = input.strip_prefix_ci("relate ") {
+            let words: Vec<_> = quoted_words(rest).collect();
+
+            if let Some(as_index) = words.iter().rposition(|word| word.as_str().eq_ci("as")) {
+                if let Some(to_word) = words[..as_index]
+                    .iter()
+                    .skip(1)
+                    .rfind(|word| word.as_str().eq_ci("to"))
+                {
+                    let as_word = &words[as_index];
+                    let name = rest[..to_word.range().start].trim().trim_matches('"');
+                    let other = rest[to_word.range().end..as_word.range().start]
+                        .trim()
+                        .trim_matches('"');
+
+                    if let (false, false, Ok(kind)) = (
+                        name.is_empty(),
+                        other.is_empty(),
+                        rest[as_word.range().end..].trim().parse(),
+                    ) {
+                        matches.push_canonical(Self::Relate {
+                            name: name.to_string(),
+                            other: other.to_string(),
+                            kind,
+                        });
+                    }
+                }
+            }
+        }
+
+        if let Some(name) = input
+            .strip_prefix_ci("what is in ")
+            .or_else(|| input.strip_prefix_ci("what's in "))
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+        {
+            matches.push_canonical(Self::Tree {
+                name: name.to_string(),
+            });
+        }
+
+        if let Some(rest) = input.strip_prefix_ci("note ") {
+            let (replace, rest) = if let Some(rest) = rest.strip_prefix_ci("set ") {
+                (true, rest)
+            } else {
+                (false, rest.strip_prefix_ci("add ").unwrap_or(rest))
+            };
+
+            if let Some((name, note)) = rest.split_once(':') {
+                let name = name.trim();
+
+                if !name.is_empty() {
+                    matches.push_canonical(Self::Note {
+                        name: name.to_string(),
+                        note: note.trim().trim_matches('"').trim().to_string(),
+                        replace,
+                    });
+                }
+            }
+        }
+
+        if let Some(name) = input
+            .strip_prefix_ci("notes ")
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+        {
+            matches.push_canonical(Self::Notes {
+                name: name.to_string(),
+            });
+        }
+
+        if let Some((rest, remove)) = input
+            .strip_prefix_ci("tag ")
+            .map(|s| (s, false))
+            .or_else(|| input.strip_prefix_ci("untag ").map(|s| (s, true)))
+        {
+            if let Some((name, tags)) = split_tags(rest, app_meta).await {
+                matches.push_canonical(Self::Tag {
+                    name: name.to_string(),
+                    tags,
+                    remove,
+                });
+            }
+        }
+
+        if let Some(name) = input
+            .strip_prefix_ci("describe ")
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+        {
+            matches.push_canonical(Self::Describe {
+                name: name.to_string(),
+            });
+        }
+
+        if let Some(weights) = input
+            .strip_prefix_ci("set demographics ")
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+        {
+            matches.push_canonical(Self::SetDemographics {
+                weights: weights.to_lowercase(),
+            });
+        } else if input.eq_ci("show demographics") {
+            matches.push_canonical(Self::ShowDemographics);
+        } else if input.eq_ci("demographics") {
+            matches.push_fuzzy(Self::ShowDemographics);
+        }
+
+        if let Some(name) = ["who is at ", "who's at ", "who is in ", "who's in "]
+            .iter()
+            .find_map(|prefix| input.strip_prefix_ci(prefix))
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+        {
+            matches.push_canonical(Self::WhoIsAt {
+                name: name.to_string(),
+            });
+        }
+
+        if let Some(word) = quoted_words(input)
+            .skip(1)
+            .find(|word| word.as_str().eq_ci("is"))
+        {
+            let (name, description) = (
+                input[..word.range().start].trim(),
+                input[word.range().end..].trim(),
+            );
+
+            let resolved_name = app_meta.resolve_pronoun(name).unwrap_or_default();
+            let (diff, thing) =
+                if let Ok(thing) = app_meta.repository.get_by_name(&resolved_name).await {
+                    (
+                        match thing {
+                            Thing::Npc(_) => description
+                                .parse::<ParsedThing<Npc>>()
+                                .map(|npc| npc.into_thing()),
+                            Thing::Place(_) => description
+                                .parse::<ParsedThing<Place>>()
+                                .map(|npc| npc.into_thing()),
+                        }
+                        .or_else(|_| description.parse()),
+                        Some(thing),
+                    )
+                } else {
+                    // This will be an error when we try to run the command, but for now we'll pretend
+                    // it's valid so that we can provide a more coherent message.
+                    (description.parse(), None)
+                };
+
+            if let Ok(mut diff) = diff {
+                let name = thing
+                    .map(|t| t.name().to_string())
+                    .unwrap_or_else(|| name.to_string());
+
+                diff.unknown_words.iter_mut().for_each(|range| {
+                    *range = range.start + word.range().end + 1..range.end + word.range().end + 1
+                });
+
+                matches.push_fuzzy(Self::Edit { name, diff });
+            }
+        }
+
+        matches
+    }
+}
+
+#[async_trait(?Send)]
+impl Autocomplete for WorldCommand {
+    async fn autocomplete(input: &str, app_meta: &AppMeta) -> Vec<AutocompleteSuggestion> {
+        let mut suggestions = Vec::new();
+
+        let (mut place_suggestions, mut npc_suggestions) = join!(
+            Place::autocomplete(input, app_meta),
+            Npc::autocomplete(input, app_meta),
+        );
+
+        suggestions.append(&mut place_suggestions);
+        suggestions.append(&mut npc_suggestions);
+
+        if let Some(field_start) = input.strip_prefix_ci("reroll ") {
+            suggestions.extend(
+                REROLL_FIELDS
+                    .iter()
+                    .filter(|field| field.starts_with_ci(field_start.trim_start()))
+                    .map(|field| {
+                        AutocompleteSuggestion::new(
+                            format!("reroll {}", field),
+                            if REROLL_ALL_BUT_NAME.contains(field) {
+                                "regenerate all but the name".to_string()
+                            } else {
+                                format!("regenerate the {}", field)
+                            },
+                        )
+                    }),
+            );
+        } else if "reroll".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "reroll [field]",
+                "regenerate part of the last result",
+            ));
+        }
+
+        if let Some(name_start) = input.strip_prefix_ci("describe ") {
+            suggestions.extend(
+                app_meta
+                    .repository
+                    .get_by_name_start(name_start, Some(app_meta.autocomplete_limit))
+                    .await
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|thing| thing.npc())
+                    .map(|npc| {
+                        AutocompleteSuggestion::new(
+                            format!("describe {}", npc.name),
+                            format!("describe {}", npc.display_description()),
+                        )
+                    }),
+            );
+        } else if "describe".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "describe [character]",
+                "describe a character in prose",
+            ));
+        }
+
+        if let Some(name_start) = input.strip_prefix_ci("locations in ") {
+            suggestions.extend(
+                app_meta
+                    .repository
+                    .get_by_name_start(name_start, Some(app_meta.autocomplete_limit))
+                    .await
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|thing| thing.place())
+                    .filter(|place| {
+                        place
+                            .subtype
+                            .value()
+                            .is_some_and(|subtype| subtype.is_region())
+                    })
+                    .map(|place| {
+                        AutocompleteSuggestion::new(
+                            format!("locations in {}", place.name),
+                            format!("list the locations in {}", place.display_description()),
+                        )
+                    }),
+            );
+        } else if "locations in".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "locations in [region]",
+                "list the locations in a region",
+            ));
+        }
+
+        if "note add".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "note add [name]: [note]",
+                "add a note to a character or place",
+            ));
+        }
+        if "note set".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "note set [name]: [note]",
+                "replace the notes on a character or place",
+            ));
+        }
+
+        if let Some(name_start) = input.strip_prefix_ci("notes ") {
+            suggestions.extend(
+                app_meta
+                    .repository
+                    .get_by_name_start(name_start, Some(app_meta.autocomplete_limit))
+                    .await
+                    .unwrap_or_default()
+                    .iter()
+                    .filter(|thing| thing.notes().is_some())
+                    .map(|thing| {
+                        AutocompleteSuggestion::new(
+                            format!("notes {}", thing.name()),
+                            format!("show the notes on {}", thing.display_description()),
+                        )
+                    }),
+            );
+        } else if "notes".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "notes [name]",
+                "show the notes on a character or place",
+            ));
+        }
+
+        if "tag".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "tag [name] [tags]",
+                "tag a character or place",
+            ));
+        }
+        if "untag".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "untag [name] [tags]",
+                "remove tags from a character or place",
+            ));
+        }
+
+        if "put".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "put [name] in [place]",
+                "move something into a place",
+            ));
+        }
+
+        if "relate".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "relate [name] to [name] as [relationship]",
+                "record how two characters are related",
+            ));
+        }
+
+        if let Some(name_start) = input
+            .strip_prefix_ci("what is in ")
+            .or_else(|| input.strip_prefix_ci("what's in "))
+        {
+            suggestions.extend(
+                app_meta
+                    .repository
+                    .get_by_name_start(name_start, Some(app_meta.autocomplete_limit))
+                    .await
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|thing| thing.place())
+                    .map(|place| {
+                        AutocompleteSuggestion::new(
+                            format!("what is in {}", place.name),
+                            format!("show the places in {}", place.display_description()),
+                        )
+                    }),
+            );
+        } else if "what is in".starts_with_ci(input) || "what's in".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "what is in [place]",
+                "show the places inside a place",
+            ));
+        }
+
+        if "generate".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "generate [thing]",
+                "create anything, eg. generate tavern",
+            ));
+        }
+
+        if let Some(geography_start) = input
+            .strip_prefix_ci("generate region ")
+            .filter(|s| !s.contains(' '))
+        {
+            suggestions.extend(
+                PlaceType::variants()
+                    .filter(PlaceType::is_geography)
+                    .filter(|subtype| subtype.as_str().starts_with_ci(geography_start))
+                    .map(|subtype| {
+                        AutocompleteSuggestion::new(
+                            format!("generate region {}", subtype),
+                            format!("create a {} region", subtype),
+                        )
+                    }),
+            );
+        } else if "generate region".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "generate region [type]",
+                "create a region of a chosen type, eg. forest",
+            ));
+        }
+
+        if "generate town".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "generate town",
+                "create a town with places and people",
+            ));
+        }
+
+        if let Some(name_start) = input.strip_prefix_ci("rename ") {
+            if name_start.find_ci(" to ").is_none() {
+                suggestions.extend(
+                    app_meta
+                        .repository
+                        .get_by_name_start(name_start, Some(app_meta.autocomplete_limit))
+                        .await
+                        .unwrap_or_default()
+                        .iter()
+                        .map(|thing| {
+                            AutocompleteSuggestion::new(
+                                format!("rename {} to [new name]", thing.name()),
+                                format!("rename {}", thing.display_description()),
+                            )
+                        }),
+                );
             }
+        } else if "rename".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "rename [name] to [new name]",
+                "change the name of something",
+            ));
         }
 
-        matches
-    }
-}
-
-#[async_trait(?Send)]
-impl Autocomplete for WorldCommand {
-    async fn autocomplete(input: &str, app_meta: &AppMeta) -> Vec<AutocompleteSuggestion> {
-        let mut suggestions = Vec::new();
+        if "set demographics".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "set demographics [species] [weight]...",
+                "eg. human 50 elf 50",
+            ));
+        }
 
-        let (mut place_suggestions, mut npc_suggestions) = join!(
-            Place::autocomplete(input, app_meta),
-            Npc::autocomplete(input, app_meta),
-        );
+        if "who is at".starts_with_ci(input) || "who's at".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "who is at [place]",
+                "list characters at a place",
+            ));
+        }
 
-        suggestions.append(&mut place_suggestions);
-        suggestions.append(&mut npc_suggestions);
+        if "show demographics".starts_with_ci(input) {
+            suggestions.push(AutocompleteSuggestion::new(
+                "show demographics",
+                "current species weights",
+            ));
+        }
 
         let mut input_words = quoted_words(input).skip(1);
 
@@ -417,9 +1888,59 @@ impl fmt::Display for WorldCommand {
             Self::CreateMultiple { thing } => {
                 write!(f, "create  multiple {}", thing.display_description())
             }
+            Self::CreateCount { thing, count } => {
+                write!(f, "create {} x{}", thing.display_description(), count)
+            }
+            Self::CreateRegion {
+                geography,
+                name: None,
+            } if geography.is_empty() => write!(f, "generate region"),
+            Self::CreateRegion {
+                geography,
+                name: None,
+            } => write!(f, "generate region {}", geography),
+            Self::CreateRegion {
+                geography,
+                name: Some(name),
+            } => write!(f, "generate region {} named {}", geography, name),
+            Self::CreateTown { name: None } => write!(f, "generate town"),
+            Self::CreateTown { name: Some(name) } => write!(f, "generate town named {}", name),
             Self::Edit { name, diff } => {
                 write!(f, "{} is {}", name, diff.thing.display_description())
             }
+            Self::Describe { name } => write!(f, "describe {}", name),
+            Self::MoveInto { name, parent } => write!(f, "put {} in {}", name, parent),
+            Self::LocationsIn { name } => write!(f, "locations in {}", name),
+            Self::Note {
+                name,
+                note,
+                replace: false,
+            } => write!(f, "note add {}: {}", name, note),
+            Self::Note {
+                name,
+                note,
+                replace: true,
+            } => write!(f, "note set {}: {}", name, note),
+            Self::Notes { name } => write!(f, "notes {}", name),
+            Self::Tag {
+                name,
+                tags,
+                remove: false,
+            } => write!(f, "tag {} {}", name, tags.join(" ")),
+            Self::Tag {
+                name,
+                tags,
+                remove: true,
+            } => write!(f, "untag {} {}", name, tags.join(" ")),
+            Self::Tree { name } => write!(f, "what is in {}", name),
+            Self::Rename { name, new_name } => write!(f, "rename {} to {}", name, new_name),
+            Self::Relate { name, other, kind } => {
+                write!(f, "relate {} to {} as {}", name, other, kind)
+            }
+            Self::Reroll { field } => write!(f, "reroll {}", field),
+            Self::SetDemographics { weights } => write!(f, "set demographics {}", weights),
+            Self::ShowDemographics => write!(f, "show demographics"),
+            Self::WhoIsAt { name } => write!(f, "who is at {}", name),
         }
     }
 }
@@ -450,6 +1971,472 @@ impl<T: Into<Thing>> From<ParsedThing<T>> for Thing {
     }
 }
 
+/// Parse a description, optionally followed by "in [place]" to set its location, eg.
+/// "elf in Stonehold". Only places saved to the journal can be used as a location.
+async fn parse_thing(input: &str, app_meta: &AppMeta) -> Result<ParsedThing<Thing>, ()> {
+    if let Some(word) = quoted_words(input)
+        .skip(1)
+        .filter(|word| word.as_str().eq_ci("in"))
+        .last()
+    {
+        let place_name = input[word.range().end..].trim().trim_matches('"');
+
+        if let Ok(Thing::Place(Place {
+            uuid: Some(uuid), ..
+        })) = app_meta.repository.get_by_name(place_name).await
+        {
+            let mut parsed: ParsedThing<Thing> = input[..word.range().start].parse()?;
+
+            match &mut parsed.thing {
+                Thing::Npc(npc) => npc.location_uuid = Field::new(uuid),
+                Thing::Place(place) => place.location_uuid = Field::new(uuid),
+            }
+
+            return Ok(parsed);
+        }
+    }
+
+    input.parse()
+}
+
+/// Things generated within a place with its own population (or whose parent has one) use that
+/// population's demographics instead of the global default.
+async fn local_demographics(thing: &Thing, app_meta: &AppMeta) -> Demographics {
+    let location = match app_meta.repository.load_relations(thing).await {
+        Ok(ThingRelations::Npc(relations)) => relations.location,
+        Ok(ThingRelations::Place(relations)) => relations.location,
+        _ => None,
+    };
+
+    location
+        .and_then(|(parent, grandparent)| {
+            Option::from(parent.demographics)
+                .or_else(|| grandparent.and_then(|place| place.demographics.into()))
+        })
+        .unwrap_or_else(|| app_meta.demographics.clone())
+        .with_names(app_meta.names)
+}
+
+/// Split a trailing name from the input, eg. `"forest named Mirkwood"` =>
+/// `("forest", Some("Mirkwood"))`.
+fn split_named(input: &str) -> (&str, Option<&str>) {
+    match input.find_ci(" named ") {
+        Some(i) => (&input[..i], Some(input[i + " named ".len()..].trim())),
+        None => (input, None),
+    }
+}
+
+/// Split `[name] [tag] [tag]...` into the name and its normalized tags. Names can contain
+/// spaces, so the longest run of leading words that names an existing thing is used,
+/// falling back to the first word if nothing matches. Returns `None` if there are no tags.
+async fn split_tags<'a>(input: &'a str, app_meta: &AppMeta) -> Option<(&'a str, Vec<String>)> {
+    let word_ends: Vec<usize> = quoted_words(input).map(|word| word.range().end).collect();
+
+    if word_ends.len() < 2 {
+        return None;
+    }
+
+    let mut name_end = word_ends[0];
+    for &end in word_ends.iter().rev() {
+        let name = input[..end].trim().trim_matches('"');
+
+        if app_meta.repository.get_by_name(name).await.is_ok() {
+            name_end = end;
+            break;
+        }
+    }
+
+    let tags = parse_tags(&input[name_end..]);
+
+    Some((input[..name_end].trim().trim_matches('"'), tags)).filter(|(_, tags)| !tags.is_empty())
+}
+
+/// Split a trailing count from the input, eg. `"elf x5"` => `("elf", 5)`.
+fn split_count(input: &str) -> Option<(&str, usize)> {
+    let (description, count) = input.trim_end().rsplit_once(' ')?;
+    let count = count.strip_prefix(&['x', 'X'][..])?.parse().ok()?;
+    Some((description.trim_end(), count))
+}
+
+/// Generate a thing matching the description and add it to the recent results, or to the journal
+/// if it was given a name, rerolling until the name is unique. Nothing is displayed, so the
+/// generated thing can be inspected directly.
+///
+/// With `number_duplicates`, the thing always goes to the recent results, and if rerolling
+/// doesn't produce a unique name, the duplicates are numbered instead: "Bob (2)".
+async fn create_thing(
+    diff: &Thing,
+    number_duplicates: bool,
+    app_meta: &mut AppMeta,
+) -> Result<Thing, String> {
+    let demographics = local_demographics(diff, app_meta).await;
+    let attempts = if number_duplicates {
+        10 + MAX_CREATE_COUNT
+    } else {
+        10
+    };
+
+    for attempt in 0..attempts {
+        let mut thing = diff.clone();
+        thing.regenerate(&mut app_meta.rng, &demographics);
+
+        if attempt >= 10 {
+            if let Some(name) = match &mut thing {
+                Thing::Npc(npc) => npc.name.value_mut(),
+                Thing::Place(place) => place.name.value_mut(),
+            } {
+                name.push_str(&format!(" ({})", attempt - 8));
+            }
+        }
+
+        let change = if !number_duplicates && matches!(thing.name(), Field::Locked(Some(_))) {
+            Change::CreateAndSave { thing }
+        } else {
+            Change::Create { thing }
+        };
+
+        match app_meta.repository.modify(change).await {
+            Ok(Some(thing)) => return Ok(thing),
+            Err((Change::Create { thing }, RepositoryError::NameAlreadyExists))
+            | Err((Change::CreateAndSave { thing }, RepositoryError::NameAlreadyExists)) => {
+                if thing.name().is_locked() && !number_duplicates {
+                    if let Ok(other_thing) = app_meta
+                        .repository
+                        .get_by_name(thing.name().value().unwrap())
+                        .await
+                    {
+                        return Err(format!(
+                            "That name is already in use by {}.",
+                            other_thing.display_summary(),
+                        ));
+                    } else {
+                        return Err("That name is already in use.".to_string());
+                    }
+                }
+            }
+            Err((Change::Create { thing }, RepositoryError::MissingName)) => return Err(format!("There is no name generator implemented for that type. You must specify your own name using `{} named [name]`.", thing.display_description())),
+            Ok(None) | Err(_) => return Err("An error occurred.".to_string()),
+        }
+    }
+
+    Err(format!(
+        "Couldn't create a unique {} name.",
+        diff.display_description(),
+    ))
+}
+
+/// Generate `count` things matching the description and add them to recent history, returning
+/// their numbered summaries. Only the first ten get a number shortcut.
+async fn create_list(
+    thing: &Thing,
+    count: usize,
+    app_meta: &mut AppMeta,
+) -> Result<String, String> {
+    let mut output = String::new();
+
+    for i in 1..=count {
+        let thing = match create_thing(thing, true, app_meta).await {
+            Ok(thing) => thing,
+            Err(e) if i == 1 => return Err(e),
+            Err(_) => {
+                output.push_str("\n\n! An error occurred generating additional results.");
+                break;
+            }
+        };
+
+        let separator = if i == 1 { "\n\n" } else { "\\\n" };
+        let name = thing.name().to_string();
+        app_meta.push_generated(&name);
+
+        if i <= 10 {
+            output.push_str(&format!(
+                "{}~{}~ {}",
+                separator,
+                i % 10,
+                thing.display_summary(),
+            ));
+            app_meta.command_aliases.insert(CommandAlias::literal(
+                (i % 10).to_string(),
+                format!("load {}", name),
+                StorageCommand::Load { name: name.clone() }.into(),
+            ));
+        } else {
+            output.push_str(&format!("{}{}", separator, thing.display_summary()));
+        }
+    }
+
+    Ok(output)
+}
+
+/// The places located directly inside a place, in reverse alphabetical order so that they come
+/// off a stack alphabetically.
+async fn child_places(uuid: &crate::Uuid, app_meta: &AppMeta) -> Result<Vec<Place>, String> {
+    let mut places: Vec<Place> = app_meta
+        .repository
+        .get_by_location(uuid)
+        .await
+        .map_err(|_| "Couldn't access the journal.".to_string())?
+        .into_iter()
+        .filter_map(|thing| thing.into_place().ok())
+        .collect();
+
+    places.sort_by(|a, b| b.name.to_string().cmp_ci(a.name.to_string()));
+
+    Ok(places)
+}
+
+/// Generate a thing and save it directly to the journal, retrying if the generated name is
+/// already in use.
+/// The buildings in a generated town. Only inns know how to name themselves, so the others are
+/// named after the town.
+fn town_buildings(town_name: &str) -> [(&'static str, Option<String>); 3] {
+    [
+        ("inn", None),
+        (
+            "general-store",
+            Some(format!("{} General Store", town_name)),
+        ),
+        ("temple", Some(format!("Temple of {}", town_name))),
+    ]
+}
+
+/// The existing thing, if any, that already has the town's name or one that its buildings would
+/// be given.
+async fn town_name_in_use(town_name: &str, app_meta: &AppMeta) -> Option<Thing> {
+    for name in iter::once(town_name.to_string()).chain(
+        town_buildings(town_name)
+            .into_iter()
+            .filter_map(|(_, name)| name),
+    ) {
+        if let Ok(thing) = app_meta.repository.get_by_name(&name).await {
+            return Some(thing);
+        }
+    }
+
+    None
+}
+
+async fn create_and_save(diff: Thing, app_meta: &mut AppMeta) -> Result<Thing, String> {
+    let demographics = local_demographics(&diff, app_meta).await;
+
+    for _ in 0..10 {
+        let mut thing = diff.clone();
+        thing.regenerate(&mut app_meta.rng, &demographics);
+
+        match app_meta
+            .repository
+            .modify(Change::CreateAndSave { thing })
+            .await
+        {
+            Ok(Some(thing)) => return Ok(thing),
+            Err((Change::CreateAndSave { thing }, RepositoryError::NameAlreadyExists))
+                if thing.name().is_locked() =>
+            {
+                return Err(
+                    match app_meta
+                        .repository
+                        .get_by_name(&thing.name().to_string())
+                        .await
+                    {
+                        Ok(other_thing) => format!(
+                            "That name is already in use by {}.",
+                            other_thing.display_summary(),
+                        ),
+                        Err(_) => "That name is already in use.".to_string(),
+                    },
+                );
+            }
+            Err((_, RepositoryError::NameAlreadyExists)) => {}
+            Ok(None) | Err(_) => return Err("An error occurred.".to_string()),
+        }
+    }
+
+    Err(format!(
+        "Couldn't create a unique {} name.",
+        diff.display_description(),
+    ))
+}
+
+/// The details of a thing that was just edited, followed by a notice of what was done, eg. "Olive
+/// was renamed to Olivia". If editing it also saved it to the journal, `autosaved` is added to the
+/// notice, eg. " and automatically saved to your `journal`".
+async fn display_edited(thing: &Thing, done: &str, autosaved: &str, app_meta: &AppMeta) -> String {
+    format!(
+        "{}\n\n_{}{}. Use `undo` to reverse this._",
+        thing
+            .display_details(
+                app_meta
+                    .repository
+                    .load_relations(thing)
+                    .await
+                    .unwrap_or_default()
+            )
+            .with_units(app_meta.units),
+        done,
+        if matches!(
+            app_meta.repository.undo_history().next(),
+            Some(Change::EditAndUnsave { .. }),
+        ) {
+            autosaved
+        } else {
+            ""
+        },
+    )
+}
+
+/// Regenerate a single field of a thing, leaving the rest untouched. The result is a diff
+/// containing only the rerolled field, or `None` if the thing has no such field.
+fn reroll_field(
+    thing: &Thing,
+    field: &str,
+    rng: &mut impl Rng,
+    demographics: &Demographics,
+) -> Option<Thing> {
+    let mut rerolled = thing.clone();
+    rerolled.lock_all();
+
+    match (&mut rerolled, field) {
+        (Thing::Npc(npc), "name") => npc.name.unlock(),
+        (Thing::Npc(npc), "gender") => npc.gender.unlock(),
+        // Size is generated from the age, so it goes with it.
+        (Thing::Npc(npc), "age") => {
+            npc.age.unlock();
+            npc.age_years.unlock();
+            npc.size.unlock();
+        }
+        // Ethnicity, name, size and age in years are all generated to suit the species, so a new
+        // species needs new ones too. The age category is kept.
+        (Thing::Npc(npc), "species") => {
+            npc.species.unlock();
+            npc.ethnicity.unlock();
+            npc.name.unlock();
+            npc.age_years.unlock();
+            npc.size.unlock();
+            npc.draconic_ancestry.unlock();
+        }
+        (Thing::Npc(npc), "ethnicity") => npc.ethnicity.unlock(),
+        (Thing::Npc(npc), "occupation") => npc.occupation.unlock(),
+        (Thing::Npc(npc), "personality") => npc.personality.unlock(),
+        (Thing::Npc(npc), "alignment") => npc.alignment.unlock(),
+        (Thing::Npc(npc), "ancestry") => npc.draconic_ancestry.unlock(),
+        (Thing::Npc(npc), "quirk") => npc.quirk.unlock(),
+        (Thing::Npc(npc), field) if REROLL_ALL_BUT_NAME.contains(&field) => {
+            npc.gender.unlock();
+            npc.age.unlock();
+            npc.age_years.unlock();
+            npc.size.unlock();
+            npc.species.unlock();
+            npc.ethnicity.unlock();
+            npc.occupation.unlock();
+            npc.personality.unlock();
+            npc.alignment.unlock();
+            npc.draconic_ancestry.unlock();
+            npc.quirk.unlock();
+        }
+        (Thing::Place(place), "name") => place.name.unlock(),
+        _ => return None,
+    }
+
+    rerolled.regenerate(rng, demographics);
+
+    // Only the fields that were unlocked above have changed, so everything else is dropped from
+    // the diff and the rerolled fields are locked in.
+    fn rerolled_only<T>(field: Field<T>) -> Field<T> {
+        match field {
+            Field::Unlocked(value) => Field::Locked(value),
+            Field::Locked(_) => Field::default(),
+        }
+    }
+
+    Some(match rerolled {
+        Thing::Npc(npc) => Npc {
+            uuid: None,
+            name: rerolled_only(npc.name),
+            gender: rerolled_only(npc.gender),
+            age: rerolled_only(npc.age),
+            age_years: rerolled_only(npc.age_years),
+            age_range: None,
+            size: rerolled_only(npc.size),
+            species: rerolled_only(npc.species),
+            ethnicity: rerolled_only(npc.ethnicity),
+            location_uuid: rerolled_only(npc.location_uuid),
+            occupation: rerolled_only(npc.occupation),
+            personality: rerolled_only(npc.personality),
+            alignment: rerolled_only(npc.alignment),
+            draconic_ancestry: rerolled_only(npc.draconic_ancestry),
+            quirk: rerolled_only(npc.quirk),
+            relationships: Field::default(),
+            notes: Field::default(),
+            tags: Field::default(),
+        }
+        .into(),
+        Thing::Place(place) => Place {
+            uuid: None,
+            location_uuid: rerolled_only(place.location_uuid),
+            subtype: rerolled_only(place.subtype),
+            name: rerolled_only(place.name),
+            description: rerolled_only(place.description),
+            demographics: rerolled_only(place.demographics),
+            notes: Field::default(),
+            tags: Field::default(),
+        }
+        .into(),
+    })
+}
+
+/// A word that the parser didn't recognize directly preceding "npc" (eg. "potato npc") was
+/// probably intended to be a species, so generating a random species would be misleading.
+fn find_unknown_species<'a>(
+    thing: &Thing,
+    input: &'a str,
+    unknown_words: &[Range<usize>],
+) -> Option<&'a str> {
+    if let Thing::Npc(npc) = thing {
+        if npc.species.is_locked() || npc.ethnicity.is_locked() {
+            return None;
+        }
+
+        unknown_words
+            .iter()
+            .find(|range| {
+                matches!(
+                    quoted_words(&input[range.end..]).next(),
+                    Some(word) if word.as_str().in_ci(&["character", "npc", "person"]),
+                )
+            })
+            .map(|range| &input[range.clone()])
+    } else {
+        None
+    }
+}
+
+/// A warning if a character was asked for an age range reaching beyond their species' lifespan,
+/// eg. "a human aged 90-120", or alongside an age that overrides it, eg. "elderly human age 20-30".
+fn age_range_notice(diff: &Thing, thing: &Thing) -> Option<String> {
+    if let (Thing::Npc(diff), Thing::Npc(npc)) = (diff, thing) {
+        let (min, max) = diff.age_range?;
+
+        if diff.age.is_some() || diff.age_years.is_some() {
+            return Some(format!(
+                "\n\n! The age range {}-{} was ignored because an age was also given.",
+                min, max,
+            ));
+        }
+
+        let species = npc.species.value()?;
+
+        if max > species.max_age_years() {
+            return Some(format!(
+                "\n\n! A {} rarely lives past {} years, so that age range is unusual.",
+                species,
+                species.max_age_years(),
+            ));
+        }
+    }
+
+    None
+}
+
 fn append_unknown_words_notice(
     mut output: String,
     input: &str,
@@ -499,10 +2486,12 @@ fn append_unknown_words_notice(
 mod test {
     use super::*;
     use crate::app::assert_autocomplete;
-    use crate::storage::NullDataStore;
+    use crate::storage::{MemoryDataStore, NullDataStore};
     use crate::world::npc::{Age, Gender, Species};
     use crate::world::place::PlaceType;
+    use crate::world::Generate;
     use crate::Event;
+    use rand::prelude::*;
     use tokio_test::block_on;
 
     #[test]
@@ -532,6 +2521,64 @@ mod test {
             block_on(WorldCommand::parse_input("potato", &app_meta)),
         );
 
+        assert_eq!(
+            CommandMatches::new_fuzzy(WorldCommand::CreateCount {
+                thing: Npc {
+                    species: Species::Elf.into(),
+                    ..Default::default()
+                }
+                .into(),
+                count: 5,
+            }),
+            block_on(WorldCommand::parse_input("elf x5", &app_meta)),
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(WorldCommand::CreateCount {
+                thing: Npc::default().into(),
+                count: 3,
+            }),
+            block_on(WorldCommand::parse_input("create npc X3", &app_meta)),
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(WorldCommand::Reroll {
+                field: "name".to_string(),
+            }),
+            block_on(WorldCommand::parse_input("REROLL Name", &app_meta)),
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(WorldCommand::WhoIsAt {
+                name: "the Prancing Pony".to_string(),
+            }),
+            block_on(WorldCommand::parse_input(
+                "who's at the Prancing Pony",
+                &app_meta
+            )),
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(WorldCommand::Rename {
+                name: "Potato Johnson".to_string(),
+                new_name: "Potato Jackson".to_string(),
+            }),
+            block_on(WorldCommand::parse_input(
+                "Rename Potato Johnson TO Potato Jackson",
+                &app_meta
+            )),
+        );
+
+        assert_eq!(
+            CommandMatches::new_canonical(WorldCommand::Describe {
+                name: "Potato Johnson".to_string(),
+            }),
+            block_on(WorldCommand::parse_input(
+                "DESCRIBE Potato Johnson ",
+                &app_meta
+            )),
+        );
+
         {
             block_on(
                 app_meta.repository.modify(Change::Create {
@@ -560,6 +2607,51 @@ mod test {
                 }),
                 block_on(WorldCommand::parse_input("Spot is a good boy", &app_meta)),
             );
+
+            assert_eq!(
+                CommandMatches::new_canonical(WorldCommand::Tag {
+                    name: "Spot".into(),
+                    tags: vec!["good".to_string(), "boy".to_string()],
+                    remove: false,
+                }),
+                block_on(WorldCommand::parse_input(
+                    "tag Spot Good, #boy good",
+                    &app_meta
+                )),
+            );
+        }
+
+        {
+            block_on(
+                app_meta.repository.modify(Change::Create {
+                    thing: Place {
+                        name: "The Prancing Pony".into(),
+                        ..Default::default()
+                    }
+                    .into(),
+                }),
+            )
+            .unwrap();
+
+            assert_eq!(
+                CommandMatches::new_canonical(WorldCommand::Tag {
+                    name: "The Prancing Pony".into(),
+                    tags: vec!["inn".to_string()],
+                    remove: true,
+                }),
+                block_on(WorldCommand::parse_input(
+                    "untag The Prancing Pony INN",
+                    &app_meta
+                )),
+            );
+
+            assert_eq!(
+                CommandMatches::default(),
+                block_on(WorldCommand::parse_input(
+                    "tag The Prancing Pony",
+                    &app_meta
+                )),
+            );
         }
     }
 
@@ -612,6 +2704,7 @@ mod test {
         assert_autocomplete(
             &[
                 ("baby", "create infant"),
+                ("badlands", "create barrens"),
                 ("bakery", "create bakery"),
                 ("bank", "create bank"),
                 ("bar", "create bar"),
@@ -622,11 +2715,13 @@ mod test {
                 ("bathhouse", "create bathhouse"),
                 ("beach", "create beach"),
                 ("blacksmith", "create blacksmith"),
+                ("bog", "create swamp"),
                 ("boy", "create child, he/him"),
                 ("brewery", "create brewery"),
                 ("bridge", "create bridge"),
                 ("building", "create building"),
                 ("business", "create business"),
+                ("butte", "create mesa"),
             ][..],
             block_on(WorldCommand::autocomplete("b", &app_meta)),
         );
@@ -664,6 +2759,47 @@ mod test {
         );
     }
 
+    #[test]
+    fn reroll_field_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let demographics = Demographics::default();
+        let thing: Thing = Npc::generate(&mut rng, &demographics).into();
+
+        let diff = reroll_field(&thing, "age", &mut rng, &demographics).unwrap();
+        let npc = diff.npc().unwrap();
+        assert!(npc.age.is_locked());
+        assert!(npc.age_years.is_locked());
+        assert!(npc.age_years.is_some());
+        assert!(npc.size.is_locked());
+        assert_eq!(npc.name, Field::default());
+        assert_eq!(npc.species, Field::default());
+
+        let diff = reroll_field(&thing, "species", &mut rng, &demographics).unwrap();
+        let npc = diff.npc().unwrap();
+        assert!(npc.species.is_locked());
+        assert!(npc.ethnicity.is_locked());
+        assert!(npc.name.is_locked());
+        assert!(npc.age_years.is_locked());
+        assert!(npc.size.is_locked());
+        assert_eq!(npc.age, Field::default());
+        assert_eq!(npc.gender, Field::default());
+
+        let diff = reroll_field(&thing, "name", &mut rng, &demographics).unwrap();
+        let npc = diff.npc().unwrap();
+        assert!(npc.name.is_locked());
+        assert_ne!(thing.name(), &npc.name);
+        assert_eq!(npc.gender, Field::default());
+
+        assert_eq!(
+            None,
+            reroll_field(&thing, "potato", &mut rng, &demographics)
+        );
+        assert_eq!(
+            None,
+            reroll_field(&Place::default().into(), "age", &mut rng, &demographics),
+        );
+    }
+
     #[test]
     fn display_test() {
         let app_meta = app_meta();
@@ -678,6 +2814,72 @@ mod test {
                 species: Some(Species::Elf).into(),
                 ..Default::default()
             }),
+            WorldCommand::Reroll {
+                field: "age".to_string(),
+            },
+            WorldCommand::CreateCount {
+                thing: Npc::default().into(),
+                count: 5,
+            },
+            WorldCommand::SetDemographics {
+                weights: "human 50 elf 50".to_string(),
+            },
+            WorldCommand::ShowDemographics,
+            WorldCommand::CreateTown { name: None },
+            WorldCommand::CreateRegion {
+                geography: String::new(),
+                name: None,
+            },
+            WorldCommand::CreateRegion {
+                geography: "forest".to_string(),
+                name: None,
+            },
+            WorldCommand::CreateRegion {
+                geography: "mountains".to_string(),
+                name: Some("MISTY MOUNTAINS".to_string()),
+            },
+            WorldCommand::LocationsIn {
+                name: "WESTEROS".to_string(),
+            },
+            WorldCommand::MoveInto {
+                name: "WESTEROS".to_string(),
+                parent: "ESSOS".to_string(),
+            },
+            WorldCommand::Tree {
+                name: "WESTEROS".to_string(),
+            },
+            WorldCommand::Relate {
+                name: "POTATO JOHNSON".to_string(),
+                other: "POTATO JACKSON".to_string(),
+                kind: RelationshipKind::Sibling,
+            },
+            WorldCommand::Rename {
+                name: "POTATO JOHNSON".to_string(),
+                new_name: "POTATO JACKSON".to_string(),
+            },
+            WorldCommand::Note {
+                name: "POTATO JOHNSON".to_string(),
+                note: "OWES THE PARTY 50GP".to_string(),
+                replace: false,
+            },
+            WorldCommand::Note {
+                name: "POTATO JOHNSON".to_string(),
+                note: "".to_string(),
+                replace: true,
+            },
+            WorldCommand::Notes {
+                name: "POTATO JOHNSON".to_string(),
+            },
+            WorldCommand::Tag {
+                name: "GUNDREN".to_string(),
+                tags: vec!["faction:dwarves".to_string(), "quest-giver".to_string()],
+                remove: false,
+            },
+            WorldCommand::Tag {
+                name: "GUNDREN".to_string(),
+                tags: vec!["quest-giver".to_string()],
+                remove: true,
+            },
         ]
         .into_iter()
         .for_each(|command| {
@@ -703,6 +2905,73 @@ mod test {
         });
     }
 
+    #[test]
+    fn create_thing_test() {
+        let mut app_meta = app_meta();
+        let diff: Thing = Npc {
+            species: Species::Dwarf.into(),
+            age: Age::Elderly.into(),
+            ..Default::default()
+        }
+        .into();
+
+        let thing = block_on(create_thing(&diff, false, &mut app_meta)).unwrap();
+        let npc = thing.npc().unwrap();
+        assert_eq!(Some(&Species::Dwarf), npc.species.value());
+        assert_eq!(Some(&Age::Elderly), npc.age.value());
+        assert!(npc.name.is_unlocked());
+        assert!(npc.age_years.value().is_some());
+
+        assert_eq!(
+            Some(&thing),
+            app_meta
+                .repository
+                .recent()
+                .find(|t| t.name() == thing.name()),
+        );
+    }
+
+    #[test]
+    fn create_thing_test_name_in_use() {
+        let mut app_meta = AppMeta::new(MemoryDataStore::default(), &event_dispatcher);
+        let diff: Thing = Npc {
+            name: "Potato Johnson".into(),
+            ..Default::default()
+        }
+        .into();
+
+        let thing = block_on(create_thing(&diff, false, &mut app_meta)).unwrap();
+        assert_eq!(
+            Some("Potato Johnson"),
+            thing.name().value().map(String::as_str)
+        );
+        assert!(thing.uuid().is_some());
+
+        let error = block_on(create_thing(&diff, false, &mut app_meta)).unwrap_err();
+        assert!(
+            error.starts_with("That name is already in use by "),
+            "{}",
+            error,
+        );
+    }
+
+    #[test]
+    fn create_thing_test_number_duplicates() {
+        let mut app_meta = AppMeta::new(MemoryDataStore::default(), &event_dispatcher);
+        let diff: Thing = Npc {
+            name: "Potato Johnson".into(),
+            ..Default::default()
+        }
+        .into();
+
+        let thing = block_on(create_thing(&diff, true, &mut app_meta)).unwrap();
+        assert_eq!("Potato Johnson", thing.name().to_string());
+        assert!(thing.uuid().is_none());
+
+        let thing = block_on(create_thing(&diff, true, &mut app_meta)).unwrap();
+        assert_eq!("Potato Johnson (2)", thing.name().to_string());
+    }
+
     fn create(thing: impl Into<Thing>) -> WorldCommand {
         WorldCommand::Create {
             thing: ParsedThing {
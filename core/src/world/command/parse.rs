@@ -1,8 +1,14 @@
 use crate::utils::{capitalize, quoted_words, CaseInsensitiveStr};
 use crate::world::command::ParsedThing;
+use crate::world::npc::{Alignment, DraconicAncestry, Ethnicity, Species};
+use crate::world::place::PlaceType;
 use crate::world::{Field, Npc, Place};
 use std::str::FromStr;
 
+/// How strongly a species named in a place description ("a dwarvish hold") dominates the local
+/// population.
+const PLACE_DEMOGRAPHICS_SHIFT: f64 = 0.8;
+
 fn split_name(input: &str) -> Option<(&str, &str)> {
     let (named, comma) = quoted_words(input).fold((None, None), |(named, comma), word| {
         if named.is_none() && word.as_str().in_ci(&["named", "called"]) {
@@ -46,6 +52,13 @@ fn split_name(input: &str) -> Option<(&str, &str)> {
     }
 }
 
+/// A range of ages like "20-40", with the bounds swapped if they're given the wrong way around.
+fn parse_age_range(input: &str) -> Option<(u16, u16)> {
+    let (start, end) = input.split_once('-')?;
+    let (start, end): (u16, u16) = (start.parse().ok()?, end.parse().ok()?);
+    Some((start.min(end), start.max(end)))
+}
+
 impl FromStr for ParsedThing<Place> {
     type Err = ();
 
@@ -61,14 +74,38 @@ impl FromStr for ParsedThing<Place> {
             input
         };
 
-        for word in quoted_words(description) {
+        let mut words = quoted_words(description).peekable();
+
+        while let Some(word) = words.next() {
             let word_str = &word.as_str();
             word_count += 1;
 
+            // Species only describe a place's population when directly followed by the type of
+            // place, eg. "a dwarvish kingdom". A bare "elf" describes a person.
+            let describes_place = words
+                .peek()
+                .is_some_and(|next_word| next_word.as_str().parse::<PlaceType>().is_ok());
+
             if word_str.in_ci(&["a", "an"]) {
                 word_count -= 1;
             } else if let Ok(place_type) = word_str.parse() {
                 place.subtype = Field::new(place_type);
+            } else if let Some(species) =
+                word_str.parse::<Species>().ok().filter(|_| describes_place)
+            {
+                // "a dwarf kingdom"
+                let demographics = place.demographics.value().cloned().unwrap_or_default();
+                place.demographics =
+                    Field::new(demographics.shift_species(&species, PLACE_DEMOGRAPHICS_SHIFT));
+            } else if let Some(ethnicity) = word_str
+                .parse::<Ethnicity>()
+                .ok()
+                .filter(|_| describes_place)
+            {
+                // "an elvish realm"
+                let demographics = place.demographics.value().cloned().unwrap_or_default();
+                place.demographics =
+                    Field::new(demographics.shift_ethnicity(&ethnicity, PLACE_DEMOGRAPHICS_SHIFT));
             } else {
                 unknown_words.push(word.range().to_owned());
             }
@@ -101,12 +138,56 @@ impl FromStr for ParsedThing<Npc> {
             input
         };
 
-        for word in quoted_words(description) {
+        let mut words = quoted_words(description).peekable();
+
+        while let Some(word) = words.next() {
             let word_str = &word.as_str();
             word_count += 1;
 
             if word_str.in_ci(&["a", "an"]) {
                 word_count -= 1;
+            } else if let Some(age_years) = word_str
+                .in_ci(&["age", "aged"])
+                .then(|| words.peek().and_then(|word| word.as_str().parse().ok()))
+                .flatten()
+            {
+                // "age 37", "aged 37"
+                words.next();
+                npc.age_years = Field::new(age_years);
+            } else if let Some(age_range) = word_str
+                .in_ci(&["age", "aged"])
+                .then(|| words.peek().and_then(|word| parse_age_range(word.as_str())))
+                .flatten()
+            {
+                // "age 20-40", "aged 40-20"
+                words.next();
+                npc.age_range = Some(age_range);
+            } else if let Some(alignment) = words.peek().and_then(|next_word| {
+                format!("{} {}", word_str, next_word.as_str())
+                    .parse::<Alignment>()
+                    .ok()
+            }) {
+                // "lawful good", "chaotic neutral"
+                words.next();
+                npc.alignment = Field::new(alignment);
+            } else if let Some(species) = words.peek().and_then(|next_word| {
+                format!("{} {}", word_str, next_word.as_str())
+                    .parse::<Species>()
+                    .ok()
+            }) {
+                // "half elf", "half orc"
+                words.next();
+                npc.species = Field::new(species);
+            } else if let Some(ancestry) = word_str.parse::<DraconicAncestry>().ok().filter(|_| {
+                words
+                    .peek()
+                    .and_then(|next_word| next_word.as_str().parse().ok())
+                    == Some(Species::Dragonborn)
+            }) {
+                // "a red dragonborn"
+                npc.draconic_ancestry = Field::new(ancestry);
+            } else if let Ok(alignment) = word_str.parse() {
+                npc.alignment = Field::new(alignment);
             } else if word_str.in_ci(&["character", "npc", "person"]) {
                 // ignore
             } else if let Ok(gender) = word_str.parse() {
@@ -134,6 +215,12 @@ impl FromStr for ParsedThing<Npc> {
                 word_str.strip_suffix_ci("-year-old").map(|s| s.parse())
             {
                 npc.age_years = Field::new(age_years);
+            } else if let Some(age_range) = word_str
+                .strip_suffix_ci("-year-old")
+                .and_then(parse_age_range)
+            {
+                // "20-40-year-old"
+                npc.age_range = Some(age_range);
             } else {
                 unknown_words.push(word.range().to_owned());
             }
@@ -216,6 +303,35 @@ mod test {
             assert_eq!(0, place.unknown_words.len());
             assert_eq!(1, place.word_count);
         }
+
+        {
+            let place: ParsedThing<Place> = "a dwarvish kingdom".parse().unwrap();
+            assert_eq!(
+                Field::Locked("kingdom".parse::<PlaceType>().ok()),
+                place.thing.subtype,
+            );
+            assert!(
+                place
+                    .thing
+                    .demographics
+                    .value()
+                    .unwrap()
+                    .to_string()
+                    .starts_with("80% dwarf, "),
+                "{:?}",
+                place.thing.demographics,
+            );
+            assert_eq!(0, place.unknown_words.len());
+            assert_eq!(2, place.word_count);
+        }
+
+        assert_eq!(Err(()), "elf".parse::<ParsedThing<Place>>());
+        assert!("place elf"
+            .parse::<ParsedThing<Place>>()
+            .unwrap()
+            .thing
+            .demographics
+            .is_none());
     }
 
     #[test]
@@ -280,6 +396,94 @@ mod test {
                 .unwrap(),
         );
 
+        {
+            let npc: ParsedThing<Npc> = "npc age 37".parse().unwrap();
+            assert_eq!(Field::Locked(Some(37)), npc.thing.age_years);
+            assert_eq!(0, npc.unknown_words.len());
+            assert_eq!(2, npc.word_count);
+        }
+        assert_eq!(
+            "npc age 37".parse::<ParsedThing<Npc>>().unwrap(),
+            "NPC AGED 37".parse::<ParsedThing<Npc>>().unwrap(),
+        );
+
+        {
+            let npc: ParsedThing<Npc> = "elderly elf aged potato".parse().unwrap();
+            assert_eq!(Field::Unlocked(None), npc.thing.age_years);
+            assert_eq!(vec![12..16, 17..23], npc.unknown_words);
+        }
+
+        {
+            let npc: ParsedThing<Npc> = "npc age 20-40".parse().unwrap();
+            assert_eq!(Some((20, 40)), npc.thing.age_range);
+            assert_eq!(Field::Unlocked(None), npc.thing.age_years);
+            assert_eq!(0, npc.unknown_words.len());
+        }
+        assert_eq!(
+            Some((20, 40)),
+            "dwarf aged 40-20"
+                .parse::<ParsedThing<Npc>>()
+                .unwrap()
+                .thing
+                .age_range,
+        );
+        assert_eq!(
+            Some((20, 40)),
+            "20-40-year-old dwarf"
+                .parse::<ParsedThing<Npc>>()
+                .unwrap()
+                .thing
+                .age_range,
+        );
+
+        {
+            let npc: ParsedThing<Npc> = "lawful good dwarf".parse().unwrap();
+            assert_eq!(
+                Field::Locked(Some(Alignment::LawfulGood)),
+                npc.thing.alignment
+            );
+            assert_eq!(Field::Locked(Some(Species::Dwarf)), npc.thing.species);
+            assert_eq!(0, npc.unknown_words.len());
+            assert_eq!(2, npc.word_count);
+        }
+        assert_eq!(
+            "lawful good dwarf".parse::<ParsedThing<Npc>>().unwrap(),
+            "LAWFUL GOOD DWARF".parse::<ParsedThing<Npc>>().unwrap(),
+        );
+
+        {
+            let npc: ParsedThing<Npc> = "a half elf".parse().unwrap();
+            assert_eq!(Field::Locked(Some(Species::HalfElf)), npc.thing.species);
+            assert_eq!(0, npc.unknown_words.len());
+            assert_eq!(1, npc.word_count);
+        }
+        assert_eq!(
+            "half-orc".parse::<ParsedThing<Npc>>().unwrap().thing,
+            "Half Orc".parse::<ParsedThing<Npc>>().unwrap().thing,
+        );
+
+        {
+            let npc: ParsedThing<Npc> = "a red dragonborn".parse().unwrap();
+            assert_eq!(
+                Field::Locked(Some(DraconicAncestry::Red)),
+                npc.thing.draconic_ancestry,
+            );
+            assert_eq!(Field::Locked(Some(Species::Dragonborn)), npc.thing.species);
+            assert_eq!(0, npc.unknown_words.len());
+        }
+
+        {
+            let npc: ParsedThing<Npc> = "red elf".parse().unwrap();
+            assert!(npc.thing.draconic_ancestry.is_none());
+            assert_eq!(1, npc.unknown_words.len());
+        }
+
+        {
+            let npc: ParsedThing<Npc> = "neutral npc".parse().unwrap();
+            assert_eq!(Field::Locked(Some(Alignment::Neutral)), npc.thing.alignment);
+            assert_eq!(0, npc.unknown_words.len());
+        }
+
         {
             assert!("potato".parse::<ParsedThing<Npc>>().is_err());
         }
@@ -1,16 +1,26 @@
-use super::npc::{Ethnicity, Species};
+use super::npc::{Ethnicity, NameFlavor, Species};
 use rand::distributions::WeightedIndex;
 use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
 use std::iter;
 
 type GroupMap = HashMap<(Species, Ethnicity), u64>;
 type GroupMapSerialized = Vec<(Species, Ethnicity, u64)>;
 
+/// The largest weight that a species can be given with `set demographics`. This keeps the total
+/// of the weights from overflowing.
+const MAX_WEIGHT: u64 = 1000;
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Demographics {
     groups: GroupMapWrapper,
+
+    /// The flavor of human names, which follows the app setting rather than being saved with
+    /// a place.
+    #[serde(skip)]
+    names: NameFlavor,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -21,9 +31,18 @@ impl Demographics {
     pub fn new(groups: GroupMap) -> Self {
         Self {
             groups: GroupMapWrapper(groups),
+            names: NameFlavor::default(),
         }
     }
 
+    pub fn with_names(self, names: NameFlavor) -> Self {
+        Self { names, ..self }
+    }
+
+    pub fn names(&self) -> NameFlavor {
+        self.names
+    }
+
     pub fn shift_species(&self, species: &Species, amount: f64) -> Self {
         self.shift_by(
             |s, _| s == species,
@@ -69,13 +88,71 @@ impl Demographics {
         if self.groups().is_empty() {
             (Species::Human, Species::Human.default_ethnicity())
         } else {
+            // Sort the groups so that a seeded RNG gives the same result every time, regardless
+            // of the HashMap's iteration order.
+            let mut groups: Vec<_> = self.groups().iter().collect();
+            groups.sort_by_key(|((species, ethnicity), _)| (species.as_str(), ethnicity.as_str()));
+
             let (groups, weights): (Vec<(Species, Ethnicity)>, Vec<u64>) =
-                self.groups().iter().unzip();
+                groups.into_iter().unzip();
             let dist = WeightedIndex::new(weights).unwrap();
             groups[dist.sample(rng)]
         }
     }
 
+    /// Parse a list of species and relative weights, eg. "human 50 elf 30 half-elf 20". Each
+    /// species is given its default ethnicity.
+    pub fn parse_weights(input: &str) -> Result<Self, String> {
+        let mut groups = GroupMap::new();
+        let mut name_words: Vec<&str> = Vec::new();
+
+        for word in input.split_whitespace() {
+            if let Ok(weight) = word.parse::<u64>() {
+                if name_words.is_empty() {
+                    return Err(format!("Expected a species before \"{}\".", word));
+                }
+
+                let name = name_words.join(" ");
+                name_words.clear();
+
+                if weight > MAX_WEIGHT {
+                    return Err(format!(
+                        "\"{}\" has a weight of {}. Weights can be at most {}.",
+                        name, weight, MAX_WEIGHT,
+                    ));
+                }
+
+                let species: Species = name.parse().map_err(|_| Species::not_recognized(&name))?;
+
+                *groups
+                    .entry((species, species.default_ethnicity()))
+                    .or_default() += weight;
+            } else if word.parse::<i64>().is_ok() || word.parse::<f64>().is_ok() {
+                return Err(format!(
+                    "\"{}\" is not a valid weight. Weights must be whole numbers of zero or more.",
+                    word,
+                ));
+            } else {
+                name_words.push(word);
+            }
+        }
+
+        if !name_words.is_empty() {
+            return Err(format!(
+                "Missing a weight for \"{}\".",
+                name_words.join(" ")
+            ));
+        }
+
+        groups.retain(|_, weight| *weight > 0);
+
+        if groups.is_empty() {
+            Err("At least one species must have a weight greater than zero.".to_string())
+        } else {
+            Ok(Self::new(groups))
+        }
+    }
+
     fn shift_by<F: Fn(&Species, &Ethnicity) -> bool>(
         &self,
         f: F,
@@ -146,6 +223,38 @@ impl Default for Demographics {
     }
 }
 
+impl fmt::Display for Demographics {
+    /// Summarize by species, largest first, eg. "80% dwarf, 12% human, 8% gnome". Groups smaller
+    /// than 1% are omitted.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let population: u64 = self.groups().values().sum();
+        let mut species: HashMap<Species, u64> = HashMap::new();
+
+        for ((s, _), n) in self.groups() {
+            *species.entry(*s).or_default() += n;
+        }
+
+        let mut species: Vec<(Species, u64)> = species
+            .into_iter()
+            .map(|(s, n)| (s, (n as f64 * 100. / population as f64).round() as u64))
+            .filter(|(_, percent)| *percent > 0)
+            .collect();
+        species.sort_by(|(a_species, a), (b_species, b)| {
+            b.cmp(a)
+                .then_with(|| a_species.as_str().cmp(b_species.as_str()))
+        });
+
+        for (i, (s, percent)) in species.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}% {}", percent, s)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl From<GroupMap> for GroupMapWrapper {
     fn from(value: GroupMap) -> Self {
         Self(value)
@@ -412,6 +521,60 @@ mod test {
         );
     }
 
+    #[test]
+    fn fmt_test() {
+        assert_eq!("50% gnome, 50% human", demographics().to_string());
+        assert_eq!(
+            "85% gnome, 15% human",
+            demographics()
+                .shift_species(&Species::Gnome, 0.7)
+                .to_string(),
+        );
+        assert_eq!("", Demographics::new(HashMap::new()).to_string());
+    }
+
+    #[test]
+    fn parse_weights_test() {
+        assert_eq!(
+            Ok("60% human, 40% half-elf".to_string()),
+            Demographics::parse_weights("human 3 half elf 2").map(|d| d.to_string()),
+        );
+        assert_eq!(
+            Ok("100% elf".to_string()),
+            Demographics::parse_weights("Elf 50 elf 50 dwarf 0").map(|d| d.to_string()),
+        );
+
+        assert_eq!(
+            Err(
+                "\"-5\" is not a valid weight. Weights must be whole numbers of zero or more."
+                    .to_string()
+            ),
+            Demographics::parse_weights("human -5").map(|_| ()),
+        );
+        assert!(Demographics::parse_weights("potato 5")
+            .unwrap_err()
+            .starts_with("\"potato\" is not a recognized species. Try one of: dragonborn, "));
+        assert_eq!(
+            Err("Missing a weight for \"elf\".".to_string()),
+            Demographics::parse_weights("human 5 elf").map(|_| ()),
+        );
+        assert_eq!(
+            Err("Expected a species before \"5\".".to_string()),
+            Demographics::parse_weights("5 human").map(|_| ()),
+        );
+        assert_eq!(
+            Err("At least one species must have a weight greater than zero.".to_string()),
+            Demographics::parse_weights("human 0").map(|_| ()),
+        );
+        assert_eq!(
+            Err(
+                "\"human\" has a weight of 18446744073709551615. Weights can be at most 1000."
+                    .to_string()
+            ),
+            Demographics::parse_weights("human 18446744073709551615 elf 5").map(|_| ()),
+        );
+    }
+
     fn demographics() -> Demographics {
         let mut groups = HashMap::with_capacity(3);
         groups.insert((Species::Human, Ethnicity::Human), 30);
@@ -5,16 +5,19 @@ pub mod place;
 pub use command::{ParsedThing, WorldCommand};
 pub use demographics::Demographics;
 pub use field::Field;
-pub use npc::{Npc, NpcRelations};
+pub use npc::{NameFlavor, Npc, NpcRelations};
 pub use place::{Place, PlaceRelations, Uuid as PlaceUuid};
 pub use thing::{Thing, ThingRelations};
+pub use units::Units;
 
 mod command;
 mod field;
 mod thing;
+mod units;
 mod word;
 
 use rand::Rng;
+use std::fmt;
 
 pub trait Generate: Default {
     fn generate(rng: &mut impl Rng, demographics: &Demographics) -> Self {
@@ -26,7 +29,10 @@ pub trait Generate: Default {
     fn regenerate(&mut self, rng: &mut impl Rng, demographics: &Demographics);
 }
 
-fn weighted_index_from_tuple<'a, T>(rng: &mut impl Rng, input: &'a [(T, usize)]) -> &'a T {
+pub(crate) fn weighted_index_from_tuple<'a, T>(
+    rng: &mut impl Rng,
+    input: &'a [(T, usize)],
+) -> &'a T {
     let total = input.iter().map(|(_, n)| n).sum();
 
     if total == 0 {
@@ -46,6 +52,53 @@ fn weighted_index_from_tuple<'a, T>(rng: &mut impl Rng, input: &'a [(T, usize)])
     unreachable!();
 }
 
+/// The user's notes on a thing, one per line, written as a paragraph after the other details.
+fn write_notes(notes: &Field<String>, f: &mut fmt::Formatter) -> fmt::Result {
+    if let Some(notes) = notes.value().filter(|notes| !notes.is_empty()) {
+        write!(
+            f,
+            "\n\n**Notes:** {}",
+            notes.lines().collect::<Vec<_>>().join("\\\n"),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// The user's tags on a thing, written as a paragraph after the other details.
+fn write_tags(tags: &Field<Vec<String>>, f: &mut fmt::Formatter) -> fmt::Result {
+    if let Some(tags) = tags.value().filter(|tags| !tags.is_empty()) {
+        write!(f, "\n\n**Tags:** {}", tags.join(", "))?;
+    }
+
+    Ok(())
+}
+
+/// Tags are compared by their normalized form, so `Quest-Giver`, `#quest-giver` and
+/// `quest-giver` are all the same tag.
+pub(crate) fn normalize_tag(tag: &str) -> String {
+    tag.trim().trim_start_matches('#').to_lowercase()
+}
+
+/// Split a list of tags, separated by spaces or commas, into their normalized forms without
+/// duplicates. Tagging and listing by tag both go through here, so `Quest Giver` is always the
+/// two tags `quest` and `giver`.
+pub(crate) fn parse_tags(input: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+
+    for tag in input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .map(normalize_tag)
+        .filter(|tag| !tag.is_empty())
+    {
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+
+    tags
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -77,6 +130,24 @@ mod test {
         );
     }
 
+    #[test]
+    fn normalize_tag_test() {
+        assert_eq!("quest-giver", normalize_tag("quest-giver"));
+        assert_eq!("quest-giver", normalize_tag(" #Quest-Giver "));
+        assert_eq!("faction:dwarves", normalize_tag("#Faction:Dwarves"));
+        assert_eq!("", normalize_tag(" # "));
+    }
+
+    #[test]
+    fn parse_tags_test() {
+        assert_eq!(vec!["quest", "giver"], parse_tags("  Quest   Giver "));
+        assert_eq!(
+            vec!["faction:dwarves", "quest-giver"],
+            parse_tags("#Faction:Dwarves, quest-giver,QUEST-GIVER"),
+        );
+        assert!(parse_tags(" # , ").is_empty());
+    }
+
     #[test]
     #[should_panic]
     fn weighted_index_from_tuple_test_empty() {
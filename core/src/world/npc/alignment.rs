@@ -0,0 +1,110 @@
+use crate::world::weighted_index_from_tuple;
+use initiative_macros::WordList;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, WordList, Serialize, Deserialize)]
+#[serde(into = "&'static str", try_from = "&str")]
+pub enum Alignment {
+    #[term = "lawful good"]
+    LawfulGood,
+
+    #[term = "neutral good"]
+    NeutralGood,
+
+    #[term = "chaotic good"]
+    ChaoticGood,
+
+    #[term = "lawful neutral"]
+    LawfulNeutral,
+
+    #[alias = "true neutral"]
+    Neutral,
+
+    #[term = "chaotic neutral"]
+    ChaoticNeutral,
+
+    #[term = "lawful evil"]
+    LawfulEvil,
+
+    #[term = "neutral evil"]
+    NeutralEvil,
+
+    #[term = "chaotic evil"]
+    ChaoticEvil,
+}
+
+impl Alignment {
+    /// Most ordinary folk are some shade of neutral; the extremes are rarer.
+    pub fn generate(rng: &mut impl Rng) -> Self {
+        *weighted_index_from_tuple(
+            rng,
+            &[
+                (Self::LawfulGood, 10),
+                (Self::NeutralGood, 15),
+                (Self::ChaoticGood, 8),
+                (Self::LawfulNeutral, 12),
+                (Self::Neutral, 30),
+                (Self::ChaoticNeutral, 10),
+                (Self::LawfulEvil, 6),
+                (Self::NeutralEvil, 6),
+                (Self::ChaoticEvil, 3),
+            ][..],
+        )
+    }
+}
+
+impl fmt::Display for Alignment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::prelude::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn generate_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut alignments: HashMap<Alignment, u16> = HashMap::new();
+
+        for _ in 0..1000 {
+            *alignments.entry(Alignment::generate(&mut rng)).or_default() += 1;
+        }
+
+        assert_eq!(9, alignments.len());
+        assert!(alignments
+            .values()
+            .all(|&count| count <= alignments[&Alignment::Neutral]));
+    }
+
+    #[test]
+    fn try_from_test() {
+        assert_eq!(Ok(Alignment::LawfulGood), "lawful good".parse());
+        assert_eq!(Ok(Alignment::ChaoticEvil), "Chaotic Evil".parse());
+        assert_eq!(Ok(Alignment::Neutral), "neutral".parse());
+        assert_eq!(Ok(Alignment::Neutral), "true neutral".parse());
+        assert_eq!(Err(()), "good".parse::<Alignment>());
+    }
+
+    #[test]
+    fn fmt_test() {
+        assert_eq!("lawful good", format!("{}", Alignment::LawfulGood));
+        assert_eq!("neutral", format!("{}", Alignment::Neutral));
+    }
+
+    #[test]
+    fn serialize_deserialize_test() {
+        assert_eq!(
+            "\"chaotic neutral\"",
+            serde_json::to_string(&Alignment::ChaoticNeutral).unwrap(),
+        );
+
+        let value: Alignment = serde_json::from_str("\"chaotic neutral\"").unwrap();
+        assert_eq!(Alignment::ChaoticNeutral, value);
+    }
+}
@@ -0,0 +1,102 @@
+use crate::world::weighted_index_from_tuple;
+use initiative_macros::WordList;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The kind of dragon from which a dragonborn is descended, which determines the damage type of
+/// their breath weapon.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, WordList, Serialize, Deserialize)]
+#[serde(into = "&'static str", try_from = "&str")]
+pub enum DraconicAncestry {
+    Black,
+    Blue,
+    Brass,
+    Bronze,
+    Copper,
+    Gold,
+    Green,
+    Red,
+    Silver,
+    White,
+}
+
+impl DraconicAncestry {
+    pub fn generate(rng: &mut impl Rng) -> Self {
+        *weighted_index_from_tuple(
+            rng,
+            &[
+                (Self::Black, 1),
+                (Self::Blue, 1),
+                (Self::Brass, 1),
+                (Self::Bronze, 1),
+                (Self::Copper, 1),
+                (Self::Gold, 1),
+                (Self::Green, 1),
+                (Self::Red, 1),
+                (Self::Silver, 1),
+                (Self::White, 1),
+            ][..],
+        )
+    }
+
+    pub fn damage_type(&self) -> &'static str {
+        match self {
+            Self::Black | Self::Copper => "acid",
+            Self::Blue | Self::Bronze => "lightning",
+            Self::Brass | Self::Gold | Self::Red => "fire",
+            Self::Green => "poison",
+            Self::Silver | Self::White => "cold",
+        }
+    }
+}
+
+impl fmt::Display for DraconicAncestry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} dragon ({})", self.as_str(), self.damage_type())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::prelude::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn generate_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let ancestries: HashSet<DraconicAncestry> = (0..200)
+            .map(|_| DraconicAncestry::generate(&mut rng))
+            .collect();
+
+        assert_eq!(10, ancestries.len());
+    }
+
+    #[test]
+    fn try_from_test() {
+        assert_eq!(Ok(DraconicAncestry::Red), "red".parse());
+        assert_eq!(Ok(DraconicAncestry::Bronze), "Bronze".parse());
+        assert_eq!(Err(()), "purple".parse::<DraconicAncestry>());
+    }
+
+    #[test]
+    fn fmt_test() {
+        assert_eq!("red dragon (fire)", format!("{}", DraconicAncestry::Red));
+        assert_eq!(
+            "silver dragon (cold)",
+            format!("{}", DraconicAncestry::Silver)
+        );
+    }
+
+    #[test]
+    fn serialize_deserialize_test() {
+        assert_eq!(
+            "\"copper\"",
+            serde_json::to_string(&DraconicAncestry::Copper).unwrap(),
+        );
+
+        let value: DraconicAncestry = serde_json::from_str("\"copper\"").unwrap();
+        assert_eq!(DraconicAncestry::Copper, value);
+    }
+}
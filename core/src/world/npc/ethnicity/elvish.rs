@@ -222,7 +222,7 @@ impl Generate for Ethnicity {
 #[cfg(test)]
 mod test_generate_for_ethnicity {
     use super::*;
-    use crate::world::npc::ethnicity::{regenerate, Ethnicity};
+    use crate::world::npc::ethnicity::{regenerate, Ethnicity, NameFlavor};
     use crate::world::Npc;
 
     #[test]
@@ -264,7 +264,7 @@ mod test_generate_for_ethnicity {
         npc.gender.replace(*gender);
         npc.age.replace(*age);
         npc.ethnicity.replace(Ethnicity::Elvish);
-        regenerate(rng, &mut npc);
+        regenerate(rng, &mut npc, NameFlavor::default());
         format!("{}", npc.name)
     }
 }
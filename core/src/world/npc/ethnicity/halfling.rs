@@ -202,7 +202,7 @@ impl Generate for Ethnicity {
 #[cfg(test)]
 mod test_generate_for_ethnicity {
     use super::*;
-    use crate::world::npc::ethnicity::{regenerate, Ethnicity};
+    use crate::world::npc::ethnicity::{regenerate, Ethnicity, NameFlavor};
     use crate::world::Npc;
 
     #[test]
@@ -238,7 +238,7 @@ mod test_generate_for_ethnicity {
         npc.gender.replace(*gender);
         npc.age.replace(*age);
         npc.ethnicity.replace(Ethnicity::Halfling);
-        regenerate(rng, &mut npc);
+        regenerate(rng, &mut npc, NameFlavor::default());
         format!("{}", npc.name)
     }
 }
@@ -1,8 +1,118 @@
 use super::{Age, Gender, Generate, GenerateSimple};
+use crate::world::Npc;
+use initiative_macros::WordList;
 use rand::prelude::*;
+use std::fmt;
 
 pub struct Ethnicity;
 
+/// The cultural flavor of generated human names, chosen with `set names [flavor]`. The common
+/// flavor is the original syllable-based generator; the others draw whole names from a list.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, WordList)]
+pub enum NameFlavor {
+    Arabic,
+    Celtic,
+    #[default]
+    #[alias = "default"]
+    Common,
+    Nordic,
+}
+
+impl NameFlavor {
+    #[rustfmt::skip]
+    fn given_names_f(&self) -> &'static [&'static str] {
+        match self {
+            Self::Arabic => &[
+                "Aisha", "Amira", "Farah", "Fatima", "Huda", "Jamila", "Layla", "Lina", "Mariam",
+                "Nadia", "Noor", "Rania", "Salma", "Samira", "Yasmin", "Zahra",
+            ],
+            Self::Celtic => &[
+                "Aislinn", "Brigid", "Caoimhe", "Deirdre", "Eilidh", "Fionnuala", "Gwen",
+                "Isolde", "Maeve", "Niamh", "Orla", "Rhiannon", "Saoirse", "Siobhan", "Tegan",
+                "Una",
+            ],
+            Self::Common => &[],
+            Self::Nordic => &[
+                "Astrid", "Brynja", "Eira", "Freya", "Gudrun", "Helga", "Hilde", "Ingrid", "Liv",
+                "Ragnhild", "Runa", "Sif", "Sigrid", "Solveig", "Thora", "Yrsa",
+            ],
+        }
+    }
+
+    #[rustfmt::skip]
+    fn given_names_m(&self) -> &'static [&'static str] {
+        match self {
+            Self::Arabic => &[
+                "Amir", "Faris", "Hassan", "Ibrahim", "Idris", "Jamal", "Karim", "Khalid",
+                "Malik", "Nasir", "Omar", "Rashid", "Samir", "Tariq", "Yusuf", "Zayd",
+            ],
+            Self::Celtic => &[
+                "Aedan", "Bran", "Cathal", "Cian", "Cormac", "Declan", "Eamon", "Fergus",
+                "Gareth", "Lorcan", "Niall", "Owain", "Padraig", "Rhys", "Ronan", "Tadhg",
+            ],
+            Self::Common => &[],
+            Self::Nordic => &[
+                "Bjorn", "Erik", "Gunnar", "Halvard", "Harald", "Ivar", "Knut", "Leif", "Olaf",
+                "Ragnar", "Sigurd", "Sven", "Thorvald", "Torsten", "Ulf", "Vidar",
+            ],
+        }
+    }
+
+    #[rustfmt::skip]
+    fn family_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Arabic => &[
+                "al-Amin", "al-Farsi", "al-Hakim", "al-Jabiri", "al-Masri", "al-Rashid",
+                "al-Sayed", "Bakr", "Darwish", "Haddad", "Hamdan", "Khoury", "Mansour", "Nassar",
+                "Qasim", "Saleh",
+            ],
+            Self::Celtic => &[
+                "Donnelly", "Flanagan", "Gallagher", "Kennedy", "MacAlister", "MacKenna",
+                "MacLeod", "MacNeil", "Murphy", "O'Brien", "O'Connor", "O'Neill", "Quinn",
+                "Rafferty", "Sullivan", "Walsh",
+            ],
+            Self::Common | Self::Nordic => &[],
+        }
+    }
+
+    fn gen_name(&self, rng: &mut impl Rng, gender: &Gender) -> String {
+        let given_names: Vec<&str> = match gender {
+            Gender::Feminine => self.given_names_f().to_vec(),
+            Gender::Masculine => self.given_names_m().to_vec(),
+            _ => [self.given_names_f(), self.given_names_m()].concat(),
+        };
+        let given_name = given_names.choose(rng).unwrap();
+
+        let family_name = if let Self::Nordic = self {
+            // Nordic family names are patronymics, taken from the father's given name.
+            let father = self.given_names_m().choose(rng).unwrap();
+            match gender {
+                Gender::Feminine => format!("{}sdottir", father),
+                Gender::Masculine => format!("{}sson", father),
+                _ => format!("{}sbur", father),
+            }
+        } else {
+            self.family_names().choose(rng).unwrap().to_string()
+        };
+
+        format!("{} {}", given_name, family_name)
+    }
+}
+
+impl fmt::Display for NameFlavor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+pub fn regenerate(rng: &mut impl Rng, npc: &mut Npc, names: NameFlavor) {
+    if names == NameFlavor::Common {
+        Ethnicity::regenerate(rng, npc);
+    } else if let Some(gender) = npc.gender.value() {
+        npc.name.replace_with(|_| names.gen_name(rng, gender));
+    }
+}
+
 impl GenerateSimple for Ethnicity {
     fn syllable_fname_count_f() -> &'static [(u8, usize)] {
         &[(2, 717), (3, 357), (4, 27), (5, 1)]
@@ -207,8 +317,7 @@ impl Generate for Ethnicity {
 #[cfg(test)]
 mod test_generate_for_ethnicity {
     use super::*;
-    use crate::world::npc::ethnicity::{regenerate, Ethnicity};
-    use crate::world::Npc;
+    use crate::world::npc::ethnicity::{regenerate, Ethnicity, NameFlavor};
 
     #[test]
     fn gen_name_test() {
@@ -238,12 +347,46 @@ mod test_generate_for_ethnicity {
         );
     }
 
+    #[test]
+    fn gen_name_test_flavors() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let f = Gender::Feminine;
+        let m = Gender::Masculine;
+        let t = Gender::NonBinaryThey;
+
+        assert_eq!(
+            [
+                "Ingrid Leifsdottir",
+                "Leif Ulfsson",
+                "Ulf Ragnarsbur",
+                "Lina al-Sayed",
+                "Tariq Hamdan",
+                "Niamh MacAlister",
+            ],
+            [
+                NameFlavor::Nordic.gen_name(&mut rng, &f),
+                NameFlavor::Nordic.gen_name(&mut rng, &m),
+                NameFlavor::Nordic.gen_name(&mut rng, &t),
+                NameFlavor::Arabic.gen_name(&mut rng, &f),
+                NameFlavor::Arabic.gen_name(&mut rng, &m),
+                NameFlavor::Celtic.gen_name(&mut rng, &f),
+            ],
+        );
+    }
+
+    #[test]
+    fn name_flavor_from_str_test() {
+        assert_eq!(Ok(NameFlavor::Nordic), "NORDIC".parse());
+        assert_eq!(Ok(NameFlavor::Common), "default".parse());
+        assert_eq!(Err(()), "klingon".parse::<NameFlavor>());
+    }
+
     fn gen_name(rng: &mut impl Rng, age: &Age, gender: &Gender) -> String {
         let mut npc = Npc::default();
         npc.gender.replace(*gender);
         npc.age.replace(*age);
         npc.ethnicity.replace(Ethnicity::Human);
-        regenerate(rng, &mut npc);
+        regenerate(rng, &mut npc, NameFlavor::default());
         format!("{}", npc.name)
     }
 }
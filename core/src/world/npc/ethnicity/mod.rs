@@ -7,6 +7,8 @@ mod human;
 mod orcish;
 mod tiefling;
 
+pub use human::NameFlavor;
+
 use super::{Age, Gender, Npc, Species};
 use crate::world::weighted_index_from_tuple;
 use initiative_macros::WordList;
@@ -112,8 +114,42 @@ trait GenerateSimple {
     fn word_lname_last() -> &'static [(&'static str, usize)];
 }
 
-pub fn regenerate(rng: &mut impl Rng, npc: &mut Npc) {
+/// Characters of mixed heritage are named after both parents: their family name follows the
+/// culture they were raised in (their ethnicity), and their given name the other parent's.
+fn regenerate_mixed<Given: GenerateSimple, Family: GenerateSimple>(
+    rng: &mut impl Rng,
+    npc: &mut Npc,
+) {
+    if let Some(gender) = npc.gender.value() {
+        npc.name.replace_with(|_| {
+            format!(
+                "{} {}",
+                Given::gen_fname_simple(rng, gender),
+                Family::gen_lname_simple(rng),
+            )
+        });
+    }
+}
+
+/// Generate a name fitting the character's ethnicity. Human names follow the chosen flavor.
+pub fn regenerate(rng: &mut impl Rng, npc: &mut Npc, names: NameFlavor) {
     if let Some(ethnicity) = npc.ethnicity.value() {
+        match (npc.species.value(), ethnicity) {
+            (Some(Species::HalfElf), Ethnicity::Human) => {
+                return regenerate_mixed::<elvish::Ethnicity, human::Ethnicity>(rng, npc);
+            }
+            (Some(Species::HalfElf), Ethnicity::Elvish) => {
+                return regenerate_mixed::<human::Ethnicity, elvish::Ethnicity>(rng, npc);
+            }
+            (Some(Species::HalfOrc), Ethnicity::Human) => {
+                return regenerate_mixed::<orcish::Ethnicity, human::Ethnicity>(rng, npc);
+            }
+            (Some(Species::HalfOrc), Ethnicity::Orcish) => {
+                return regenerate_mixed::<human::Ethnicity, orcish::Ethnicity>(rng, npc);
+            }
+            _ => {}
+        }
+
         match ethnicity {
             Ethnicity::Dragonborn => dragonborn::Ethnicity::regenerate(rng, npc),
             Ethnicity::Dwarvish => dwarvish::Ethnicity::regenerate(rng, npc),
@@ -121,7 +157,7 @@ pub fn regenerate(rng: &mut impl Rng, npc: &mut Npc) {
             Ethnicity::Gnomish => gnomish::Ethnicity::regenerate(rng, npc),
             Ethnicity::Orcish => orcish::Ethnicity::regenerate(rng, npc),
             Ethnicity::Halfling => halfling::Ethnicity::regenerate(rng, npc),
-            Ethnicity::Human => human::Ethnicity::regenerate(rng, npc),
+            Ethnicity::Human => human::regenerate(rng, npc, names),
             Ethnicity::Tiefling => tiefling::Ethnicity::regenerate(rng, npc),
         }
     }
@@ -182,6 +218,36 @@ mod test {
         assert_eq!(Species::Tiefling, Ethnicity::Tiefling.default_species());
     }
 
+    #[test]
+    fn regenerate_mixed_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut npc = Npc {
+            species: Species::HalfElf.into(),
+            ethnicity: Ethnicity::Human.into(),
+            gender: Gender::Feminine.into(),
+            age: Age::Adult.into(),
+            ..Default::default()
+        };
+        regenerate(&mut rng, &mut npc, NameFlavor::default());
+        let half_elf_name = npc.name.value().unwrap().clone();
+
+        let mut rng = SmallRng::seed_from_u64(0);
+        let given = elvish::Ethnicity::gen_fname_simple(&mut rng, &Gender::Feminine);
+        let family = human::Ethnicity::gen_lname_simple(&mut rng);
+        assert_eq!(format!("{} {}", given, family), half_elf_name);
+
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut npc = Npc {
+            species: Species::Human.into(),
+            ethnicity: Ethnicity::Human.into(),
+            gender: Gender::Feminine.into(),
+            age: Age::Adult.into(),
+            ..Default::default()
+        };
+        regenerate(&mut rng, &mut npc, NameFlavor::default());
+        assert_ne!(Some(&half_elf_name), npc.name.value());
+    }
+
     #[test]
     fn serialize_deserialize_test() {
         assert_eq!(
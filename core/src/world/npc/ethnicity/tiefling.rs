@@ -1,8 +1,24 @@
 use super::{Age, Gender, Generate, GenerateSimple};
+use crate::world::weighted_index_from_tuple;
 use rand::prelude::*;
 
 pub struct Ethnicity;
 
+impl Ethnicity {
+    /// Some tieflings reject their infernal names in favour of a concept they hope to embody
+    /// (or one that they feel the world has forced upon them).
+    #[rustfmt::skip]
+    fn virtue_names() -> &'static [(&'static str, usize)] {
+        &[
+            ("Art", 1), ("Carrion", 1), ("Chant", 1), ("Creed", 1), ("Despair", 1),
+            ("Excellence", 1), ("Fear", 1), ("Glory", 1), ("Hope", 1), ("Ideal", 1),
+            ("Music", 1), ("Nowhere", 1), ("Open", 1), ("Poetry", 1), ("Quest", 1),
+            ("Random", 1), ("Reverence", 1), ("Sorrow", 1), ("Temerity", 1), ("Torment", 1),
+            ("Weary", 1),
+        ]
+    }
+}
+
 impl GenerateSimple for Ethnicity {
     fn syllable_fname_count_f() -> &'static [(u8, usize)] {
         &[(2, 11), (3, 7), (4, 1)]
@@ -124,18 +140,20 @@ impl GenerateSimple for Ethnicity {
 
 impl Generate for Ethnicity {
     fn gen_name(rng: &mut impl Rng, _age: &Age, gender: &Gender) -> String {
-        format!(
-            "{} {}",
-            Self::gen_fname_simple(rng, gender),
-            Self::gen_lname_simple(rng),
-        )
+        let fname = if rng.gen_bool(0.25) {
+            weighted_index_from_tuple(rng, Self::virtue_names()).to_string()
+        } else {
+            Self::gen_fname_simple(rng, gender)
+        };
+
+        format!("{} {}", fname, Self::gen_lname_simple(rng))
     }
 }
 
 #[cfg(test)]
 mod test_generate_for_ethnicity {
     use super::*;
-    use crate::world::npc::ethnicity::{regenerate, Ethnicity};
+    use crate::world::npc::ethnicity::{regenerate, Ethnicity, NameFlavor};
     use crate::world::Npc;
 
     #[test]
@@ -149,13 +167,13 @@ mod test_generate_for_ethnicity {
         assert_eq!(
             [
                 "Vidandos Shatos",
-                "Hevivyre Alegkwing",
+                "Kavyre Alegkwing",
                 "Bilmays Katos",
                 "Narra demaern",
                 "Matuor Shazar",
-                "Norislor Gulkwing",
-                "Tefer Shavitos",
-                "Orara deVore",
+                "Mordos Impkwing",
+                "Raevyre Impni",
+                "Ideal deVore",
                 "Zevlia Sazar",
                 "Goroth Alegtos",
             ],
@@ -174,12 +192,28 @@ mod test_generate_for_ethnicity {
         );
     }
 
+    #[test]
+    fn gen_name_virtue_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let virtue_names: Vec<&str> = super::Ethnicity::virtue_names()
+            .iter()
+            .map(|(name, _)| *name)
+            .collect();
+
+        let virtue_count = (0..100)
+            .map(|_| gen_name(&mut rng, &Age::Adult, &Gender::Feminine))
+            .filter(|name| virtue_names.contains(&name.split(' ').next().unwrap()))
+            .count();
+
+        assert!((10..50).contains(&virtue_count), "{}", virtue_count);
+    }
+
     fn gen_name(rng: &mut impl Rng, age: &Age, gender: &Gender) -> String {
         let mut npc = Npc::default();
         npc.gender.replace(*gender);
         npc.age.replace(*age);
         npc.ethnicity.replace(Ethnicity::Tiefling);
-        regenerate(rng, &mut npc);
+        regenerate(rng, &mut npc, NameFlavor::default());
         format!("{}", npc.name)
     }
 }
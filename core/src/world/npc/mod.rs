@@ -1,13 +1,25 @@
 pub use age::Age;
-pub use ethnicity::Ethnicity;
+pub use alignment::Alignment;
+pub use draconic_ancestry::DraconicAncestry;
+pub use ethnicity::{Ethnicity, NameFlavor};
 pub use gender::Gender;
+pub use occupation::Occupation;
+pub use personality::PersonalityTrait;
+pub use quirk::Quirk;
+pub use relationship::{Relationship, RelationshipKind};
 pub use size::Size;
 pub use species::Species;
-pub use view::{DescriptionView, DetailsView, SummaryView};
+pub use view::{DescriptionView, DetailsView, ProseView, SummaryView};
 
 mod age;
+mod alignment;
+mod draconic_ancestry;
 mod ethnicity;
 mod gender;
+mod occupation;
+mod personality;
+mod quirk;
+mod relationship;
 mod size;
 mod species;
 mod view;
@@ -25,12 +37,45 @@ pub struct Npc {
     pub gender: Field<Gender>,
     pub age: Field<Age>,
     pub age_years: Field<u16>,
+
+    /// Bounds for a generated age in years, from a description like "aged 20-40". This only
+    /// guides generation, so it isn't saved.
+    #[serde(skip)]
+    pub age_range: Option<(u16, u16)>,
+
     pub size: Field<Size>,
     pub species: Field<Species>,
     pub ethnicity: Field<Ethnicity>,
     pub location_uuid: Field<PlaceUuid>,
+
+    #[serde(default)]
+    pub occupation: Field<Occupation>,
+
+    #[serde(default)]
+    pub personality: Field<Vec<PersonalityTrait>>,
+
+    #[serde(default)]
+    pub alignment: Field<Alignment>,
+
+    /// Only meaningful for dragonborn.
+    #[serde(default)]
+    pub draconic_ancestry: Field<DraconicAncestry>,
+
+    #[serde(default)]
+    pub quirk: Field<Quirk>,
+
+    /// Relationships with other characters, each of which stores the inverse.
+    #[serde(default)]
+    pub relationships: Field<Vec<Relationship>>,
+
+    /// Freeform notes added by the user, one per line.
+    #[serde(default)]
+    pub notes: Field<String>,
+
+    /// Freeform labels added by the user, normalized to lowercase with no spaces.
+    #[serde(default)]
+    pub tags: Field<Vec<String>>,
     // pub home: Field<PlaceUuid>,
-    // pub occupation: Field<Role>,
     // pub languages: Field<Vec<String>>,
     // pub parents: Field<Vec<Uuid>>,
     // pub spouses: Field<Vec<Uuid>>,
@@ -41,6 +86,9 @@ pub struct Npc {
 #[derive(Debug, Default)]
 pub struct NpcRelations {
     pub location: Option<(Place, Option<Place>)>,
+
+    /// Other characters, along with what this character is to them, eg. `Sibling` for a brother.
+    pub relationships: Vec<(RelationshipKind, Npc)>,
 }
 
 impl Npc {
@@ -52,6 +100,10 @@ impl Npc {
         DescriptionView::new(self)
     }
 
+    pub fn display_prose(&self) -> ProseView {
+        ProseView::new(self)
+    }
+
     pub fn display_details(&self, relations: NpcRelations) -> DetailsView {
         DetailsView::new(self, relations)
     }
@@ -74,10 +126,19 @@ impl Npc {
             gender,
             age,
             age_years,
+            age_range: _,
             size,
             species,
             ethnicity,
             location_uuid,
+            occupation,
+            personality,
+            alignment,
+            draconic_ancestry,
+            quirk,
+            relationships,
+            notes,
+            tags,
         } = self;
 
         name.lock();
@@ -88,19 +149,59 @@ impl Npc {
         species.lock();
         ethnicity.lock();
         location_uuid.lock();
+        occupation.lock();
+        personality.lock();
+        alignment.lock();
+        draconic_ancestry.lock();
+        quirk.lock();
+        relationships.lock();
+        notes.lock();
+        tags.lock();
     }
 
     pub fn apply_diff(&mut self, diff: &mut Self) {
+        // The age category depends on the species, so changing the species or age in years can
+        // change it too, unless the diff specifies a category of its own.
+        if diff.age.is_unlocked() && (diff.species.is_locked() || diff.age_years.is_locked()) {
+            let species = if diff.species.is_locked() {
+                diff.species.value()
+            } else {
+                self.species.value()
+            };
+            let age_years = if diff.age_years.is_locked() {
+                diff.age_years.value()
+            } else {
+                self.age_years.value()
+            };
+
+            if let (Some(species), Some(age_years)) = (species, age_years) {
+                let age = species.age_from_years(*age_years);
+
+                if self.age.value() != Some(&age) {
+                    diff.age = Field::new(age);
+                }
+            }
+        }
+
         let Self {
             uuid: _,
             name,
             gender,
             age,
             age_years,
+            age_range: _,
             size,
             species,
             ethnicity,
             location_uuid,
+            occupation,
+            personality,
+            alignment,
+            draconic_ancestry,
+            quirk,
+            relationships,
+            notes,
+            tags,
         } = self;
 
         name.apply_diff(&mut diff.name);
@@ -111,6 +212,14 @@ impl Npc {
         species.apply_diff(&mut diff.species);
         ethnicity.apply_diff(&mut diff.ethnicity);
         location_uuid.apply_diff(&mut diff.location_uuid);
+        occupation.apply_diff(&mut diff.occupation);
+        personality.apply_diff(&mut diff.personality);
+        alignment.apply_diff(&mut diff.alignment);
+        draconic_ancestry.apply_diff(&mut diff.draconic_ancestry);
+        quirk.apply_diff(&mut diff.quirk);
+        relationships.apply_diff(&mut diff.relationships);
+        notes.apply_diff(&mut diff.notes);
+        tags.apply_diff(&mut diff.tags);
     }
 }
 
@@ -142,7 +251,30 @@ impl Generate for Npc {
         }
 
         species::regenerate(rng, self);
-        ethnicity::regenerate(rng, self);
+        ethnicity::regenerate(rng, self, demographics.names());
+
+        if let Some(occupation) = self
+            .age
+            .value()
+            .and_then(|age| Occupation::generate(rng, age))
+        {
+            self.occupation.replace(occupation);
+        } else {
+            self.occupation.clear();
+        }
+
+        self.personality
+            .replace_with(|_| PersonalityTrait::generate(rng));
+        self.alignment.replace_with(|_| Alignment::generate(rng));
+
+        if self.species.value() == Some(&Species::Dragonborn) {
+            self.draconic_ancestry
+                .replace_with(|_| DraconicAncestry::generate(rng));
+        } else {
+            self.draconic_ancestry.clear();
+        }
+
+        self.quirk.replace_with(|_| Quirk::generate(rng));
     }
 }
 
@@ -160,6 +292,32 @@ mod test {
 
         assert!(npc.species.is_some());
         assert!(npc.name.is_some());
+        assert!(npc.personality.is_some());
+        assert!(npc.alignment.is_some());
+        assert_eq!(
+            npc.occupation.is_some(),
+            !matches!(npc.age.value(), Some(Age::Infant | Age::Child)),
+        );
+    }
+
+    #[test]
+    fn regenerate_test_draconic_ancestry() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let demographics = Demographics::default();
+
+        let mut npc = Npc {
+            species: Field::new(Species::Dragonborn),
+            ..Default::default()
+        };
+        npc.regenerate(&mut rng, &demographics);
+        assert!(npc.draconic_ancestry.is_some());
+
+        let mut npc = Npc {
+            species: Field::new(Species::Human),
+            ..Default::default()
+        };
+        npc.regenerate(&mut rng, &demographics);
+        assert!(npc.draconic_ancestry.is_none());
     }
 
     #[test]
@@ -176,13 +334,27 @@ mod test {
         let npc = gandalf();
 
         assert_eq!(
-            r#"{"uuid":"00000000-0000-0000-0000-000000000000","name":"Gandalf the Grey","gender":"neuter","age":"geriatric","age_years":65535,"size":{"type":"Medium","height":72,"weight":200},"species":"human","ethnicity":"human","location_uuid":null}"#,
+            r#"{"uuid":"00000000-0000-0000-0000-000000000000","name":"Gandalf the Grey","gender":"neuter","age":"geriatric","age_years":65535,"size":{"type":"Medium","height":72,"weight":200},"species":"human","ethnicity":"human","location_uuid":null,"occupation":"scholar","personality":["wise","secretive"],"alignment":"neutral good","draconic_ancestry":null,"quirk":null,"relationships":null,"notes":null,"tags":null}"#,
             serde_json::to_string(&npc).unwrap()
         );
 
-        let value: Npc = serde_json::from_str(r#"{"uuid":"00000000-0000-0000-0000-000000000000","name":"Gandalf the Grey","gender":"neuter","age":"geriatric","age_years":65535,"size":{"type":"Medium","height":72,"weight":200},"species":"human","ethnicity":"human","location_uuid":null}"#).unwrap();
+        let value: Npc = serde_json::from_str(r#"{"uuid":"00000000-0000-0000-0000-000000000000","name":"Gandalf the Grey","gender":"neuter","age":"geriatric","age_years":65535,"size":{"type":"Medium","height":72,"weight":200},"species":"human","ethnicity":"human","location_uuid":null,"occupation":"scholar","personality":["wise","secretive"],"alignment":"neutral good","draconic_ancestry":null,"quirk":null,"relationships":null,"notes":null,"tags":null}"#).unwrap();
 
         assert_eq!(npc, value);
+
+        // Characters saved before notes were introduced.
+        let value: Npc = serde_json::from_str(r#"{"uuid":"00000000-0000-0000-0000-000000000000","name":"Gandalf the Grey","gender":"neuter","age":"geriatric","age_years":65535,"size":{"type":"Medium","height":72,"weight":200},"species":"human","ethnicity":"human","location_uuid":null,"occupation":"scholar","personality":["wise","secretive"],"alignment":"neutral good"}"#).unwrap();
+
+        assert_eq!(npc.name, value.name);
+        assert!(value.notes.is_none());
+
+        // Characters saved before occupations, personalities, and alignments were introduced.
+        let value: Npc = serde_json::from_str(r#"{"uuid":"00000000-0000-0000-0000-000000000000","name":"Gandalf the Grey","gender":"neuter","age":"geriatric","age_years":65535,"size":{"type":"Medium","height":72,"weight":200},"species":"human","ethnicity":"human","location_uuid":null}"#).unwrap();
+
+        assert_eq!(npc.name, value.name);
+        assert!(value.occupation.is_none());
+        assert!(value.personality.is_none());
+        assert!(value.alignment.is_none());
     }
 
     #[test]
@@ -220,6 +392,7 @@ mod test {
             gender: Gender::Neuter.into(),
             age: Age::Geriatric.into(),
             age_years: u16::MAX.into(),
+            age_range: None,
             size: Size::Medium {
                 height: 72,
                 weight: 200,
@@ -228,6 +401,14 @@ mod test {
             species: Species::Human.into(),
             ethnicity: Ethnicity::Human.into(),
             location_uuid: None.into(),
+            occupation: Occupation::Scholar.into(),
+            personality: vec![PersonalityTrait::Wise, PersonalityTrait::Secretive].into(),
+            alignment: Alignment::NeutralGood.into(),
+            draconic_ancestry: None.into(),
+            quirk: None.into(),
+            relationships: None.into(),
+            notes: None.into(),
+            tags: None.into(),
         }
     }
 
@@ -243,10 +424,19 @@ mod test {
                 gender: Field::Locked(None),
                 age: Field::Locked(None),
                 age_years: Field::Locked(None),
+                age_range: None,
                 size: Field::Locked(None),
                 species: Field::Locked(None),
                 ethnicity: Field::Locked(None),
                 location_uuid: Field::Locked(None),
+                occupation: Field::Locked(None),
+                personality: Field::Locked(None),
+                alignment: Field::Locked(None),
+                draconic_ancestry: Field::Locked(None),
+                quirk: Field::Locked(None),
+                relationships: Field::Locked(None),
+                notes: Field::Locked(None),
+                tags: Field::Locked(None),
             },
             npc,
         );
@@ -0,0 +1,109 @@
+use super::Age;
+use crate::world::weighted_index_from_tuple;
+use initiative_macros::WordList;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, WordList, Serialize, Deserialize)]
+#[serde(into = "&'static str", try_from = "&str")]
+pub enum Occupation {
+    Baker,
+    Blacksmith,
+    Brewer,
+    Butcher,
+    Carpenter,
+    Cook,
+    Farmer,
+    Fisher,
+    Guard,
+    Healer,
+    Hunter,
+    Innkeeper,
+    Laborer,
+    Mason,
+    Merchant,
+    Miner,
+    Priest,
+    Sailor,
+    Scholar,
+    Servant,
+    Soldier,
+    Tailor,
+    Weaver,
+}
+
+impl Occupation {
+    pub fn generate(rng: &mut impl Rng, age: &Age) -> Option<Self> {
+        match age {
+            Age::Infant | Age::Child => None,
+            _ => Some(*weighted_index_from_tuple(
+                rng,
+                &[
+                    (Self::Baker, 3),
+                    (Self::Blacksmith, 2),
+                    (Self::Brewer, 2),
+                    (Self::Butcher, 2),
+                    (Self::Carpenter, 3),
+                    (Self::Cook, 3),
+                    (Self::Farmer, 20),
+                    (Self::Fisher, 5),
+                    (Self::Guard, 4),
+                    (Self::Healer, 1),
+                    (Self::Hunter, 3),
+                    (Self::Innkeeper, 1),
+                    (Self::Laborer, 10),
+                    (Self::Mason, 2),
+                    (Self::Merchant, 4),
+                    (Self::Miner, 3),
+                    (Self::Priest, 2),
+                    (Self::Sailor, 3),
+                    (Self::Scholar, 1),
+                    (Self::Servant, 5),
+                    (Self::Soldier, 4),
+                    (Self::Tailor, 2),
+                    (Self::Weaver, 2),
+                ][..],
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Occupation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn generate_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(None, Occupation::generate(&mut rng, &Age::Infant));
+        assert_eq!(None, Occupation::generate(&mut rng, &Age::Child));
+
+        assert!((0..100).all(|_| Occupation::generate(&mut rng, &Age::Adult).is_some()));
+    }
+
+    #[test]
+    fn fmt_test() {
+        assert_eq!("blacksmith", format!("{}", Occupation::Blacksmith));
+        assert_eq!("innkeeper", format!("{}", Occupation::Innkeeper));
+    }
+
+    #[test]
+    fn serialize_deserialize_test() {
+        assert_eq!(
+            "\"farmer\"",
+            serde_json::to_string(&Occupation::Farmer).unwrap(),
+        );
+
+        let value: Occupation = serde_json::from_str("\"farmer\"").unwrap();
+        assert_eq!(Occupation::Farmer, value);
+    }
+}
@@ -0,0 +1,96 @@
+use initiative_macros::WordList;
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, WordList, Serialize, Deserialize)]
+#[serde(into = "&'static str", try_from = "&str")]
+pub enum PersonalityTrait {
+    Arrogant,
+    Blunt,
+    Brave,
+    Cheerful,
+    Curious,
+    Cynical,
+    Fair,
+    Friendly,
+    Generous,
+    Greedy,
+    Gruff,
+    Honest,
+    Impatient,
+    Kind,
+    Lazy,
+    Loyal,
+    Nervous,
+    Pious,
+    Proud,
+    Quiet,
+    Rude,
+    Secretive,
+    Superstitious,
+    Suspicious,
+    Talkative,
+    Vain,
+    Wise,
+    Witty,
+}
+
+impl PersonalityTrait {
+    /// Generate one or two distinct traits.
+    pub fn generate(rng: &mut impl Rng) -> Vec<Self> {
+        let words: Vec<_> = Self::get_words().collect();
+        let count = if rng.gen_bool(0.5) { 1 } else { 2 };
+
+        words
+            .choose_multiple(rng, count)
+            .map(|word| word.parse().unwrap())
+            .collect()
+    }
+}
+
+impl fmt::Display for PersonalityTrait {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn generate_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut counts = HashSet::new();
+
+        for _ in 0..100 {
+            let traits = PersonalityTrait::generate(&mut rng);
+            counts.insert(traits.len());
+
+            if let [first, second] = traits[..] {
+                assert_ne!(first, second);
+            }
+        }
+
+        assert_eq!(HashSet::from([1, 2]), counts);
+    }
+
+    #[test]
+    fn fmt_test() {
+        assert_eq!("gruff", format!("{}", PersonalityTrait::Gruff));
+        assert_eq!("superstitious", PersonalityTrait::Superstitious.to_string());
+    }
+
+    #[test]
+    fn serialize_deserialize_test() {
+        assert_eq!(
+            "\"talkative\"",
+            serde_json::to_string(&PersonalityTrait::Talkative).unwrap(),
+        );
+
+        let value: PersonalityTrait = serde_json::from_str("\"talkative\"").unwrap();
+        assert_eq!(PersonalityTrait::Talkative, value);
+    }
+}
@@ -0,0 +1,114 @@
+use super::Gender;
+use initiative_macros::WordList;
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A habit or mannerism that makes a character memorable at the table, independent of their
+/// personality.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, WordList, Serialize, Deserialize)]
+#[serde(into = "&'static str", try_from = "&str")]
+pub enum Quirk {
+    Bargainer,
+    CoinFlipper,
+    DoodleDrawer,
+    Eavesdropper,
+    Fidgeter,
+    Hummer,
+    Mispronouncer,
+    NailBiter,
+    NameForgetter,
+    Namedropper,
+    Proverbial,
+    Sniffer,
+    SnackHoarder,
+    ThirdPerson,
+    Whistler,
+    WordRepeater,
+}
+
+impl Quirk {
+    pub fn generate(rng: &mut impl Rng) -> Self {
+        *Self::variants().collect::<Vec<_>>().choose(rng).unwrap()
+    }
+
+    /// Describe the quirk as a verb phrase with a subject of the given gender, eg. "hums
+    /// tunelessly while working" or "hum tunelessly while working".
+    pub fn describe(&self, gender: &Gender) -> String {
+        let (singular, plural, rest) = self.parts();
+        format!("{} {}", gender.conjugate(singular, plural), rest)
+    }
+
+    fn parts(&self) -> (&'static str, &'static str, &'static str) {
+        match self {
+            Self::Bargainer => ("haggles", "haggle", "over everything, even gifts"),
+            Self::CoinFlipper => ("flips", "flip", "a coin before making any decision"),
+            Self::DoodleDrawer => ("doodles", "doodle", "on any scrap of paper within reach"),
+            Self::Eavesdropper => (
+                "leans",
+                "lean",
+                "in to listen to other people's conversations",
+            ),
+            Self::Fidgeter => ("fidgets", "fidget", "constantly with a ring"),
+            Self::Hummer => ("hums", "hum", "tunelessly while working"),
+            Self::Mispronouncer => (
+                "mispronounces",
+                "mispronounce",
+                "long words with confidence",
+            ),
+            Self::NailBiter => ("bites", "bite", "fingernails when nervous"),
+            Self::NameForgetter => ("calls", "call", "everyone by the wrong name"),
+            Self::Namedropper => ("mentions", "mention", "famous acquaintances at every turn"),
+            Self::Proverbial => ("answers", "answer", "questions with old proverbs"),
+            Self::Sniffer => ("sniffs", "sniff", "food and drink before tasting it"),
+            Self::SnackHoarder => ("keeps", "keep", "pockets full of nuts and dried fruit"),
+            Self::ThirdPerson => ("speaks", "speak", "only in the third person"),
+            Self::Whistler => ("whistles", "whistle", "softly when deep in thought"),
+            Self::WordRepeater => ("repeats", "repeat", "the last word of every question asked"),
+        }
+    }
+}
+
+impl fmt::Display for Quirk {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.describe(&Gender::Neuter))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn generate_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let quirks: HashSet<Quirk> = (0..500).map(|_| Quirk::generate(&mut rng)).collect();
+
+        assert_eq!(Quirk::word_count(), quirks.len());
+    }
+
+    #[test]
+    fn describe_test() {
+        assert_eq!(
+            "hums tunelessly while working",
+            Quirk::Hummer.describe(&Gender::Feminine),
+        );
+        assert_eq!(
+            "hum tunelessly while working",
+            Quirk::Hummer.describe(&Gender::NonBinaryThey),
+        );
+        assert_eq!("hums tunelessly while working", Quirk::Hummer.to_string());
+    }
+
+    #[test]
+    fn serialize_deserialize_test() {
+        assert_eq!(
+            "\"coin-flipper\"",
+            serde_json::to_string(&Quirk::CoinFlipper).unwrap(),
+        );
+
+        let value: Quirk = serde_json::from_str("\"coin-flipper\"").unwrap();
+        assert_eq!(Quirk::CoinFlipper, value);
+    }
+}
@@ -0,0 +1,128 @@
+use super::{Gender, Uuid};
+use initiative_macros::WordList;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A relationship from one character to another. The other character stores the inverse, which
+/// the repository keeps in step.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Relationship {
+    pub kind: RelationshipKind,
+    pub uuid: Uuid,
+}
+
+/// The kind of relationship, read as "[this character] is the [kind] of [the other character]".
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize, WordList)]
+#[serde(into = "&'static str", try_from = "&str")]
+pub enum RelationshipKind {
+    #[alias = "brother"]
+    #[alias = "sister"]
+    Sibling,
+
+    #[alias = "father"]
+    #[alias = "mother"]
+    Parent,
+
+    #[alias = "son"]
+    #[alias = "daughter"]
+    Child,
+
+    #[alias = "husband"]
+    #[alias = "wife"]
+    Spouse,
+
+    Cousin,
+    Friend,
+    Rival,
+}
+
+impl RelationshipKind {
+    /// The same relationship seen from the other character, eg. a parent's child.
+    pub fn inverse(&self) -> Self {
+        match self {
+            Self::Parent => Self::Child,
+            Self::Child => Self::Parent,
+            kind => *kind,
+        }
+    }
+
+    /// The word for a character of the given gender in this relationship, eg. "sister".
+    pub fn noun(&self, gender: &Gender) -> &'static str {
+        match (self, gender) {
+            (Self::Sibling, Gender::Feminine) => "sister",
+            (Self::Sibling, Gender::Masculine) => "brother",
+            (Self::Parent, Gender::Feminine) => "mother",
+            (Self::Parent, Gender::Masculine) => "father",
+            (Self::Child, Gender::Feminine) => "daughter",
+            (Self::Child, Gender::Masculine) => "son",
+            (Self::Spouse, Gender::Feminine) => "wife",
+            (Self::Spouse, Gender::Masculine) => "husband",
+            (kind, _) => kind.as_str(),
+        }
+    }
+}
+
+impl fmt::Display for RelationshipKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_str_test() {
+        assert_eq!(Ok(RelationshipKind::Sibling), "brother".parse());
+        assert_eq!(Ok(RelationshipKind::Sibling), "Sister".parse());
+        assert_eq!(Ok(RelationshipKind::Child), "daughter".parse());
+        assert_eq!(Ok(RelationshipKind::Friend), "friend".parse());
+        assert_eq!(Err(()), "nemesis".parse::<RelationshipKind>());
+    }
+
+    #[test]
+    fn inverse_test() {
+        assert_eq!(RelationshipKind::Child, RelationshipKind::Parent.inverse());
+        assert_eq!(RelationshipKind::Parent, RelationshipKind::Child.inverse());
+        assert_eq!(
+            RelationshipKind::Sibling,
+            RelationshipKind::Sibling.inverse()
+        );
+        assert_eq!(RelationshipKind::Rival, RelationshipKind::Rival.inverse());
+    }
+
+    #[test]
+    fn noun_test() {
+        assert_eq!(
+            "brother",
+            RelationshipKind::Sibling.noun(&Gender::Masculine)
+        );
+        assert_eq!("mother", RelationshipKind::Parent.noun(&Gender::Feminine));
+        assert_eq!(
+            "child",
+            RelationshipKind::Child.noun(&Gender::NonBinaryThey)
+        );
+        assert_eq!("spouse", RelationshipKind::Spouse.noun(&Gender::Neuter));
+        assert_eq!("friend", RelationshipKind::Friend.noun(&Gender::Feminine));
+    }
+
+    #[test]
+    fn serialize_deserialize_test() {
+        let relationship = Relationship {
+            kind: RelationshipKind::Sibling,
+            uuid: uuid::Uuid::nil().into(),
+        };
+
+        assert_eq!(
+            r#"{"kind":"sibling","uuid":"00000000-0000-0000-0000-000000000000"}"#,
+            serde_json::to_string(&relationship).unwrap(),
+        );
+
+        let value: Relationship = serde_json::from_str(
+            r#"{"kind":"sibling","uuid":"00000000-0000-0000-0000-000000000000"}"#,
+        )
+        .unwrap();
+        assert_eq!(relationship, value);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::world::Units;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -43,20 +44,24 @@ impl Size {
     }
 }
 
-impl fmt::Display for Size {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (height_ft, height_in) = self.height_ft_in();
+impl Size {
+    pub fn fmt_with_units(&self, units: Units, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "{}'{}\", {} lbs ({})",
-            height_ft,
-            height_in,
-            self.weight(),
+            "{}, {} ({})",
+            units.length(self.height()),
+            units.weight(self.weight()),
             self.name(),
         )
     }
 }
 
+impl fmt::Display for Size {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with_units(Units::Imperial, f)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -122,6 +127,19 @@ mod test {
         assert_eq!("5'11\", 140 lbs (medium)", format!("{}", size()));
     }
 
+    #[test]
+    fn fmt_with_units_test() {
+        struct Metric(Size);
+
+        impl fmt::Display for Metric {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.0.fmt_with_units(Units::Metric, f)
+            }
+        }
+
+        assert_eq!("180 cm, 64 kg (medium)", Metric(size()).to_string());
+    }
+
     #[test]
     fn serialize_deserialize_test() {
         assert_eq!(
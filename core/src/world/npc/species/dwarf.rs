@@ -4,6 +4,8 @@ use rand::prelude::*;
 pub struct Species;
 
 impl Generate for Species {
+    const MAX_AGE_YEARS: u16 = 400;
+
     fn gen_gender(rng: &mut impl Rng) -> Gender {
         match rng.gen_range(1..=101) {
             1..=50 => Gender::Feminine,
@@ -13,17 +15,13 @@ impl Generate for Species {
         }
     }
 
-    fn gen_age_years(rng: &mut impl Rng) -> u16 {
-        rng.gen_range(0..=400)
-    }
-
     fn gen_years_from_age(rng: &mut impl Rng, age: &Age) -> u16 {
         rng.gen_range(match age {
             Age::Infant => 0..=1,
             Age::Child => 2..=9,
             Age::Adolescent => 10..=19,
-            Age::YoungAdult => 20..=39,
-            Age::Adult => 40..=199,
+            Age::YoungAdult => 20..=49,
+            Age::Adult => 50..=199,
             Age::MiddleAged => 200..=299,
             Age::Elderly => 300..=349,
             Age::Geriatric => 350..=400,
@@ -35,7 +33,7 @@ impl Generate for Species {
             i if i < 2 => Age::Infant,
             i if i < 10 => Age::Child,
             i if i < 20 => Age::Adolescent,
-            i if i < 40 => Age::YoungAdult,
+            i if i < 50 => Age::YoungAdult,
             i if i < 200 => Age::Adult,
             i if i < 300 => Age::MiddleAged,
             i if i < 350 => Age::Elderly,
@@ -124,9 +122,10 @@ mod test_generate_for_species {
         assert_eq!(Age::Adolescent, Species::age_from_years(19));
 
         assert_eq!(Age::YoungAdult, Species::age_from_years(20));
-        assert_eq!(Age::YoungAdult, Species::age_from_years(39));
+        assert_eq!(Age::YoungAdult, Species::age_from_years(40));
+        assert_eq!(Age::YoungAdult, Species::age_from_years(49));
 
-        assert_eq!(Age::Adult, Species::age_from_years(40));
+        assert_eq!(Age::Adult, Species::age_from_years(50));
         assert_eq!(Age::Adult, Species::age_from_years(199));
 
         assert_eq!(Age::MiddleAged, Species::age_from_years(200));
@@ -4,6 +4,8 @@ use rand::prelude::*;
 pub struct Species;
 
 impl Generate for Species {
+    const MAX_AGE_YEARS: u16 = 800;
+
     fn gen_gender(rng: &mut impl Rng) -> Gender {
         match rng.gen_range(1..=101) {
             1..=50 => Gender::Feminine,
@@ -13,10 +15,6 @@ impl Generate for Species {
         }
     }
 
-    fn gen_age_years(rng: &mut impl Rng) -> u16 {
-        rng.gen_range(0..=800)
-    }
-
     fn gen_years_from_age(rng: &mut impl Rng, age: &Age) -> u16 {
         rng.gen_range(match age {
             Age::Infant => 0..=1,
@@ -5,14 +5,12 @@ use rand::prelude::*;
 pub struct Species;
 
 impl Generate for Species {
+    const MAX_AGE_YEARS: u16 = 200;
+
     fn gen_gender(rng: &mut impl Rng) -> Gender {
         Human::gen_gender(rng)
     }
 
-    fn gen_age_years(rng: &mut impl Rng) -> u16 {
-        rng.gen_range(0..=200)
-    }
-
     fn gen_years_from_age(rng: &mut impl Rng, age: &Age) -> u16 {
         rng.gen_range(match age {
             Age::Infant => 0..=1,
@@ -1,9 +1,12 @@
 use super::{Age, Gender, Generate, Size};
 use rand::prelude::*;
+use std::ops::RangeInclusive;
 
 pub struct Species;
 
 impl Generate for Species {
+    const MAX_AGE_YEARS: u16 = 79;
+
     fn gen_gender(rng: &mut impl Rng) -> Gender {
         match rng.gen_range(1..=101) {
             1..=50 => Gender::Feminine,
@@ -13,10 +16,6 @@ impl Generate for Species {
         }
     }
 
-    fn gen_age_years(rng: &mut impl Rng) -> u16 {
-        rng.gen_range(0..=79)
-    }
-
     fn gen_years_from_age(rng: &mut impl Rng, age: &Age) -> u16 {
         rng.gen_range(match age {
             Age::Infant => 0..=1,
@@ -44,63 +43,72 @@ impl Generate for Species {
     }
 
     fn gen_size(rng: &mut impl Rng, age_years: u16, gender: &Gender) -> Size {
+        // Neither masculine nor feminine characters get a build halfway between the two.
         let is_female = match gender {
-            Gender::Masculine => rng.gen_bool(0.01),
-            Gender::Feminine => rng.gen_bool(0.99),
-            _ => rng.gen_bool(0.5),
+            Gender::Masculine => Some(rng.gen_bool(0.01)),
+            Gender::Feminine => Some(rng.gen_bool(0.99)),
+            _ => None,
         };
 
-        match (age_years, is_female) {
-            (0, _) => {
+        match age_years {
+            0 => {
                 let size = rng.gen_range(0..=30);
                 Size::Tiny {
                     height: 20 + size / 3,
                     weight: 7 + size / 2,
                 }
             }
-            (1, _) => {
+            1 => {
                 let size = rng.gen_range(0..=5);
                 Size::Tiny {
                     height: 30 + size,
                     weight: 22 + size,
                 }
             }
-            (2..=9, _) => {
+            2..=9 => {
                 let y = (age_years - 2) as f32 / 8.;
                 let (height, weight) =
                     super::gen_height_weight(rng, (33. + y * 18.)..=(35. + y * 22.), 14.0..=17.0);
                 Size::Small { height, weight }
             }
-            (10..=19, true) => {
-                let y = (age_years - 10) as f32;
-                let (height, weight) = super::gen_height_weight(
-                    rng,
-                    (51. + y * 2.).min(61.)..=(65. + y * 2.).min(67.),
-                    (15. + y * 2.5 / 5.).min(18.5)..=(19. + y * 4.5 / 5.).min(25.),
-                );
-                Size::Medium { height, weight }
-            }
-            (10..=19, false) => {
-                let y = (age_years - 10) as f32 / 5.;
-                let (height, weight) = super::gen_height_weight(
-                    rng,
-                    (51. + y * 12.).min(66.)..=(57. + y * 13.).min(72.),
-                    (15. + y * 2.5).min(18.5)..=(18.5 + y * 4.5).min(29.),
-                );
-                Size::Medium { height, weight }
-            }
-            (_, true) => {
-                let (height, weight) = super::gen_height_weight(rng, 61.0..=67.0, 19.0..=25.0);
-                Size::Medium { height, weight }
-            }
-            (_, false) => {
-                let (height, weight) = super::gen_height_weight(rng, 66.0..=72.0, 18.5..=29.0);
+            _ => {
+                let ranges = |is_female| match (age_years, is_female) {
+                    (10..=19, true) => {
+                        let y = (age_years - 10) as f32;
+                        (
+                            (51. + y * 2.).min(61.)..=(65. + y * 2.).min(67.),
+                            (15. + y * 2.5 / 5.).min(18.5)..=(19. + y * 4.5 / 5.).min(25.),
+                        )
+                    }
+                    (10..=19, false) => {
+                        let y = (age_years - 10) as f32 / 5.;
+                        (
+                            (51. + y * 12.).min(66.)..=(57. + y * 13.).min(72.),
+                            (15. + y * 2.5).min(18.5)..=(18.5 + y * 4.5).min(29.),
+                        )
+                    }
+                    (_, true) => (61.0..=67.0, 19.0..=25.0),
+                    (_, false) => (66.0..=72.0, 18.5..=29.0),
+                };
+
+                let (height_range, bmi_range) = if let Some(is_female) = is_female {
+                    ranges(is_female)
+                } else {
+                    let ((f_height, f_bmi), (m_height, m_bmi)) = (ranges(true), ranges(false));
+                    (midpoint(f_height, m_height), midpoint(f_bmi, m_bmi))
+                };
+
+                let (height, weight) = super::gen_height_weight(rng, height_range, bmi_range);
                 Size::Medium { height, weight }
             }
         }
     }
 }
 
+fn midpoint(a: RangeInclusive<f32>, b: RangeInclusive<f32>) -> RangeInclusive<f32> {
+    (a.start() + b.start()) / 2.0..=(a.end() + b.end()) / 2.0
+}
+
 #[cfg(test)]
 mod test_generate_for_species {
     use super::*;
@@ -263,4 +271,19 @@ mod test_generate_for_species {
                 .collect::<Vec<_>>(),
         );
     }
+
+    #[test]
+    fn gen_size_non_binary_test() {
+        let mut genders = [Gender::Feminine, Gender::Masculine, Gender::NonBinaryThey]
+            .map(|gender| (gender, SmallRng::seed_from_u64(0), 0u32));
+
+        for _ in 0..100 {
+            for (gender, rng, total_height) in genders.iter_mut() {
+                *total_height += u32::from(Species::gen_size(rng, 30, gender).height());
+            }
+        }
+
+        let [(_, _, f), (_, _, m), (_, _, t)] = genders;
+        assert!(f < t && t < m, "{} < {} < {}", f, t, m);
+    }
 }
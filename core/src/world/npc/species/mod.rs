@@ -37,10 +37,15 @@ pub enum Species {
 trait Generate {
     fn regenerate(rng: &mut impl Rng, npc: &mut Npc) {
         npc.gender.replace_with(|_| Self::gen_gender(rng));
+        let age_range = npc.age_range.take();
 
         match (npc.age.is_locked(), npc.age_years.is_locked()) {
             (false, false) => {
-                let age_years = Self::gen_age_years(rng);
+                let age_years = if let Some((min, max)) = age_range {
+                    rng.gen_range(min..=max)
+                } else {
+                    Self::gen_age_years(rng)
+                };
                 npc.age_years.replace(age_years);
                 npc.age.replace_with(|_| Self::age_from_years(age_years));
             }
@@ -69,7 +74,12 @@ trait Generate {
 
     fn gen_gender(rng: &mut impl Rng) -> Gender;
 
-    fn gen_age_years(rng: &mut impl Rng) -> u16;
+    /// The oldest age in years that this species is generated with.
+    const MAX_AGE_YEARS: u16;
+
+    fn gen_age_years(rng: &mut impl Rng) -> u16 {
+        rng.gen_range(0..=Self::MAX_AGE_YEARS)
+    }
 
     fn gen_years_from_age(rng: &mut impl Rng, age: &Age) -> u16;
 
@@ -117,6 +127,37 @@ fn gen_height_weight(
 }
 
 impl Species {
+    /// Age categories vary by species: a 120-year-old elf is a young adult, while a 120-year-old
+    /// human is geriatric.
+    pub fn age_from_years(&self, years: u16) -> Age {
+        match self {
+            Self::Dragonborn => dragonborn::Species::age_from_years(years),
+            Self::Dwarf => dwarf::Species::age_from_years(years),
+            Self::Elf => elf::Species::age_from_years(years),
+            Self::Gnome => gnome::Species::age_from_years(years),
+            Self::HalfElf => half_elf::Species::age_from_years(years),
+            Self::HalfOrc => half_orc::Species::age_from_years(years),
+            Self::Halfling => halfling::Species::age_from_years(years),
+            Self::Human => human::Species::age_from_years(years),
+            Self::Tiefling => tiefling::Species::age_from_years(years),
+        }
+    }
+
+    /// How long members of the species live, at most, in years.
+    pub fn max_age_years(&self) -> u16 {
+        match self {
+            Self::Dragonborn => dragonborn::Species::MAX_AGE_YEARS,
+            Self::Dwarf => dwarf::Species::MAX_AGE_YEARS,
+            Self::Elf => elf::Species::MAX_AGE_YEARS,
+            Self::Gnome => gnome::Species::MAX_AGE_YEARS,
+            Self::HalfElf => half_elf::Species::MAX_AGE_YEARS,
+            Self::HalfOrc => half_orc::Species::MAX_AGE_YEARS,
+            Self::Halfling => halfling::Species::MAX_AGE_YEARS,
+            Self::Human => human::Species::MAX_AGE_YEARS,
+            Self::Tiefling => tiefling::Species::MAX_AGE_YEARS,
+        }
+    }
+
     pub fn default_ethnicity(&self) -> Ethnicity {
         match self {
             Self::Dragonborn => Ethnicity::Dragonborn,
@@ -130,6 +171,21 @@ impl Species {
             Self::Tiefling => Ethnicity::Tiefling,
         }
     }
+
+    /// The error for a word that was meant as a species but isn't one, listing those that are.
+    pub fn not_recognized(word: &str) -> String {
+        let mut species: Vec<_> = Self::get_words()
+            .filter_map(|word| word.parse::<Self>().ok())
+            .map(|species| species.to_string())
+            .collect();
+        species.dedup();
+
+        format!(
+            "\"{}\" is not a recognized species. Try one of: {}.",
+            word,
+            species.join(", "),
+        )
+    }
 }
 
 impl fmt::Display for Species {
@@ -167,6 +223,55 @@ mod test {
         assert!(npc.size.is_some());
     }
 
+    #[test]
+    fn regenerate_test_age_range() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        for _ in 0..20 {
+            let mut npc = Npc {
+                species: Species::Human.into(),
+                age_range: Some((20, 40)),
+                ..Default::default()
+            };
+
+            regenerate(&mut rng, &mut npc);
+            assert!((20..=40).contains(npc.age_years.value().unwrap()));
+            assert_eq!(None, npc.age_range);
+        }
+    }
+
+    #[test]
+    fn max_age_years_test() {
+        assert_eq!(79, Species::Human.max_age_years());
+        assert_eq!(800, Species::Elf.max_age_years());
+    }
+
+    #[test]
+    fn age_from_years_test() {
+        assert_eq!(Age::Geriatric, Species::Human.age_from_years(120));
+        assert_eq!(Age::YoungAdult, Species::Elf.age_from_years(120));
+    }
+
+    #[test]
+    fn regenerate_test_non_binary() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        for species in Species::get_words().filter_map(|word| word.parse::<Species>().ok()) {
+            let mut npc = Npc {
+                species: species.into(),
+                gender: Gender::NonBinaryThey.into(),
+                ..Default::default()
+            };
+
+            regenerate(&mut rng, &mut npc);
+            assert!(npc.size.is_some(), "{}", species);
+
+            npc.display_summary().to_string();
+            npc.display_prose().to_string();
+            npc.display_details(Default::default()).to_string();
+        }
+    }
+
     #[test]
     fn regenerate_test_locked() {
         let mut npc = Npc::default();
@@ -1,16 +1,36 @@
-use super::{Age, Gender, Npc, NpcRelations};
+use super::{Age, Gender, Npc, NpcRelations, Species};
+use crate::utils::indefinite_article;
+use crate::world::{write_notes, write_tags, Units};
 use std::fmt;
 
 pub struct SummaryView<'a>(&'a Npc);
 
 pub struct DescriptionView<'a>(&'a Npc);
 
+pub struct ProseView<'a> {
+    npc: &'a Npc,
+    units: Units,
+}
+
+struct AgeSpeciesView<'a>(&'a Npc);
+
 pub struct DetailsView<'a> {
     npc: &'a Npc,
     relations: NpcRelations,
+    units: Units,
 }
 
 fn write_summary_details(npc: &Npc, f: &mut fmt::Formatter) -> fmt::Result {
+    write_age_species(npc, f)?;
+
+    if let Some(gender) = npc.gender.value() {
+        write!(f, ", {}", gender.pronouns())?;
+    }
+
+    Ok(())
+}
+
+fn write_age_species(npc: &Npc, f: &mut fmt::Formatter) -> fmt::Result {
     if let Some(age) = npc.age.value() {
         age.fmt_with_species_ethnicity(npc.species.value(), npc.ethnicity.value(), f)?;
     } else if let Some(species) = npc.species.value() {
@@ -21,13 +41,21 @@ fn write_summary_details(npc: &Npc, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "person")?;
     }
 
-    if let Some(gender) = npc.gender.value() {
-        write!(f, ", {}", gender.pronouns())?;
-    }
-
     Ok(())
 }
 
+fn write_list<T: fmt::Display>(items: &[T], f: &mut fmt::Formatter) -> fmt::Result {
+    items.iter().enumerate().try_for_each(|(i, item)| {
+        if i == 0 {
+            write!(f, "{}", item)
+        } else if i == items.len() - 1 {
+            write!(f, " and {}", item)
+        } else {
+            write!(f, ", {}", item)
+        }
+    })
+}
+
 impl<'a> SummaryView<'a> {
     pub fn new(npc: &'a Npc) -> Self {
         Self(npc)
@@ -40,9 +68,32 @@ impl<'a> DescriptionView<'a> {
     }
 }
 
+impl<'a> ProseView<'a> {
+    pub fn new(npc: &'a Npc) -> Self {
+        Self {
+            npc,
+            units: Units::default(),
+        }
+    }
+
+    pub fn with_units(mut self, units: Units) -> Self {
+        self.units = units;
+        self
+    }
+}
+
 impl<'a> DetailsView<'a> {
     pub fn new(npc: &'a Npc, relations: NpcRelations) -> Self {
-        Self { npc, relations }
+        Self {
+            npc,
+            relations,
+            units: Units::default(),
+        }
+    }
+
+    pub fn with_units(mut self, units: Units) -> Self {
+        self.units = units;
+        self
     }
 }
 
@@ -93,9 +144,98 @@ impl<'a> fmt::Display for DescriptionView<'a> {
     }
 }
 
+impl<'a> fmt::Display for ProseView<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Self { npc, units } = self;
+        let age_species = AgeSpeciesView(npc).to_string();
+
+        if let Some(name) = npc.name.value() {
+            write!(f, "{} is ", name)?;
+        } else {
+            write!(f, "This is ")?;
+        }
+        write!(f, "{} {}", indefinite_article(&age_species), age_species)?;
+
+        if let Some(size) = npc.size.value() {
+            write!(
+                f,
+                ", {} tall and weighing {}",
+                units.length(size.height()),
+                units.weight(size.weight()),
+            )?;
+        }
+        write!(f, ".")?;
+
+        // Fields that are missing are left out entirely rather than described as unknown. An
+        // unspecified gender is referred to as "they".
+        let gender = npc.gender();
+        let (subject, is, works) = (
+            gender.they_cap(),
+            gender.conjugate("is", "are"),
+            gender.conjugate("works", "work"),
+        );
+
+        match (npc.age_years.value(), npc.occupation.value()) {
+            (Some(age_years), Some(occupation)) => write!(
+                f,
+                " {} {} {} years old and {} as {} {}.",
+                subject,
+                is,
+                age_years,
+                works,
+                indefinite_article(occupation.as_str()),
+                occupation,
+            )?,
+            (Some(age_years), None) => write!(f, " {} {} {} years old.", subject, is, age_years)?,
+            (None, Some(occupation)) => write!(
+                f,
+                " {} {} as {} {}.",
+                subject,
+                works,
+                indefinite_article(occupation.as_str()),
+                occupation,
+            )?,
+            (None, None) => {}
+        }
+
+        let adjectives: Vec<String> = npc
+            .personality
+            .value()
+            .into_iter()
+            .flatten()
+            .map(|personality_trait| personality_trait.to_string())
+            .chain(npc.alignment.value().map(|alignment| alignment.to_string()))
+            .collect();
+
+        if !adjectives.is_empty() {
+            write!(f, " {} {} ", subject, is)?;
+            write_list(&adjectives, f)?;
+            write!(f, ".")?;
+        }
+
+        if let Some(quirk) = npc.quirk.value() {
+            write!(f, " {} {}.", subject, quirk.describe(&gender))?;
+        }
+
+        write_notes(&npc.notes, f)?;
+
+        Ok(())
+    }
+}
+
+impl<'a> fmt::Display for AgeSpeciesView<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_age_species(self.0, f)
+    }
+}
+
 impl<'a> fmt::Display for DetailsView<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let Self { npc, relations } = self;
+        let Self {
+            npc,
+            relations,
+            units,
+        } = self;
 
         writeln!(f, "<div class=\"thing-box npc\">\n")?;
 
@@ -117,6 +257,13 @@ impl<'a> fmt::Display for DetailsView<'a> {
             (None, None) => write!(f, "\n\n**Species:** N/A")?,
         }
 
+        if npc.species.value() == Some(&Species::Dragonborn) {
+            npc.draconic_ancestry
+                .value()
+                .map(|ancestry| write!(f, "\\\n**Draconic ancestry:** {}", ancestry))
+                .transpose()?;
+        }
+
         npc.gender
             .value()
             .map(|gender| write!(f, "\\\n**Gender:** {}", gender.name()))
@@ -127,7 +274,30 @@ impl<'a> fmt::Display for DetailsView<'a> {
             .transpose()?;
         npc.size
             .value()
-            .map(|size| write!(f, "\\\n**Size:** {}", size))
+            .map(|size| {
+                write!(f, "\\\n**Size:** ")?;
+                size.fmt_with_units(*units, f)
+            })
+            .transpose()?;
+        npc.alignment
+            .value()
+            .map(|alignment| write!(f, "\\\n**Alignment:** {}", alignment))
+            .transpose()?;
+        npc.occupation
+            .value()
+            .map(|occupation| write!(f, "\\\n**Occupation:** {}", occupation))
+            .transpose()?;
+        npc.personality
+            .value()
+            .filter(|traits| !traits.is_empty())
+            .map(|traits| {
+                write!(f, "\\\n**Personality:** ")?;
+                write_list(traits, f)
+            })
+            .transpose()?;
+        npc.quirk
+            .value()
+            .map(|quirk| write!(f, "\\\n**Quirk:** {}", quirk))
             .transpose()?;
 
         relations
@@ -147,6 +317,21 @@ impl<'a> fmt::Display for DetailsView<'a> {
             })
             .transpose()?;
 
+        if !relations.relationships.is_empty() {
+            let gender = npc.gender();
+            let relationships: Vec<String> = relations
+                .relationships
+                .iter()
+                .map(|(kind, other)| format!("{} of `{}`", kind.noun(&gender), other.name))
+                .collect();
+
+            write!(f, "\\\n**Relationships:** ")?;
+            write_list(&relationships, f)?;
+        }
+
+        write_tags(&npc.tags, f)?;
+        write_notes(&npc.notes, f)?;
+
         write!(f, "\n\n</div>")?;
 
         Ok(())
@@ -156,7 +341,10 @@ impl<'a> fmt::Display for DetailsView<'a> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::world::npc::{Age, Ethnicity, Gender, Size, Species};
+    use crate::world::npc::{
+        Age, Alignment, DraconicAncestry, Ethnicity, Gender, Occupation, PersonalityTrait, Quirk,
+        RelationshipKind, Size, Species,
+    };
     use crate::world::place::{Place, PlaceType};
     use crate::world::Field;
 
@@ -215,6 +403,56 @@ mod test {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn prose_view_test_filled() {
+        let mut npc = Npc::default();
+        npc.name.replace("Potato Johnson".to_string());
+        npc.species.replace(Species::Human);
+        npc.gender.replace(Gender::Feminine);
+        npc.age.replace(Age::MiddleAged);
+        npc.age_years.replace(45);
+        npc.size.replace(Size::Medium {
+            height: 71,
+            weight: 140,
+        });
+        npc.alignment.replace(Alignment::ChaoticGood);
+        npc.occupation.replace(Occupation::Innkeeper);
+        npc.personality
+            .replace(vec![PersonalityTrait::Nervous, PersonalityTrait::Talkative]);
+
+        assert_eq!(
+            "Potato Johnson is a middle-aged human, 5'11\" tall and weighing 140 lbs. She is 45 years old and works as an innkeeper. She is nervous, talkative and chaotic good.",
+            npc.display_prose().to_string(),
+        );
+
+        npc.gender.replace(Gender::NonBinaryThey);
+        npc.age_years.clear();
+        assert_eq!(
+            "Potato Johnson is a middle-aged human, 5'11\" tall and weighing 140 lbs. They work as an innkeeper. They are nervous, talkative and chaotic good.",
+            npc.display_prose().to_string(),
+        );
+    }
+
+    #[test]
+    fn prose_view_test_partial() {
+        assert_eq!(
+            "This is a person.",
+            Npc::default().display_prose().to_string()
+        );
+        assert_eq!(
+            "Potato Johnson is an elderly human. They are 60 years old.",
+            gen_npc(NAME | AGE | SPECIES).display_prose().to_string(),
+        );
+        assert_eq!(
+            "This is an elvish person.",
+            gen_npc(ETHNICITY).display_prose().to_string(),
+        );
+        assert_eq!(
+            "This is an elderly person. He is 60 years old.",
+            gen_npc(AGE | GENDER).display_prose().to_string(),
+        );
+    }
+
     #[test]
     fn details_view_test_filled() {
         let mut npc = Npc::default();
@@ -228,6 +466,10 @@ mod test {
             height: 71,
             weight: 140,
         });
+        npc.alignment.replace(Alignment::ChaoticGood);
+        npc.occupation.replace(Occupation::Innkeeper);
+        npc.personality
+            .replace(vec![PersonalityTrait::Nervous, PersonalityTrait::Talkative]);
 
         assert_eq!(
             r#"<div class="thing-box npc">
@@ -238,7 +480,10 @@ mod test {
 **Species:** human (elvish)\
 **Gender:** non-binary\
 **Age:** 30 years\
-**Size:** 5'11", 140 lbs (medium)
+**Size:** 5'11", 140 lbs (medium)\
+**Alignment:** chaotic good\
+**Occupation:** innkeeper\
+**Personality:** nervous and talkative
 
 </div>"#,
             format!("{}", npc.display_details(NpcRelations::default()))
@@ -291,6 +536,54 @@ mod test {
         );
     }
 
+    #[test]
+    fn details_view_test_draconic_ancestry() {
+        let mut npc = Npc::default();
+        npc.species.replace(Species::Dragonborn);
+        npc.draconic_ancestry.replace(DraconicAncestry::Brass);
+
+        assert_eq!(
+            r#"<div class="thing-box npc">
+
+# Unnamed NPC
+*dragonborn*
+
+**Species:** dragonborn\
+**Draconic ancestry:** brass dragon (fire)
+
+</div>"#,
+            format!("{}", npc.display_details(NpcRelations::default())),
+        );
+
+        // Ancestry left over from a change of species isn't shown.
+        npc.species.replace(Species::Human);
+        assert!(!format!("{}", npc.display_details(NpcRelations::default())).contains("ancestry"));
+    }
+
+    #[test]
+    fn quirk_test() {
+        let mut npc = Npc::default();
+        npc.name.replace("Potato Johnson".to_string());
+        npc.quirk.replace(Quirk::Hummer);
+
+        assert_eq!(
+            "Potato Johnson is a person. They hum tunelessly while working.",
+            npc.display_prose().to_string(),
+        );
+
+        npc.gender.replace(Gender::Masculine);
+        assert_eq!(
+            "Potato Johnson is a person. He hums tunelessly while working.",
+            npc.display_prose().to_string(),
+        );
+        let details = npc.display_details(NpcRelations::default()).to_string();
+        assert!(
+            details.contains("\\\n**Quirk:** hums tunelessly while working"),
+            "{}",
+            details,
+        );
+    }
+
     #[test]
     fn details_view_test_empty() {
         assert_eq!(
@@ -325,6 +618,7 @@ mod test {
                 },
                 None,
             )),
+            ..Default::default()
         };
 
         assert_eq!(
@@ -336,6 +630,49 @@ mod test {
 **Species:** N/A\\
 **Location:** ⛰ `Mount Doom` (mountain)
 
+</div>",
+            format!("{}", DetailsView::new(&npc, relations)),
+        );
+    }
+
+    #[test]
+    fn details_view_test_with_relationships() {
+        let npc = Npc {
+            name: "Gundren Rockseeker".into(),
+            gender: Gender::Masculine.into(),
+            ..Default::default()
+        };
+
+        let relations = NpcRelations {
+            relationships: vec![
+                (
+                    RelationshipKind::Sibling,
+                    Npc {
+                        name: "Tharden Rockseeker".into(),
+                        ..Default::default()
+                    },
+                ),
+                (
+                    RelationshipKind::Friend,
+                    Npc {
+                        name: "Sildar Hallwinter".into(),
+                        ..Default::default()
+                    },
+                ),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            "<div class=\"thing-box npc\">
+
+# Gundren Rockseeker
+*person, he/him*
+
+**Species:** N/A\\
+**Gender:** masculine\\
+**Relationships:** brother of `Tharden Rockseeker` and friend of `Sildar Hallwinter`
+
 </div>",
             format!("{}", DetailsView::new(&npc, relations)),
         );
@@ -361,6 +698,7 @@ mod test {
                     ..Default::default()
                 }),
             )),
+            ..Default::default()
         };
 
         assert_eq!(
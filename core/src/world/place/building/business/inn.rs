@@ -4,6 +4,15 @@ use rand::prelude::*;
 
 pub fn generate(place: &mut Place, rng: &mut impl Rng, _demographics: &Demographics) {
     place.name.replace_with(|_| name(rng));
+    place.description.replace_with(|_| description(rng));
+}
+
+fn description(rng: &mut impl Rng) -> String {
+    format!(
+        "A {} establishment known for its {}.",
+        atmosphere(rng),
+        specialty_drink(rng),
+    )
 }
 
 fn name(rng: &mut impl Rng) -> String {
@@ -74,10 +83,46 @@ fn number(rng: &mut impl Rng) -> &'static str {
     ListGenerator(&["Three", "Five", "Seven", "Ten"]).gen(rng)
 }
 
+#[rustfmt::skip]
+fn atmosphere(rng: &mut impl Rng) -> &'static str {
+    ListGenerator(&[
+        "bustling", "cozy", "dimly lit", "quiet", "rowdy", "run-down", "smoky", "upscale",
+        "welcoming",
+    ]).gen(rng)
+}
+
+#[rustfmt::skip]
+fn specialty_drink(rng: &mut impl Rng) -> &'static str {
+    ListGenerator(&[
+        "blackberry wine", "dark stout", "dwarven ale", "elderflower cordial", "honeyed mead",
+        "house brandy", "mulled wine", "pale ale", "plum brandy", "spiced cider",
+        "sweet sherry", "watered-down beer",
+    ]).gen(rng)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn description_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            [
+                "A rowdy establishment known for its house brandy.",
+                "A rowdy establishment known for its watered-down beer.",
+                "A run-down establishment known for its house brandy.",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>(),
+            (0..3)
+                .map(|_| description(&mut rng))
+                .collect::<Vec<String>>(),
+        );
+    }
+
     #[test]
     fn name_test() {
         let mut rng = SmallRng::seed_from_u64(0);
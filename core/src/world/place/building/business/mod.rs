@@ -1,4 +1,5 @@
 mod inn;
+mod shop;
 
 use super::BuildingType;
 use crate::world::place::{Place, PlaceType};
@@ -109,9 +110,23 @@ impl BusinessType {
 pub fn generate(place: &mut Place, rng: &mut impl Rng, demographics: &Demographics) {
     #[allow(clippy::collapsible_match)]
     if let Some(PlaceType::Building(BuildingType::Business(subtype))) = place.subtype.value() {
-        #[allow(clippy::single_match)]
         match subtype {
+            BusinessType::Armorer => shop::generate(place, rng, &[("armor", 1)]),
+            BusinessType::Blacksmith => {
+                shop::generate(place, rng, &[("weapon", 3), ("armor", 2), ("tools", 1)])
+            }
+            BusinessType::GeneralStore | BusinessType::TradingPost => shop::generate(
+                place,
+                rng,
+                &[
+                    ("adventuring-gear", 10),
+                    ("tools", 3),
+                    ("weapon", 1),
+                    ("armor", 1),
+                ],
+            ),
             BusinessType::Inn => inn::generate(place, rng, demographics),
+            BusinessType::Weaponsmith => shop::generate(place, rng, &[("weapon", 1)]),
             _ => {}
         }
     }
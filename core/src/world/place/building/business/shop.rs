@@ -0,0 +1,101 @@
+use crate::reference::Item;
+use crate::world::Place;
+use rand::prelude::*;
+
+/// Stock the shop with a handful of SRD items. Each entry of `stock` is an SRD equipment category
+/// and its relative weight. A category is picked before the item, so large categories like
+/// weapons don't crowd out everything else.
+pub fn generate(place: &mut Place, rng: &mut impl Rng, stock: &[(&str, usize)]) {
+    let inventory = inventory(rng, stock);
+
+    if !inventory.is_empty() {
+        place
+            .description
+            .replace_with(|_| display_inventory(&inventory));
+    }
+}
+
+fn inventory(rng: &mut impl Rng, stock: &[(&str, usize)]) -> Vec<Item> {
+    let categories: Vec<(Vec<Item>, usize)> = stock
+        .iter()
+        .map(|(category_index, weight)| {
+            (
+                Item::get_all()
+                    .filter(|item| item.get_category_index() == *category_index)
+                    .collect::<Vec<_>>(),
+                *weight,
+            )
+        })
+        .filter(|(items, _)| !items.is_empty())
+        .collect();
+
+    let available = categories.iter().map(|(items, _)| items.len()).sum();
+    let count = rng.gen_range(4..=8).min(available);
+    let mut inventory = Vec::with_capacity(count);
+
+    while inventory.len() < count {
+        let (items, _) = categories
+            .choose_weighted(rng, |(_, weight)| *weight)
+            .unwrap();
+        let item = items.choose(rng).unwrap();
+
+        if !inventory.contains(item) {
+            inventory.push(item.clone());
+        }
+    }
+
+    inventory.sort_by_key(|item| item.get_name());
+    inventory
+}
+
+fn display_inventory(inventory: &[Item]) -> String {
+    let mut output = "**Inventory:**".to_string();
+
+    inventory.iter().enumerate().for_each(|(i, item)| {
+        if i > 0 {
+            output.push('\\');
+        }
+        output.push_str(&format!("\n`{}` ({})", item.get_name(), item.get_cost()));
+    });
+
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn inventory_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        for _ in 0..10 {
+            let inventory = inventory(&mut rng, &[("weapon", 1)]);
+
+            assert!(!inventory.is_empty());
+            assert!(inventory
+                .iter()
+                .all(|item| item.get_category_index() == "weapon"));
+            assert!(inventory
+                .windows(2)
+                .all(|pair| pair[0].get_name() < pair[1].get_name()));
+        }
+
+        assert!(inventory(&mut rng, &[("potato", 1)]).is_empty());
+    }
+
+    #[test]
+    fn display_inventory_test() {
+        assert_eq!("**Inventory:**", display_inventory(&[]));
+
+        let item: Item = Item::get_all().next().unwrap();
+        assert_eq!(
+            format!(
+                "**Inventory:**\n`{0}` ({1})\\\n`{0}` ({1})",
+                item.get_name(),
+                item.get_cost(),
+            ),
+            display_inventory(&[item.clone(), item]),
+        );
+    }
+}
@@ -38,14 +38,14 @@ impl GeographicalType {
     pub const fn get_emoji(&self) -> Option<&'static str> {
         match self {
             Self::Beach => Some("🏖"),
-            Self::Canyon | Self::Chasm | Self::River | Self::Valley => Some("🏞"),
+            Self::Canyon | Self::Chasm | Self::Rift | Self::River | Self::Valley => Some("🏞"),
+            Self::Cave => Some("🦇"),
             Self::Glacier => Some("🏔"),
             Self::Grove | Self::Tree => Some("🌳"),
             Self::Hill | Self::Pass | Self::Ridge => Some("⛰"),
             Self::Island | Self::Peninsula => Some("🏝"),
             Self::Monolith => Some("🗿"),
             Self::Oasis => Some("🌴"),
-            Self::Cave | Self::Rift => None,
         }
     }
 }
@@ -60,3 +60,15 @@ pub fn generate(place: &mut Place, rng: &mut impl Rng, demographics: &Demographi
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn get_emoji_test() {
+        GeographicalType::variants().for_each(|variant| {
+            assert!(variant.get_emoji().is_some(), "{:?} has no emoji", variant);
+        });
+    }
+}
@@ -35,9 +35,9 @@ impl LocationType {
 pub fn generate(place: &mut Place, rng: &mut impl Rng, demographics: &Demographics) {
     #[allow(clippy::collapsible_match)]
     if let Some(PlaceType::Location(subtype)) = place.subtype.value() {
-        #[allow(clippy::single_match)]
         match subtype {
             LocationType::Geographical(_) => geographical::generate(place, rng, demographics),
+            LocationType::Settlement(_) => settlement::generate(place, rng, demographics),
             _ => {}
         }
     }
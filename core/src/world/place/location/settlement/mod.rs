@@ -1,6 +1,13 @@
+mod town;
+
 use initiative_macros::WordList;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
+use crate::world::{place::PlaceType, Demographics, Place};
+
+use super::LocationType;
+
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
 #[serde(into = "&'static str", try_from = "&str")]
 pub enum SettlementType {
@@ -30,3 +37,14 @@ impl SettlementType {
         }
     }
 }
+
+pub fn generate(place: &mut Place, rng: &mut impl Rng, demographics: &Demographics) {
+    #[allow(clippy::collapsible_match)]
+    if let Some(PlaceType::Location(LocationType::Settlement(subtype))) = place.subtype.value() {
+        #[allow(clippy::single_match)]
+        match subtype {
+            SettlementType::Town => town::generate(place, rng, demographics),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::world::{word, word::ListGenerator, Demographics, Place};
+use rand::prelude::*;
+
+pub fn generate(place: &mut Place, rng: &mut impl Rng, _demographics: &Demographics) {
+    place.name.replace_with(|_| name(rng));
+}
+
+fn name(rng: &mut impl Rng) -> String {
+    match rng.gen_range(0..6) {
+        0..=3 => format!("{}{}", root(rng), suffix(rng)),
+        4 => format!(
+            "{} {}{}",
+            word::cardinal_direction(rng),
+            root(rng),
+            suffix(rng)
+        ),
+        5 => format!("{}{} {}", root(rng), suffix(rng), crossing(rng)),
+        _ => unreachable!(),
+    }
+}
+
+fn root(rng: &mut impl Rng) -> &'static str {
+    ListGenerator(&[
+        "Alder", "Ash", "Black", "Briar", "Bright", "Cold", "Deep", "Elm", "Fox", "Frost", "Green",
+        "Hazel", "High", "Iron", "Long", "Marsh", "Mill", "Oak", "Raven", "Red", "Rose", "Stone",
+        "Thorn", "White", "Willow", "Wolf",
+    ])
+    .gen(rng)
+}
+
+fn suffix(rng: &mut impl Rng) -> &'static str {
+    ListGenerator(&[
+        "bridge", "brook", "bury", "dale", "field", "ford", "haven", "hollow", "mere", "moor",
+        "stead", "ton", "vale", "well", "wick", "wood",
+    ])
+    .gen(rng)
+}
+
+fn crossing(rng: &mut impl Rng) -> &'static str {
+    ListGenerator(&["Crossing", "Cross", "Green", "Landing"]).gen(rng)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn name_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            [
+                "Hazelhollow",
+                "Willowmoor Landing",
+                "Wolfstead",
+                "Deepmoor",
+                "Deephaven",
+                "Wolfwell Landing",
+                "Willowbridge",
+                "West Briarhollow",
+                "South Foxwell",
+                "Blackdale",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>(),
+            (0..10).map(|_| name(&mut rng)).collect::<Vec<String>>(),
+        );
+    }
+}
@@ -6,6 +6,7 @@ mod region;
 mod view;
 
 use super::{Demographics, Field, Generate};
+use crate::utils::CaseInsensitiveStr;
 use initiative_macros::WordList;
 use rand::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -21,6 +22,18 @@ pub struct Place {
 
     pub name: Field<String>,
     pub description: Field<String>,
+
+    /// Population breakdown used when generating characters located here.
+    #[serde(default)]
+    pub demographics: Field<Demographics>,
+
+    /// Freeform notes added by the user, one per line.
+    #[serde(default)]
+    pub notes: Field<String>,
+
+    /// Freeform labels added by the user, normalized to lowercase with no spaces.
+    #[serde(default)]
+    pub tags: Field<Vec<String>>,
     // pub architecture: Option<String>,
     // pub floors: Field<u8>,
     // pub owner: Field<Vec<NpcUuid>>,
@@ -76,12 +89,18 @@ impl Place {
             subtype,
             name,
             description,
+            demographics,
+            notes,
+            tags,
         } = self;
 
         location_uuid.lock();
         subtype.lock();
         name.lock();
         description.lock();
+        demographics.lock();
+        notes.lock();
+        tags.lock();
     }
 
     pub fn apply_diff(&mut self, diff: &mut Self) {
@@ -91,12 +110,18 @@ impl Place {
             subtype,
             name,
             description,
+            demographics,
+            notes,
+            tags,
         } = self;
 
         location_uuid.apply_diff(&mut diff.location_uuid);
         subtype.apply_diff(&mut diff.subtype);
         name.apply_diff(&mut diff.name);
         description.apply_diff(&mut diff.description);
+        demographics.apply_diff(&mut diff.demographics);
+        notes.apply_diff(&mut diff.notes);
+        tags.apply_diff(&mut diff.tags);
     }
 }
 
@@ -113,6 +138,7 @@ impl Generate for Place {
             match value {
                 PlaceType::Building(_) => building::generate(self, rng, demographics),
                 PlaceType::Location(_) => location::generate(self, rng, demographics),
+                PlaceType::Region(_) => region::generate(self, rng, demographics),
                 _ => {}
             }
         }
@@ -132,6 +158,35 @@ impl PlaceType {
             "📍"
         }
     }
+
+    pub const fn is_location(&self) -> bool {
+        matches!(self, Self::Location(_))
+    }
+
+    pub const fn is_region(&self) -> bool {
+        matches!(self, Self::Region(_))
+    }
+
+    /// Whether this describes the terrain of a region, eg. a forest or a mountain range.
+    pub const fn is_geography(&self) -> bool {
+        matches!(self, Self::Region(region::RegionType::Geography(_)))
+    }
+
+    /// Whether a word for a place type names several of something (eg. "islands"), and so
+    /// doesn't take "a" or "an".
+    pub fn is_plural_word(word: &str) -> bool {
+        word.in_ci(&[
+            "badlands",
+            "dunes",
+            "highlands",
+            "islands",
+            "moors",
+            "mountains",
+            "plains",
+            "wastes",
+            "woods",
+        ])
+    }
 }
 
 impl Default for PlaceType {
@@ -178,6 +233,62 @@ mod test {
         );
     }
 
+    #[test]
+    fn variants_test() {
+        // Aliases are words but not variants.
+        assert!(PlaceType::variants().count() < PlaceType::word_count());
+        assert!(PlaceType::variants().all(|variant| variant
+            .as_str()
+            .parse::<PlaceType>()
+            .is_ok_and(|parsed| parsed.as_str() == variant.as_str())));
+        assert!(PlaceType::variants().any(|variant| variant.as_str() == "inn"));
+    }
+
+    #[test]
+    fn is_geography_test() {
+        assert!("forest".parse::<PlaceType>().unwrap().is_geography());
+        assert!("mountains".parse::<PlaceType>().unwrap().is_geography());
+        assert!(!"kingdom".parse::<PlaceType>().unwrap().is_geography());
+        assert!(!"region".parse::<PlaceType>().unwrap().is_geography());
+        assert!(!"inn".parse::<PlaceType>().unwrap().is_geography());
+    }
+
+    #[test]
+    fn is_plural_word_test() {
+        assert!(PlaceType::is_plural_word("islands"));
+        assert!(PlaceType::is_plural_word("Mountains"));
+        assert!(!PlaceType::is_plural_word("island"));
+        assert!(!PlaceType::is_plural_word("business"));
+
+        // Every plural word is also a place type.
+        [
+            "badlands",
+            "dunes",
+            "highlands",
+            "islands",
+            "moors",
+            "mountains",
+            "plains",
+            "wastes",
+            "woods",
+        ]
+        .iter()
+        .for_each(|word| assert!(word.parse::<PlaceType>().is_ok(), "{}", word));
+    }
+
+    #[test]
+    fn is_location_is_region_test() {
+        let [town, kingdom, inn]: [PlaceType; 3] =
+            ["town", "kingdom", "inn"].map(|s| s.parse().unwrap());
+
+        assert!(town.is_location());
+        assert!(!town.is_region());
+        assert!(!kingdom.is_location());
+        assert!(kingdom.is_region());
+        assert!(!inn.is_location());
+        assert!(!inn.is_region());
+    }
+
     #[test]
     fn default_test() {
         assert_eq!(PlaceType::Any, PlaceType::default());
@@ -224,13 +335,20 @@ mod test {
         let place = oaken_mermaid_inn();
 
         assert_eq!(
-            r#"{"uuid":"00000000-0000-0000-0000-000000000000","location_uuid":"00000000-0000-0000-0000-000000000000","subtype":"inn","name":"Oaken Mermaid Inn","description":"I am Mordenkainen"}"#,
+            r#"{"uuid":"00000000-0000-0000-0000-000000000000","location_uuid":"00000000-0000-0000-0000-000000000000","subtype":"inn","name":"Oaken Mermaid Inn","description":"I am Mordenkainen","demographics":null,"notes":null,"tags":null}"#,
             serde_json::to_string(&place).unwrap(),
         );
 
-        let value: Place = serde_json::from_str(r#"{"uuid":"00000000-0000-0000-0000-000000000000","location_uuid":"00000000-0000-0000-0000-000000000000","subtype":"inn","name":"Oaken Mermaid Inn","description":"I am Mordenkainen"}"#).unwrap();
+        let value: Place = serde_json::from_str(r#"{"uuid":"00000000-0000-0000-0000-000000000000","location_uuid":"00000000-0000-0000-0000-000000000000","subtype":"inn","name":"Oaken Mermaid Inn","description":"I am Mordenkainen","demographics":null,"notes":null,"tags":null}"#).unwrap();
 
         assert_eq!(place, value);
+
+        // Places saved before demographics were introduced.
+        let value: Place = serde_json::from_str(r#"{"uuid":"00000000-0000-0000-0000-000000000000","location_uuid":"00000000-0000-0000-0000-000000000000","subtype":"inn","name":"Oaken Mermaid Inn","description":"I am Mordenkainen"}"#).unwrap();
+
+        assert_eq!(place.name, value.name);
+        assert!(value.demographics.is_none());
+        assert!(value.notes.is_none());
     }
 
     #[test]
@@ -273,6 +391,9 @@ mod test {
                 subtype: Field::Locked(None),
                 name: Field::Locked(None),
                 description: Field::Locked(None),
+                demographics: Field::Locked(None),
+                notes: Field::Locked(None),
+                tags: Field::Locked(None),
             },
             place,
         );
@@ -296,6 +417,7 @@ mod test {
             ("archipelago", "🏝"),
             ("arena", "🏛"),
             ("armorer", "🛡"),
+            ("badlands", "🏜"),
             ("bakery", "🍞"),
             ("bank", "🏦"),
             ("bar", "🍻"),
@@ -306,10 +428,12 @@ mod test {
             ("bathhouse", "🛁"),
             ("beach", "🏖"),
             ("blacksmith", "🗡"),
+            ("bog", "🐸"),
             ("brewery", "🍻"),
             ("bridge", "🌉"),
             ("building", "📍"),
             ("business", "🪙"),
+            ("butte", "🏜"),
             ("camp", "🏕"),
             ("campsite", "🏕"),
             ("canyon", "🏞"),
@@ -317,8 +441,8 @@ mod test {
             ("caravansary", "🏨"),
             ("casino", "🃏"),
             ("castle", "🏰"),
-            ("cave", "📍"),
-            ("cavern", "📍"),
+            ("cave", "🦇"),
+            ("cavern", "🦇"),
             ("cemetery", "🪦"),
             ("chasm", "🏞"),
             ("church", "🙏"),
@@ -326,10 +450,11 @@ mod test {
             ("city", "🏙"),
             ("city-state", "👑"),
             ("club", ""),
+            ("coast", "🌊"),
             ("coastline", "🌊"),
             ("college", "🎓"),
             ("confederation", "👑"),
-            ("continent", "📍"),
+            ("continent", "🗺"),
             ("country", "👑"),
             ("county", "👑"),
             ("court", "🏰"),
@@ -339,10 +464,12 @@ mod test {
             ("district", "🏘"),
             ("domain", "👑"),
             ("duchy", "👑"),
+            ("dunes", "🏜"),
             ("duty-house", "🪙"),
             ("embassy", "🚩"),
             ("empire", "👑"),
             ("farm", "🌱"),
+            ("fen", "🐸"),
             ("ferry", "⛴"),
             ("fighting-pit", "⚔"),
             ("food-counter", "🍲"),
@@ -359,19 +486,23 @@ mod test {
             ("general-store", "🪙"),
             ("glacier", "🏔"),
             ("gorge", "🏞"),
+            ("grassland", "🌾"),
             ("graveyard", "🪦"),
             ("grove", "🌳"),
             ("guardhouse", "🛡"),
             ("guild-hall", "🪙"),
             ("hamlet", "🏘"),
             ("harbor", "⛵"),
+            ("heath", "🌫"),
             ("hermitage", "🙏"),
+            ("highlands", "⛰"),
             ("hill", "⛰"),
             ("hotel", "🏨"),
             ("house", "🏠"),
             ("imports-shop", "🪙"),
             ("inn", "🏨"),
             ("island", "🏝"),
+            ("islands", "🏝"),
             ("jail", "🛡"),
             ("jeweller", "💍"),
             ("jungle", "🌳"),
@@ -387,18 +518,21 @@ mod test {
             ("manor", "🏠"),
             ("mansion", "🏠"),
             ("market", "🪙"),
-            ("marsh", "📍"),
+            ("marsh", "🐸"),
             ("mausoleum", "🪦"),
-            ("mesa", "📍"),
+            ("mesa", "🏜"),
             ("metropolis", "🏙"),
             ("mill", "🌾"),
             ("mine", "⚒"),
             ("monastery", "🙏"),
             ("monolith", "🗿"),
             ("monument", "🗽"),
-            ("moor", "📍"),
+            ("moor", "🌫"),
+            ("moors", "🌫"),
             ("mosque", "🙏"),
             ("mountain", "⛰"),
+            ("mountain-range", "⛰"),
+            ("mountains", "⛰"),
             ("nation", "👑"),
             ("necropolis", "🪦"),
             ("neighborhood", "🏘"),
@@ -414,38 +548,43 @@ mod test {
             ("pet-store", "🐶"),
             ("pier", "⛵"),
             ("place", "📍"),
-            ("plain", "📍"),
-            ("plateau", "📍"),
+            ("plain", "🌾"),
+            ("plains", "🌾"),
+            ("plateau", "⛰"),
             ("portal", "📍"),
+            ("prairie", "🌾"),
             ("principality", "👑"),
             ("prison", "🛡"),
             ("province", "👑"),
             ("pub", "🍻"),
             ("quarter", "🏘"),
+            ("rainforest", "🌳"),
             ("realm", "👑"),
-            ("reef", "📍"),
+            ("reef", "🐠"),
             ("region", "👑"),
             ("region", "👑"),
             ("residence", "🏠"),
             ("restaurant", "🍽"),
             ("ridge", "⛰"),
-            ("rift", "📍"),
+            ("rift", "🏞"),
             ("river", "🏞"),
             ("ruin", "🏚"),
             ("school", "🎓"),
             ("sea", "🌊"),
             ("shipyard", "⛵"),
             ("shop", "🪙"),
+            ("shore", "🌊"),
             ("shrine", "🙏"),
             ("smithy", "🗡"),
             ("specialty-shop", "🪙"),
             ("spirits-shop", "🥃"),
             ("stable", "🐎"),
             ("statue", "🗽"),
+            ("steppe", "🌾"),
             ("store", "🪙"),
             ("street", "🏘"),
             ("stronghold", "🏰"),
-            ("swamp", "📍"),
+            ("swamp", "🐸"),
             ("synagogue", "🙏"),
             ("tavern", "🏨"),
             ("temple", "🙏"),
@@ -468,8 +607,12 @@ mod test {
             ("ward", "🏘"),
             ("warehouse", "📦"),
             ("wasteland", "🏜"),
+            ("wastes", "🏜"),
             ("watch-house", "🛡"),
             ("weaponsmith", "🗡"),
+            ("wetland", "🐸"),
+            ("woodland", "🌳"),
+            ("woods", "🌳"),
             ("woodshop", "🪚"),
             ("world", "🌐"),
         ]
@@ -495,6 +638,9 @@ mod test {
 
             name: "Oaken Mermaid Inn".into(),
             description: "I am Mordenkainen".into(),
+            demographics: None.into(),
+            notes: None.into(),
+            tags: None.into(),
         }
     }
 }
@@ -1,28 +1,55 @@
 use initiative_macros::WordList;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
+use crate::world::{place::PlaceType, word::ListGenerator, Demographics, Place};
+
+use super::RegionType;
+
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
 #[serde(into = "&'static str", try_from = "&str")]
 pub enum GeographyType {
+    #[alias = "islands"]
     Archipelago,
+    #[alias = "badlands"]
     Barrens,
+    #[alias = "coast"]
+    #[alias = "shore"]
     Coastline,
     Continent,
+    #[alias = "dunes"]
     Desert,
+    #[alias = "woods"]
+    #[alias = "woodland"]
     Forest,
+    #[alias = "rainforest"]
     Jungle,
     Lake,
+    #[alias = "fen"]
+    #[alias = "wetland"]
     Marsh,
+    #[alias = "butte"]
     Mesa,
+    #[alias = "heath"]
+    #[alias = "moors"]
     Moor,
+    #[alias = "mountains"]
+    #[alias = "mountain-range"]
     Mountain,
     Ocean,
+    #[alias = "grassland"]
+    #[alias = "plains"]
+    #[alias = "prairie"]
+    #[alias = "steppe"]
     Plain,
+    #[alias = "highlands"]
     Plateau,
     Reef,
     Sea,
+    #[alias = "bog"]
     Swamp,
     Tundra,
+    #[alias = "wastes"]
     Wasteland,
     World,
 }
@@ -31,20 +58,243 @@ impl GeographyType {
     pub const fn get_emoji(&self) -> Option<&'static str> {
         match self {
             Self::Archipelago => Some("🏝"),
-            Self::Barrens | Self::Desert | Self::Wasteland => Some("🏜"),
+            Self::Barrens | Self::Desert | Self::Mesa | Self::Wasteland => Some("🏜"),
             Self::Coastline | Self::Lake | Self::Sea | Self::Ocean => Some("🌊"),
+            Self::Continent => Some("🗺"),
             Self::Forest | Self::Jungle => Some("🌳"),
-            Self::Mountain => Some("⛰"),
+            Self::Marsh | Self::Swamp => Some("🐸"),
+            Self::Moor => Some("🌫"),
+            Self::Mountain | Self::Plateau => Some("⛰"),
+            Self::Plain => Some("🌾"),
+            Self::Reef => Some("🐠"),
             Self::Tundra => Some("❄"),
             Self::World => Some("🌐"),
-            Self::Continent
-            | Self::Marsh
-            | Self::Mesa
-            | Self::Moor
-            | Self::Plain
-            | Self::Plateau
-            | Self::Reef
-            | Self::Swamp => None,
         }
     }
 }
+
+/// The pieces that names of a particular type of terrain are built from. Roots and suffixes are
+/// joined into a single word (Gloom + wood), while terms stand alone (The Lost Woods).
+struct NameWords {
+    roots: &'static [&'static str],
+    suffixes: &'static [&'static str],
+    terms: &'static [&'static str],
+}
+
+pub fn generate(place: &mut Place, rng: &mut impl Rng, _demographics: &Demographics) {
+    if let Some(PlaceType::Region(RegionType::Geography(subtype))) = place.subtype.value().copied()
+    {
+        place.name.replace_with(|_| name(subtype, rng));
+    }
+}
+
+fn name(subtype: GeographyType, rng: &mut impl Rng) -> String {
+    let words = name_words(subtype);
+
+    // Continents and worlds are too big to be called "the something".
+    match rng.gen_range(0..if words.terms.is_empty() { 4 } else { 7 }) {
+        0..=3 => compound(&words, rng),
+        4 => format!(
+            "{} {}",
+            compound(&words, rng),
+            ListGenerator(words.terms).gen(rng),
+        ),
+        5 | 6 => format!(
+            "The {} {}",
+            adjective(rng),
+            ListGenerator(words.terms).gen(rng),
+        ),
+        _ => unreachable!(),
+    }
+}
+
+fn compound(words: &NameWords, rng: &mut impl Rng) -> String {
+    format!(
+        "{}{}",
+        ListGenerator(words.roots).gen(rng),
+        ListGenerator(words.suffixes).gen(rng),
+    )
+}
+
+#[rustfmt::skip]
+fn adjective(rng: &mut impl Rng) -> &'static str {
+    ListGenerator(&[
+        "Broken", "Endless", "Forsaken", "Grey", "Haunted", "Hidden", "Lonely", "Lost",
+        "Shattered", "Silent", "Sunken", "Whispering", "Wild",
+    ]).gen(rng)
+}
+
+#[rustfmt::skip]
+fn name_words(subtype: GeographyType) -> NameWords {
+    match subtype {
+        GeographyType::Archipelago => NameWords {
+            roots: &["Cinder", "Gull", "Mist", "Pearl", "Scatter", "Seal", "Sun", "Thousand"],
+            suffixes: &["holm", "isle", "keys", "rock", "skerry"],
+            terms: &["Archipelago", "Isles", "Islands"],
+        },
+        GeographyType::Barrens | GeographyType::Wasteland => NameWords {
+            roots: &["Ash", "Blight", "Bone", "Cinder", "Dread", "Dust", "Grim", "Salt"],
+            suffixes: &["march", "reach", "scar", "waste"],
+            terms: &["Badlands", "Barrens", "Wastes"],
+        },
+        GeographyType::Coastline => NameWords {
+            roots: &["Drift", "Foam", "Grey", "Gull", "Salt", "Shell", "Storm", "Wave"],
+            suffixes: &["coast", "reach", "shore", "strand"],
+            terms: &["Coast", "Shore", "Strand"],
+        },
+        GeographyType::Continent | GeographyType::World => NameWords {
+            roots: &["Aer", "Eld", "Kal", "Myr", "Orn", "Tor", "Thal", "Val", "Zan"],
+            suffixes: &["ara", "eth", "ia", "on", "oria", "os", "un"],
+            terms: &[],
+        },
+        GeographyType::Desert => NameWords {
+            roots: &["Amber", "Bone", "Dust", "Red", "Sand", "Scorch", "Sun", "Thirst"],
+            suffixes: &["dunes", "reach", "sands", "waste"],
+            terms: &["Desert", "Dunes", "Expanse", "Sands"],
+        },
+        GeographyType::Forest => NameWords {
+            roots: &[
+                "Ash", "Briar", "Dark", "Elder", "Gloom", "Green", "Moss", "Oak", "Raven",
+                "Shadow", "Thorn", "Whisper",
+            ],
+            suffixes: &["grove", "holt", "shade", "weald", "wood"],
+            terms: &["Forest", "Weald", "Woods"],
+        },
+        GeographyType::Jungle => NameWords {
+            roots: &["Emerald", "Jade", "Mist", "Orchid", "Rain", "Serpent", "Tiger", "Vine"],
+            suffixes: &["canopy", "tangle", "thicket", "wild"],
+            terms: &["Jungle", "Tangle", "Wilds"],
+        },
+        GeographyType::Lake => NameWords {
+            roots: &["Cold", "Deep", "Glimmer", "Mirror", "Misty", "Moon", "Silver", "Still"],
+            suffixes: &["loch", "mere", "pool", "water"],
+            terms: &["Lake", "Mere", "Waters"],
+        },
+        GeographyType::Marsh | GeographyType::Swamp => NameWords {
+            roots: &["Black", "Fog", "Gloom", "Mire", "Murk", "Reed", "Rot", "Toad"],
+            suffixes: &["bog", "fen", "marsh", "mire", "moss"],
+            terms: &["Bog", "Fens", "Marsh", "Mire", "Swamp"],
+        },
+        GeographyType::Mesa => NameWords {
+            roots: &["Copper", "Eagle", "Hawk", "Red", "Rust", "Sun"],
+            suffixes: &["butte", "rock", "table", "top"],
+            terms: &["Buttes", "Mesa", "Tablelands"],
+        },
+        GeographyType::Moor => NameWords {
+            roots: &["Barrow", "Crow", "Grey", "Heather", "Lonely", "Mist", "Wind"],
+            suffixes: &["down", "fell", "heath", "moor"],
+            terms: &["Heath", "Moor", "Moors"],
+        },
+        GeographyType::Mountain => NameWords {
+            roots: &[
+                "Cloud", "Dragon", "Eagle", "Frost", "Giant", "Grey", "Iron", "Sky", "Stone",
+                "Storm", "Thunder", "Winter",
+            ],
+            suffixes: &["crag", "fell", "horn", "peak", "spire", "tor"],
+            terms: &["Heights", "Mountains", "Peaks", "Range"],
+        },
+        GeographyType::Ocean | GeographyType::Sea => NameWords {
+            roots: &["Endless", "Grey", "Salt", "Sapphire", "Serpent", "Storm", "Sunken", "Whale"],
+            suffixes: &["deep", "surge", "tide", "water"],
+            terms: &["Deep", "Ocean", "Sea", "Waters"],
+        },
+        GeographyType::Plain => NameWords {
+            roots: &["Broad", "Gold", "Green", "Horse", "Long", "Sun", "Wheat", "Wind"],
+            suffixes: &["field", "lea", "mead", "plain", "reach"],
+            terms: &["Fields", "Grasslands", "Plains", "Steppe"],
+        },
+        GeographyType::Plateau => NameWords {
+            roots: &["Cloud", "Eagle", "High", "Sky", "Stone", "Wind"],
+            suffixes: &["height", "reach", "table", "top"],
+            terms: &["Heights", "Highlands", "Plateau"],
+        },
+        GeographyType::Reef => NameWords {
+            roots: &["Coral", "Pearl", "Razor", "Shark", "Shell", "Sunken"],
+            suffixes: &["reef", "rocks", "shoal"],
+            terms: &["Reef", "Shallows", "Shoals"],
+        },
+        GeographyType::Tundra => NameWords {
+            roots: &["Bleak", "Frost", "Ice", "Rime", "Snow", "White", "Winter"],
+            suffixes: &["fell", "march", "reach", "waste"],
+            terms: &["Expanse", "Tundra", "Wastes"],
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn name_test() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        assert_eq!(
+            [
+                "Greytor",
+                "The Lost Range",
+                "Wintercrag",
+                "The Grey Mountains",
+                "The Silent Peaks",
+                "Frosttor",
+                "Wintercrag",
+                "The Hidden Range",
+                "Gianttor",
+                "Giantfell",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>(),
+            (0..10)
+                .map(|_| name(GeographyType::Mountain, &mut rng))
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            [
+                "Ashweald Woods",
+                "Briarshade",
+                "Gloomshade Woods",
+                "Briargrove Forest",
+                "Darkshade",
+                "Oakshade Forest",
+                "Ashwood",
+                "The Hidden Weald",
+                "The Wild Woods",
+                "Mossholt",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>(),
+            (0..10)
+                .map(|_| name(GeographyType::Forest, &mut rng))
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn variants_test() {
+        assert_eq!(21, GeographyType::variants().count());
+        assert_eq!(
+            Some(GeographyType::Archipelago),
+            GeographyType::variants().next(),
+        );
+        assert_eq!(Some(GeographyType::World), GeographyType::variants().last());
+    }
+
+    #[test]
+    fn alias_test() {
+        assert_eq!(Ok(GeographyType::Forest), "woods".parse());
+        assert_eq!(Ok(GeographyType::Forest), "Woodland".parse());
+        assert_eq!(Ok(GeographyType::Mountain), "mountains".parse());
+        assert_eq!(Ok(GeographyType::Plain), "plains".parse());
+        assert_eq!("forest", GeographyType::Forest.as_str());
+    }
+
+    #[test]
+    fn get_emoji_test() {
+        GeographyType::variants().for_each(|variant| {
+            assert!(variant.get_emoji().is_some(), "{:?} has no emoji", variant);
+        });
+    }
+}
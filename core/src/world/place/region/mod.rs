@@ -2,8 +2,11 @@ mod geography;
 mod political;
 
 use initiative_macros::WordList;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
+use crate::world::{place::PlaceType, Demographics, Place};
+
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, WordList)]
 #[serde(into = "&'static str", try_from = "&str")]
 pub enum RegionType {
@@ -23,3 +26,14 @@ impl RegionType {
         }
     }
 }
+
+pub fn generate(place: &mut Place, rng: &mut impl Rng, demographics: &Demographics) {
+    #[allow(clippy::collapsible_match)]
+    if let Some(PlaceType::Region(subtype)) = place.subtype.value() {
+        #[allow(clippy::single_match)]
+        match subtype {
+            RegionType::Geography(_) => geography::generate(place, rng, demographics),
+            _ => {}
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use super::{Place, PlaceRelations, PlaceType};
+use crate::world::{write_notes, write_tags};
 use std::fmt;
 
 pub struct NameView<'a>(&'a Place);
@@ -107,12 +108,21 @@ impl<'a> fmt::Display for DetailsView<'a> {
             })
             .transpose()?;
 
+        place
+            .demographics
+            .value()
+            .map(|demographics| write!(f, "\n\n**Population:** {}", demographics))
+            .transpose()?;
+
         place
             .description
             .value()
             .map(|description| write!(f, "\n\n{}", description))
             .transpose()?;
 
+        write_tags(&place.tags, f)?;
+        write_notes(&place.notes, f)?;
+
         write!(f, "\n\n</div>")?;
 
         Ok(())
@@ -1,4 +1,4 @@
-use super::{Demographics, Field, Generate, Npc, NpcRelations, Place, PlaceRelations};
+use super::{Demographics, Field, Generate, Npc, NpcRelations, Place, PlaceRelations, Units};
 use crate::world::command::ParsedThing;
 use crate::world::npc::{DetailsView as NpcDetailsView, Gender};
 use crate::world::place::DetailsView as PlaceDetailsView;
@@ -41,6 +41,20 @@ impl Thing {
         }
     }
 
+    pub fn notes(&self) -> &Field<String> {
+        match self {
+            Thing::Place(place) => &place.notes,
+            Thing::Npc(npc) => &npc.notes,
+        }
+    }
+
+    pub fn tags(&self) -> &Field<Vec<String>> {
+        match self {
+            Thing::Place(place) => &place.tags,
+            Thing::Npc(npc) => &npc.tags,
+        }
+    }
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Thing::Place(..) => "place",
@@ -55,6 +69,13 @@ impl Thing {
         }
     }
 
+    pub fn location_uuid(&self) -> Option<&Uuid> {
+        match self {
+            Thing::Place(place) => place.location_uuid.value().map(|u| u.as_ref()),
+            Thing::Npc(npc) => npc.location_uuid.value().map(|u| u.as_ref()),
+        }
+    }
+
     pub fn set_uuid(&mut self, uuid: Uuid) {
         match self {
             Thing::Place(place) => {
@@ -96,6 +117,7 @@ impl Thing {
         }
     }
 
+    #[allow(clippy::result_large_err)]
     pub fn into_place(self) -> Result<Place, Thing> {
         if let Self::Place(place) = self {
             Ok(place)
@@ -112,6 +134,7 @@ impl Thing {
         }
     }
 
+    #[allow(clippy::result_large_err)]
     pub fn into_npc(self) -> Result<Npc, Thing> {
         if let Self::Npc(npc) = self {
             Ok(npc)
@@ -240,6 +263,16 @@ impl<'a> fmt::Display for DescriptionView<'a> {
     }
 }
 
+impl<'a> DetailsView<'a> {
+    /// Places don't currently display any measurements, so this only affects characters.
+    pub fn with_units(self, units: Units) -> Self {
+        match self {
+            Self::Npc(view) => Self::Npc(view.with_units(units)),
+            Self::Place(view) => Self::Place(view),
+        }
+    }
+}
+
 impl<'a> fmt::Display for DetailsView<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -284,7 +317,7 @@ mod test {
     fn serialize_deserialize_test_place() {
         let thing = place();
         assert_eq!(
-            r#"{"type":"Place","uuid":null,"location_uuid":null,"subtype":null,"name":null,"description":null}"#,
+            r#"{"type":"Place","uuid":null,"location_uuid":null,"subtype":null,"name":null,"description":null,"demographics":null,"notes":null,"tags":null}"#,
             serde_json::to_string(&thing).unwrap(),
         );
     }
@@ -293,7 +326,7 @@ mod test {
     fn serialize_deserialize_test_npc() {
         let thing = npc();
         assert_eq!(
-            r#"{"type":"Npc","uuid":null,"name":null,"gender":null,"age":null,"age_years":null,"size":null,"species":null,"ethnicity":null,"location_uuid":null}"#,
+            r#"{"type":"Npc","uuid":null,"name":null,"gender":null,"age":null,"age_years":null,"size":null,"species":null,"ethnicity":null,"location_uuid":null,"occupation":null,"personality":null,"alignment":null,"draconic_ancestry":null,"quirk":null,"relationships":null,"notes":null,"tags":null}"#,
             serde_json::to_string(&thing).unwrap(),
         );
     }
@@ -0,0 +1,58 @@
+use initiative_macros::WordList;
+use std::fmt;
+
+/// The system of measurement used when displaying sizes. Values are always stored in imperial
+/// units and only converted for display.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, WordList)]
+pub enum Units {
+    #[default]
+    Imperial,
+    Metric,
+}
+
+impl Units {
+    pub fn length(&self, inches: u16) -> String {
+        match self {
+            Self::Imperial => format!("{}'{}\"", inches / 12, inches % 12),
+            Self::Metric => format!("{} cm", (f32::from(inches) * 2.54).round()),
+        }
+    }
+
+    pub fn weight(&self, pounds: u16) -> String {
+        match self {
+            Self::Imperial => format!("{} lbs", pounds),
+            Self::Metric => format!("{} kg", (f32::from(pounds) * 0.453_592).round()),
+        }
+    }
+}
+
+impl fmt::Display for Units {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn length_test() {
+        assert_eq!("5'11\"", Units::Imperial.length(71));
+        assert_eq!("0'4\"", Units::Imperial.length(4));
+        assert_eq!("180 cm", Units::Metric.length(71));
+    }
+
+    #[test]
+    fn weight_test() {
+        assert_eq!("140 lbs", Units::Imperial.weight(140));
+        assert_eq!("64 kg", Units::Metric.weight(140));
+    }
+
+    #[test]
+    fn from_str_test() {
+        assert_eq!(Ok(Units::Metric), "METRIC".parse());
+        assert_eq!(Ok(Units::Imperial), "imperial".parse());
+        assert_eq!(Err(()), "furlongs".parse::<Units>());
+    }
+}
@@ -1,4 +1,4 @@
-use initiative_core::app::AutocompleteSuggestion;
+use initiative_core::app::{AutocompleteSuggestion, CommandOutput};
 use initiative_core::{app, App, BackupData, DataStore, Event, MemoryDataStore, NullDataStore};
 use tokio_test::block_on;
 
@@ -47,7 +47,7 @@ impl SyncApp {
         Self(app(data_store, event_dispatcher))
     }
 
-    pub fn init(&mut self) -> &'static str {
+    pub fn init(&mut self) -> String {
         block_on(self.0.init())
     }
 
@@ -55,11 +55,23 @@ impl SyncApp {
         block_on(self.0.command(input))
     }
 
+    pub fn command_structured(&mut self, input: &str) -> Result<CommandOutput, CommandOutput> {
+        block_on(self.0.command_structured(input))
+    }
+
+    pub fn set_plaintext(&mut self, plaintext: bool) {
+        self.0.set_plaintext(plaintext)
+    }
+
+    pub fn set_autocomplete_limit(&mut self, limit: usize) {
+        self.0.set_autocomplete_limit(limit)
+    }
+
     pub fn autocomplete(&self, input: &str) -> Vec<AutocompleteSuggestion> {
         block_on(self.0.autocomplete(input))
     }
 
-    pub fn bulk_import(&mut self, data: BackupData) -> Result<String, String> {
-        block_on(self.0.bulk_import(data))
+    pub fn bulk_import(&mut self, data: BackupData, overwrite: bool) -> Result<String, String> {
+        block_on(self.0.bulk_import(data, overwrite))
     }
 }
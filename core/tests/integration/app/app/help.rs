@@ -23,3 +23,20 @@ fn all_commands_are_valid() {
         }
     }
 }
+
+#[test]
+fn help_topic() {
+    let mut app = sync_app();
+
+    let output = app.command("help npc").unwrap();
+    assert!(output.starts_with("# Help: characters"), "{}", output);
+    assert_eq!(output, app.command("HELP character").unwrap());
+
+    assert_eq!(
+        Err("There is no help for \"npcc\". Did you mean `help npc`?".to_string()),
+        app.command("help npcc"),
+    );
+
+    let error = app.command("help potato").unwrap_err();
+    assert!(error.contains("`help journal`"), "{}", error);
+}
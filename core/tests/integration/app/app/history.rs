@@ -0,0 +1,42 @@
+use crate::common::{get_name, sync_app};
+
+#[test]
+fn repeat_last_command() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "There is no previous command to repeat.",
+        app.command("!!").unwrap_err(),
+    );
+
+    let first = get_name(&app.command("npc").unwrap());
+    let output = app.command("!!").unwrap();
+    let second = get_name(&output);
+
+    assert!(
+        output.starts_with("<div class=\"thing-box npc\">"),
+        "{}",
+        output
+    );
+    assert_ne!(first, second);
+}
+
+#[test]
+fn history_lists_recent_commands() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "You haven't run any commands yet.",
+        app.command("history").unwrap_err(),
+    );
+
+    app.command("roll d20").unwrap();
+    app.command("npc; help").unwrap();
+    app.command("!!").unwrap();
+    app.command("history").unwrap();
+
+    assert_eq!(
+        "# Recent commands\n\n`roll d20`\\\n`npc`\\\n`help`\\\n`help`",
+        app.command("history").unwrap(),
+    );
+}
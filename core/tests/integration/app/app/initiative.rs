@@ -0,0 +1,179 @@
+use crate::common::sync_app;
+
+#[test]
+fn add_roll_list_clear() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "*There is no one in the initiative order yet. Add someone with `initiative add [name] [modifier]`.*",
+        app.command("initiative list").unwrap(),
+    );
+
+    app.command("initiative add Goblin +2").unwrap();
+    app.command("initiative add Bugbear -1").unwrap();
+    assert_eq!(
+        "# Initiative\n\n* Goblin (+2)\n* Bugbear (-1)\n\n_Use `initiative roll` once everyone is in._",
+        app.command("initiative list").unwrap(),
+    );
+
+    let output = app.command("roll initiative").unwrap();
+    let totals: Vec<i32> = output
+        .lines()
+        .skip(2)
+        .map(|line| {
+            line.split(": ")
+                .nth(1)
+                .and_then(|s| s.split(' ').next())
+                .unwrap()
+                .parse()
+                .unwrap()
+        })
+        .collect();
+    assert_eq!(2, totals.len(), "{}", output);
+    assert!(totals[0] >= totals[1], "{}", output);
+    assert_eq!(output, app.command("initiative").unwrap());
+
+    // Latecomers roll as soon as they join.
+    let output = app.command("initiative add Wolf +2").unwrap();
+    assert!(output.contains("\n3. "), "{}", output);
+    assert!(output.contains("_Wolf rolled "), "{}", output);
+
+    assert_eq!(
+        "There is already a combatant named \"wolf\" in the initiative order.",
+        app.command("initiative add wolf").unwrap_err(),
+    );
+
+    assert_eq!(
+        "The initiative order was cleared.",
+        app.command("initiative clear").unwrap(),
+    );
+    assert_eq!(
+        "There is no one in the initiative order yet. Add someone with `initiative add [name] [modifier]`.",
+        app.command("initiative roll").unwrap_err(),
+    );
+}
+
+#[test]
+fn next_prev_remove() {
+    let mut app = sync_app();
+    app.command("initiative add Goblin; initiative add Bugbear")
+        .unwrap();
+
+    assert_eq!(
+        "Initiative hasn't been rolled yet. Use `initiative roll` once everyone is in.",
+        app.command("initiative next").unwrap_err(),
+    );
+
+    let output = app.command("initiative roll").unwrap();
+    assert!(
+        output.starts_with("# Initiative: round 1\n\n1. **"),
+        "{}",
+        output,
+    );
+    let first = output
+        .split("**")
+        .nth(1)
+        .unwrap()
+        .split(':')
+        .next()
+        .unwrap();
+    let second = if first == "Goblin" {
+        "Bugbear"
+    } else {
+        "Goblin"
+    };
+
+    let output = app.command("initiative next").unwrap();
+    assert!(
+        output.contains(&format!("\n2. **{}: ", second)),
+        "{}",
+        output
+    );
+    assert!(
+        output.ends_with(&format!("_It's {}'s turn._", second)),
+        "{}",
+        output,
+    );
+
+    let output = app.command("initiative next").unwrap();
+    assert!(
+        output.starts_with("# Initiative: round 2\n\n1. **"),
+        "{}",
+        output
+    );
+
+    let output = app.command("initiative prev").unwrap();
+    assert!(output.starts_with("# Initiative: round 1\n"), "{}", output);
+
+    // The turn passes on when the active combatant leaves the fight.
+    let output = app
+        .command(&format!("initiative remove {}", second))
+        .unwrap();
+    assert!(
+        output.starts_with(&format!("# Initiative: round 2\n\n1. **{}: ", first)),
+        "{}",
+        output,
+    );
+    assert!(
+        output.ends_with(&format!(
+            "_{} was removed from the initiative order._",
+            second,
+        )),
+        "{}",
+        output,
+    );
+}
+
+#[test]
+fn damage_heal() {
+    let mut app = sync_app();
+    app.command("initiative add Goblin 7hp +2").unwrap();
+
+    assert_eq!(
+        "# Initiative\n\n* Goblin (+2), 4/7 HP\n\n_Goblin took 3 damage and has 4/7 HP left._",
+        app.command("damage Goblin 3").unwrap(),
+    );
+    assert_eq!(
+        "# Initiative\n\n* Goblin (+2), 7/7 HP\n\n_Goblin was healed for 5 and has 7/7 HP._",
+        app.command("heal goblin 5").unwrap(),
+    );
+    assert_eq!(
+        "# Initiative\n\n* Goblin (+2), 0/7 HP (down)\n\n_Goblin took 10 damage and is down._",
+        app.command("damage Goblin 10").unwrap(),
+    );
+
+    app.command("initiative add Wolf").unwrap();
+    assert_eq!(
+        "Wolf has no hit points to track. Give them some when adding them, eg. `initiative add Wolf 7hp`.",
+        app.command("damage Wolf 2").unwrap_err(),
+    );
+
+    assert_eq!(
+        "Combatants will be removed from the initiative order when they drop to 0 HP.",
+        app.command("initiative auto-remove on").unwrap(),
+    );
+    app.command("initiative add Orc 15hp").unwrap();
+    assert_eq!(
+        "# Initiative\n\n* Goblin (+2), 0/7 HP (down)\n* Wolf (+0)\n\n_Orc took 20 damage and was removed from the initiative order._",
+        app.command("damage Orc 20").unwrap(),
+    );
+}
+
+#[test]
+fn out_of_range() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "\"+2147483647\" is not a valid modifier. Modifiers must be between -30 and +30.",
+        app.command("initiative add Goblin +2147483647")
+            .unwrap_err(),
+    );
+    assert_eq!(
+        "\"4294967296hp\" is not a valid number of hit points. Hit points must be between 1 and 4294967295.",
+        app.command("initiative add Wolf 4294967296hp").unwrap_err(),
+    );
+
+    app.command("initiative add Goblin +30; initiative add Bugbear -30")
+        .unwrap();
+    assert!(app.command("initiative roll").is_ok());
+}
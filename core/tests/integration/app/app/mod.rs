@@ -2,4 +2,10 @@ mod about;
 mod changelog;
 mod debug;
 mod help;
+mod history;
+mod initiative;
+mod names;
 mod roll;
+mod seed;
+mod table;
+mod version;
@@ -0,0 +1,27 @@
+use crate::common::{get_name, sync_app};
+
+#[test]
+fn set_names() {
+    let mut app = sync_app();
+
+    app.command("seed 7").unwrap();
+    let common_name = get_name(&app.command("human woman").unwrap());
+
+    assert_eq!(
+        "Human characters will now be given nordic names.",
+        app.command("set names Nordic").unwrap(),
+    );
+    app.command("seed 7").unwrap();
+    let output = app.command("human woman x5").unwrap();
+    assert_eq!(5, output.matches("sdottir` (").count(), "{}", output);
+
+    // Other species keep their usual names.
+    let output = app.command("dwarf man").unwrap();
+    assert!(!get_name(&output).ends_with("sson"), "{}", output);
+
+    // The common flavor is the default.
+    let mut app = sync_app();
+    app.command("set names nordic; set names common; seed 7")
+        .unwrap();
+    assert_eq!(common_name, get_name(&app.command("human woman").unwrap()));
+}
@@ -18,3 +18,148 @@ fn it_works() {
 
     assert_ne!(app.command("roll 100d1000"), app.command("roll 100d1000"));
 }
+
+#[test]
+fn advantage() {
+    let mut app = sync_app();
+
+    assert_eq!("[1, 1] = **1**", app.command("roll 2d1K1").unwrap());
+    assert_eq!("[1, 1] = **1**", app.command("2d1k1").unwrap());
+}
+
+#[test]
+fn malformed() {
+    let mut app = sync_app();
+
+    for formula in ["2d", "d20+", "0d6", "d0"] {
+        assert_eq!(
+            format!(
+                "\"{}\" is not a valid dice formula. See `help` for some examples.",
+                formula,
+            ),
+            app.command(&format!("roll {}", formula)).unwrap_err(),
+        );
+    }
+}
+
+#[test]
+fn keep_highest() {
+    let mut app = sync_app();
+
+    for _ in 0..10 {
+        let output = app.command("roll 4d6kh3").unwrap();
+        let (dice, dropped, total) = parse_output(&output);
+
+        let lowest = *dice.iter().min().unwrap();
+
+        assert_eq!(4, dice.len(), "{}", output);
+        assert_eq!(dice.iter().sum::<u32>() - lowest, total, "{}", output);
+        if dice.iter().any(|die| *die != lowest) {
+            assert_eq!(vec![lowest], dropped, "{}", output);
+        }
+    }
+}
+
+#[test]
+fn keep_lowest() {
+    let mut app = sync_app();
+
+    for _ in 0..10 {
+        let output = app.command("2d20kl1").unwrap();
+        let (dice, dropped, total) = parse_output(&output);
+
+        let (lowest, highest) = (*dice.iter().min().unwrap(), *dice.iter().max().unwrap());
+
+        assert_eq!(2, dice.len(), "{}", output);
+        assert_eq!(lowest, total, "{}", output);
+        if lowest != highest {
+            assert_eq!(vec![highest], dropped, "{}", output);
+        }
+    }
+}
+
+#[test]
+fn keep_and_drop_suffixes() {
+    let mut app = sync_app();
+
+    for _ in 0..10 {
+        let output = app.command("2d20k1").unwrap();
+        let (dice, _, total) = parse_output(&output);
+        assert_eq!(*dice.iter().min().unwrap(), total, "{}", output);
+
+        let output = app.command("2d20d1").unwrap();
+        let (dice, _, total) = parse_output(&output);
+        assert_eq!(*dice.iter().max().unwrap(), total, "{}", output);
+    }
+}
+
+#[test]
+fn keep_too_many() {
+    assert_eq!(
+        Err("Can't keep 5 dice from a roll of 4.".to_string()),
+        sync_app().command("roll 4d6k5"),
+    );
+}
+
+#[test]
+fn roll_stats() {
+    let output = sync_app().command("roll stats").unwrap();
+    let lines: Vec<&str> = output.split("\\\n").collect();
+    assert_eq!(6, lines.len(), "{}", output);
+
+    for (line, ability) in lines.iter().zip(["STR", "DEX", "CON", "INT", "WIS", "CHA"]) {
+        let (dice, dropped, total) =
+            parse_output(line.strip_prefix(&format!("**{}:** ", ability)).unwrap());
+
+        let lowest = *dice.iter().min().unwrap();
+
+        assert_eq!(4, dice.len(), "{}", output);
+        assert_eq!(dice.iter().sum::<u32>() - lowest, total, "{}", output);
+        if dice.iter().any(|die| *die != lowest) {
+            assert_eq!(vec![lowest], dropped, "{}", output);
+        }
+        assert!((3..=18).contains(&total), "{}", output);
+    }
+}
+
+#[test]
+fn roll_stats_standard() {
+    assert_eq!(
+        "**Standard array:** 15, 14, 13, 12, 10, 8\n\n_Assign each score to the ability of your choice._",
+        sync_app().command("roll stats standard").unwrap(),
+    );
+}
+
+/// Split eg. "[6, ~~1~~, 5, 2] = **13**" into all dice, dropped dice and the total.
+fn parse_output(output: &str) -> (Vec<u32>, Vec<u32>, u32) {
+    let (dice, total) = output.split_once(" = ").unwrap();
+    let (mut all, mut dropped) = (Vec::new(), Vec::new());
+
+    for die in dice.trim_matches(&['[', ']'][..]).split(", ") {
+        let die = match die.strip_prefix("~~").and_then(|s| s.strip_suffix("~~")) {
+            Some(die) => {
+                dropped.push(die.parse().unwrap());
+                die
+            }
+            None => die,
+        };
+        all.push(die.parse().unwrap());
+    }
+
+    (all, dropped, total.trim_matches('*').parse().unwrap())
+}
+
+#[test]
+fn roll_percentile() {
+    let mut app = sync_app();
+
+    for _ in 0..20 {
+        let output = app.command("roll percentile").unwrap();
+        let total: u8 = output
+            .rsplit_once(" = **")
+            .and_then(|(_, total)| total.strip_suffix("**"))
+            .and_then(|total| total.parse().ok())
+            .unwrap();
+        assert!((1..=100).contains(&total), "{}", output);
+    }
+}
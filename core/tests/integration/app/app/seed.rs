@@ -0,0 +1,75 @@
+use crate::common::sync_app;
+
+#[test]
+fn same_seed_same_results() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "Random results are now seeded with 42. Use `seed 42` again to repeat them.",
+        app.command("seed 42").unwrap(),
+    );
+    let npc = app.command("npc").unwrap();
+    let inn = app.command("inn").unwrap();
+    let roll = app.command("8d6").unwrap();
+
+    let mut other_app = sync_app();
+    other_app.command("seed 42").unwrap();
+    assert_eq!(npc, other_app.command("npc").unwrap());
+    assert_eq!(inn, other_app.command("inn").unwrap());
+    assert_eq!(roll, other_app.command("8d6").unwrap());
+
+    other_app.command("seed 43").unwrap();
+    assert_ne!(npc, other_app.command("npc").unwrap());
+}
+
+#[test]
+fn last_seed_repeats_the_last_result() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "You haven't generated anything yet. Try `npc` first.",
+        app.command("last seed").unwrap_err(),
+    );
+
+    app.command("npc").unwrap();
+    let npc = app.command("npc").unwrap();
+    app.command("roll d20").unwrap();
+
+    let output = app.command("last seed").unwrap();
+    let seed = output
+        .strip_prefix("The last thing you generated used seed ")
+        .and_then(|s| s.split_once('.'))
+        .map(|(seed, _)| seed)
+        .unwrap();
+    assert!(
+        output.ends_with(&format!(
+            "Use `seed {}`, then the same command, to generate it again.",
+            seed,
+        )),
+        "{}",
+        output,
+    );
+
+    // Names must be unique, so the same character can only be generated again elsewhere.
+    let mut other_app = sync_app();
+    other_app.command(&format!("seed {}", seed)).unwrap();
+    assert_eq!(npc, other_app.command("npc").unwrap());
+}
+
+#[test]
+fn last_seed_repeats_a_region() {
+    let mut app = sync_app();
+    app.command("seed 5").unwrap();
+    let region = app.command("generate region forest").unwrap();
+
+    let output = app.command("last seed").unwrap();
+    let seed = output
+        .strip_prefix("The last thing you generated used seed ")
+        .and_then(|s| s.split_once('.'))
+        .map(|(seed, _)| seed)
+        .unwrap();
+
+    let mut other_app = sync_app();
+    other_app.command(&format!("seed {}", seed)).unwrap();
+    assert_eq!(region, other_app.command("generate region forest").unwrap());
+}
@@ -0,0 +1,102 @@
+use crate::common::sync_app;
+
+#[test]
+fn create_and_roll() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "Table \"rumors\" created with 2 entries. Use `roll table rumors` to roll on it.",
+        app.command("table create rumors: \"orc raid\", 3x \"missing child\"")
+            .unwrap(),
+    );
+
+    let results: Vec<String> = (0..20)
+        .map(|_| app.command("roll table Rumors").unwrap())
+        .collect();
+    assert!(results
+        .iter()
+        .all(|s| s == "**rumors:** orc raid" || s == "**rumors:** missing child"));
+    assert!(results.iter().any(|s| s == "**rumors:** missing child"));
+
+    assert_eq!(
+        "Table \"Rumors\" replaced with 1 entry. Use `roll table Rumors` to roll on it.",
+        app.command("table create Rumors: dragon sighting").unwrap(),
+    );
+    assert_eq!(
+        "**Rumors:** dragon sighting",
+        app.command("roll table rumors").unwrap(),
+    );
+}
+
+#[test]
+fn errors() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "There is no table named \"loot\". Create one with `table create loot: [entries]`.",
+        app.command("roll table loot").unwrap_err(),
+    );
+    assert_eq!(
+        "\"gold\" has a weight of zero. Weights must be at least 1.",
+        app.command("table create loot: 0x gold").unwrap_err(),
+    );
+
+    // Huge weights are rejected rather than overflowing when the table is rolled.
+    assert_eq!(
+        "\"a\" has a weight of 18446744073709551615. Weights can be at most 1000.",
+        app.command("table create x: 18446744073709551615x a, 18446744073709551615x b")
+            .unwrap_err(),
+    );
+    assert!(app.command("roll table x").is_err());
+}
+
+#[test]
+fn create_and_roll_ranges() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "Table \"loot\" created with 3 entries. Use `roll table loot` to roll on it.",
+        app.command("table create loot: 01-50 common, 51-90 uncommon, 91-00 rare")
+            .unwrap(),
+    );
+
+    for _ in 0..20 {
+        let output = app.command("roll table loot").unwrap();
+        let (entry, roll) = output
+            .strip_prefix("**loot:** ")
+            .and_then(|s| s.strip_suffix(" on d100)"))
+            .and_then(|s| s.split_once(" (rolled "))
+            .unwrap();
+
+        match roll {
+            "00" => assert_eq!("rare", entry),
+            roll => match roll.parse::<u8>().unwrap() {
+                1..=50 => assert_eq!("common", entry, "{}", output),
+                51..=90 => assert_eq!("uncommon", entry, "{}", output),
+                91..=99 => assert_eq!("rare", entry, "{}", output),
+                _ => panic!("{}", output),
+            },
+        }
+    }
+
+    assert_eq!(
+        "Nothing in the table \"loot\" is rolled on 51-60. Ranges can't have gaps.",
+        app.command("table create loot: 01-50 common, 61-00 rare")
+            .unwrap_err(),
+    );
+    assert_eq!(
+        "\"common\" and \"rare\" overlap on 41-50. Ranges can't overlap.",
+        app.command("table create loot: 01-50 common, 41-00 rare")
+            .unwrap_err(),
+    );
+
+    // An entry that only starts with a range makes it a range table, unless it's quoted.
+    assert!(app
+        .command("table create encounters: 1-2 goblins, wolves")
+        .is_err());
+    assert_eq!(
+        "Table \"encounters\" created with 2 entries. Use `roll table encounters` to roll on it.",
+        app.command("table create encounters: \"1-2 goblins\", wolves")
+            .unwrap(),
+    );
+}
@@ -0,0 +1,12 @@
+use crate::common::sync_app;
+
+#[test]
+fn it_works() {
+    let output = sync_app().command("version").unwrap();
+    assert!(
+        output.starts_with(&format!("initiative.sh v{}", env!("CARGO_PKG_VERSION"))),
+        "{}",
+        output,
+    );
+    assert_eq!(output, sync_app().command("VERSION").unwrap());
+}
@@ -2,7 +2,7 @@ mod app;
 mod tutorial;
 
 use crate::common::{get_name, sync_app};
-use initiative_core::app::AutocompleteSuggestion;
+use initiative_core::app::{AutocompleteSuggestion, Link, TIPS};
 
 #[test]
 fn autocomplete_command() {
@@ -17,7 +17,7 @@ fn autocomplete_command() {
             ("Delayed Blast Fireball", "SRD spell"),
             ("delete [name]", "remove an entry from journal"),
             ("Demiplane", "SRD spell"),
-            ("desert", "create desert"),
+            ("describe [character]", "describe a character in prose"),
         ]
         .into_iter()
         .map(|(term, summary)| AutocompleteSuggestion::new(term, summary))
@@ -31,6 +31,24 @@ fn autocomplete_command() {
     )
 }
 
+#[test]
+fn autocomplete_limit() {
+    let mut app = sync_app();
+    let suggestions = app.autocomplete("d");
+    assert_eq!(10, suggestions.len());
+
+    app.set_autocomplete_limit(3);
+    assert_eq!(suggestions[..3], app.autocomplete("d"));
+
+    app.set_autocomplete_limit(1000);
+    let more_suggestions = app.autocomplete("d");
+    assert!(more_suggestions.len() > 10, "{:?}", more_suggestions);
+    assert_eq!(suggestions[..], more_suggestions[..10]);
+
+    app.set_autocomplete_limit(0);
+    assert_eq!(Vec::<AutocompleteSuggestion>::new(), app.autocomplete("d"));
+}
+
 #[test]
 fn autocomplete_proper_noun() {
     let mut app = sync_app();
@@ -52,12 +70,31 @@ fn autocomplete_proper_noun() {
     );
 }
 
+#[test]
+fn autocomplete_saved_name() {
+    let mut app = sync_app();
+    app.command("a dwarf named Gundren Rockseeker").unwrap();
+
+    let autocomplete_results = app.autocomplete("gun");
+    assert!(
+        autocomplete_results
+            .iter()
+            .any(|suggestion| suggestion.term == "Gundren Rockseeker"
+                && !suggestion.summary.ends_with("(unsaved)")),
+        "{:?}",
+        autocomplete_results,
+    );
+}
+
 #[test]
 fn init() {
     let output = sync_app().init();
     assert!(output.contains("initiative.sh"), "{}", output);
     assert!(output.contains("changelog"), "{}", output);
     assert!(output.contains("\n* "), "{}", output);
+
+    let tip = output.rsplit_once("\n\n_Tip: ").unwrap().1;
+    assert!(TIPS.iter().any(|t| tip == format!("{}_", t)), "{}", output);
 }
 
 #[test]
@@ -94,3 +131,281 @@ fn command_conflict_other_meanings() {
         output,
     );
 }
+
+#[test]
+fn command_chain() {
+    let mut app = sync_app();
+
+    let output = app
+        .command("seed 42; roll stats standard; set units metric")
+        .unwrap();
+    assert_eq!(
+        "Random results are now seeded with 42. Use `seed 42` again to repeat them.\n\n**Standard array:** 15, 14, 13, 12, 10, 8\n\n_Assign each score to the ability of your choice._\n\nMeasurements will now be shown in metric units.",
+        output,
+    );
+
+    let output = app.command("npc; npc").unwrap();
+    assert_eq!(2, output.matches("# ").count(), "{}", output);
+}
+
+#[test]
+fn command_chain_stops_at_error() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "Measurements will now be shown in metric units.\n\nUnknown command: \"blah blah\"",
+        app.command("set units metric; blah blah; set units imperial")
+            .unwrap_err(),
+    );
+    assert_eq!(
+        "Measurements will now be shown in metric units.",
+        app.command("set units metric").unwrap(),
+    );
+}
+
+#[test]
+fn command_chain_trailing_semicolon() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        app.command("about").unwrap(),
+        app.command("about;").unwrap(),
+    );
+    assert_eq!(
+        "Measurements will now be shown in metric units.",
+        app.command("set units metric; ").unwrap(),
+    );
+    assert_eq!(1, app.command("; npc; ").unwrap().matches("# ").count());
+}
+
+#[test]
+fn autocomplete_command_chain() {
+    assert_eq!(
+        vec![AutocompleteSuggestion::new(
+            "npc; npc;roll [dice]",
+            "roll eg. 8d6 or d20+3",
+        )],
+        sync_app().autocomplete("npc; npc;roll"),
+    );
+
+    assert_eq!(
+        vec![AutocompleteSuggestion::new(
+            "npc; seed [number]",
+            "make results repeatable",
+        )],
+        sync_app().autocomplete("npc; see"),
+    );
+}
+
+#[test]
+fn user_alias() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "Alias `tav` created. Typing `tav` will now run `inn`.",
+        app.command("alias tav = inn").unwrap(),
+    );
+    let output = app.command("tav").unwrap();
+    assert!(output.contains("*inn*"), "{}", output);
+
+    app.command("alias party = npc; npc").unwrap();
+    let output = app.command("party").unwrap();
+    assert_eq!(2, output.matches("# ").count(), "{}", output);
+
+    let output = app.command("tav; party").unwrap();
+    assert_eq!(3, output.matches("# ").count(), "{}", output);
+
+    assert_eq!(
+        vec![AutocompleteSuggestion::new("party", "alias for npc; npc")],
+        app.autocomplete("part"),
+    );
+}
+
+#[test]
+fn user_alias_errors() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "The alias `loop` can't refer to itself.",
+        app.command("alias loop = npc; loop").unwrap_err(),
+    );
+    assert_eq!(
+        "`npc` is already a command, so it can't be used as an alias.",
+        app.command("alias npc = inn").unwrap_err(),
+    );
+    assert_eq!(
+        "Alias names must be a single word, eg. `alias tav = inn`.",
+        app.command("alias alias = inn").unwrap_err(),
+    );
+}
+
+#[test]
+fn user_alias_list_remove() {
+    let mut app = sync_app();
+
+    app.command("alias tav = inn").unwrap();
+    assert_eq!(
+        "# Aliases\n\n`tav`: `inn`",
+        app.command("alias list").unwrap(),
+    );
+    assert_eq!(
+        "Alias `tav` removed. It used to run `inn`.",
+        app.command("unalias tav").unwrap(),
+    );
+    assert_eq!(
+        "There is no alias named `tav`.",
+        app.command("unalias tav").unwrap_err(),
+    );
+    assert_eq!(
+        "Unknown command: \"tav\". Did you mean `tag`?",
+        app.command("tav").unwrap_err(),
+    );
+}
+
+#[test]
+fn unknown_command_suggestion() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "Unknown command: \"npcc\". Did you mean `npc`?",
+        app.command("npcc").unwrap_err(),
+    );
+
+    let output = app.command("npc").unwrap();
+    assert!(!output.contains("Did you mean"), "{}", output);
+}
+
+#[test]
+fn command_structured() {
+    let mut app = sync_app();
+
+    let output = app.command_structured("npc").unwrap();
+    assert_eq!(
+        Some(&Link::Temporary {
+            command: "more".to_string(),
+            range: output.text.find("~more~").unwrap()..output.text.find("~more~").unwrap() + 6,
+        }),
+        output.links.last(),
+        "{}",
+        output.text,
+    );
+
+    let output = app.command_structured("help roll").unwrap();
+    assert!(
+        output
+            .links
+            .iter()
+            .any(|link| matches!(link, Link::Dice { formula, .. } if formula == "8d6: Fireball")),
+        "{:?}",
+        output.links,
+    );
+
+    let error = app.command_structured("npcc").unwrap_err();
+    assert_eq!(
+        vec![Link::Command {
+            command: "npc".to_string(),
+            range: error.text.len() - 6..error.text.len() - 1,
+        }],
+        error.links,
+    );
+}
+
+#[test]
+fn plaintext() {
+    let mut app = sync_app();
+    app.set_plaintext(true);
+
+    app.command("a kingdom named The North").unwrap();
+    app.command("a duchy named Winterfell").unwrap();
+    app.command("put Winterfell in The North").unwrap();
+
+    assert_eq!(
+        "The North\n\n👑 The North (kingdom)\n\n- 👑 Winterfell (duchy)",
+        app.command("what is in The North").unwrap(),
+    );
+
+    app.command("note add The North: winter is coming").unwrap();
+    let output = app.command("load The North").unwrap();
+    assert!(output.starts_with("The North\nkingdom\n"), "{}", output);
+    assert!(!output.contains(['<', '*', '`', '\\']), "{}", output);
+
+    let output = app.command("roll 4d6d1").unwrap();
+    assert!(!output.contains(['~', '*']), "{}", output);
+    assert_eq!(1, output.matches('\u{336}').count(), "{}", output);
+
+    // A literal `*` isn't emphasis, so it stays.
+    let output = app.command("roll 2d6*3").unwrap();
+    assert!(output.contains("] * 3 = "), "{}", output);
+    assert_eq!(1, output.matches('*').count(), "{}", output);
+
+    app.command("note add Winterfell: see [the map](https://example.com)")
+        .unwrap();
+    let output = app.command("notes Winterfell").unwrap();
+    assert!(
+        output.contains("see the map (https://example.com)"),
+        "{}",
+        output,
+    );
+
+    assert_eq!(
+        "Unknown command: \"npcc\". Did you mean npc?",
+        app.command("npcc").unwrap_err(),
+    );
+
+    app.set_plaintext(false);
+    assert_eq!(
+        "# The North\n\n👑 `The North` (kingdom)\n\n* 👑 `Winterfell` (duchy)",
+        app.command("what is in The North").unwrap(),
+    );
+}
+
+#[test]
+fn emoji_off() {
+    let mut app = sync_app();
+
+    app.command("a kingdom named The North").unwrap();
+    app.command("a duchy named Winterfell").unwrap();
+    app.command("put Winterfell in The North").unwrap();
+
+    assert_eq!(
+        "Emoji will no longer be shown.",
+        app.command("set emoji off").unwrap(),
+    );
+    assert_eq!(
+        "# The North\n\n`The North` (kingdom)\n\n* `Winterfell` (duchy)",
+        app.command("what is in The North").unwrap(),
+    );
+
+    let output = app.command("npc").unwrap();
+    assert!(
+        !output
+            .chars()
+            .any(|c| ('\u{1f000}'..='\u{1faff}').contains(&c)),
+        "{}",
+        output,
+    );
+
+    assert_eq!(
+        "Emoji will now be shown.",
+        app.command("set emoji on").unwrap()
+    );
+    assert_eq!(
+        "# The North\n\n👑 `The North` (kingdom)\n\n* 👑 `Winterfell` (duchy)",
+        app.command("what is in The North").unwrap(),
+    );
+}
+
+#[test]
+fn commands() {
+    let commands = initiative_core::App::commands();
+
+    assert!(commands.contains(&AutocompleteSuggestion::new(
+        "roll [dice]",
+        "roll eg. 8d6 or d20+3",
+    )));
+    assert!(commands.contains(&AutocompleteSuggestion::new(
+        "help",
+        "how to use initiative.sh",
+    )));
+    assert!(commands.windows(2).all(|pair| pair[0].term < pair[1].term));
+}
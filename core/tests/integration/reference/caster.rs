@@ -0,0 +1,36 @@
+use crate::common::sync_app;
+
+#[test]
+fn caster_spell_slots() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "# Level 5 Wizard Spell Slots\n\n| 1st | 2nd | 3rd |\n|--:|--:|--:|\n| 4 | 3 | 2 |\n\n*This table is Open Game Content subject to the `Open Game License`.*",
+        app.command("srd caster wizard 5").unwrap(),
+    );
+    assert_eq!(
+        app.command("srd caster wizard 5").unwrap(),
+        app.command("caster Wizard 5").unwrap(),
+    );
+
+    let output = app.command("caster warlock 11").unwrap();
+    assert!(
+        output.contains("| 3 | 5th |\n\n*Pact Magic slots are regained on a short or long rest.*\\\n*Mystic Arcanum (each once per long rest): 6th.*"),
+        "{}",
+        output,
+    );
+}
+
+#[test]
+fn caster_errors() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "There is no level 21 cleric. Character levels go from 1 to 20.",
+        app.command("caster cleric 21").unwrap_err(),
+    );
+    assert_eq!(
+        "\"potato\" is not a spellcasting class. Try one of: bard, cleric, druid, paladin, ranger, sorcerer, warlock, wizard.",
+        app.command("srd caster potato 3").unwrap_err(),
+    );
+}
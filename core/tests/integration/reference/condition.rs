@@ -24,3 +24,21 @@ fn stunned() {
         sync_app().autocomplete("stunned"),
     );
 }
+
+#[test]
+fn conditions() {
+    let output = sync_app().command("conditions").unwrap();
+    assert!(output.starts_with("# Conditions\n"), "{}", output);
+    assert!(output.contains("\n* `Stunned`"), "{}", output);
+    assert!(
+        output.ends_with("*This listing is Open Game Content subject to the `Open Game License`.*"),
+        "{}",
+        output,
+    );
+    assert_eq!(Ok(output), sync_app().command("srd conditions"));
+
+    assert_eq!(
+        vec![AutocompleteSuggestion::new("conditions", "SRD index")],
+        sync_app().autocomplete("Conditions"),
+    );
+}
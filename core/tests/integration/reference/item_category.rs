@@ -119,3 +119,22 @@ fn magic_weapons() {
         sync_app().autocomplete("magic weapons"),
     );
 }
+
+#[test]
+fn item_categories() {
+    let mut app = sync_app();
+    let output = app.command("srd item categories").unwrap();
+
+    assert!(output.starts_with("# Item Categories\n"), "{}", output);
+    assert!(
+        output.contains("\n* `srd item category melee weapons`\n"),
+        "{}",
+        output,
+    );
+    assert_eq!(output, app.command("item categories").unwrap());
+
+    for line in output.lines().filter(|line| line.starts_with("* `")) {
+        let command = line.trim_start_matches("* `").trim_end_matches('`');
+        assert!(app.command(command).is_ok(), "{}", command);
+    }
+}
@@ -32,3 +32,23 @@ You can use an action to present the rod and command obedience from each creatur
         sync_app().autocomplete("rod of rulership"),
     );
 }
+
+#[test]
+fn magic_items_by_rarity() {
+    let mut app = sync_app();
+
+    let output = app.command("srd magic items rarity rare").unwrap();
+    assert!(output.starts_with("# Rare Magic Items\n"), "{}", output);
+    assert!(output.contains("\n* `Flame Tongue`"), "{}", output);
+    assert!(!output.contains("\n* `Deck of Many Things`"), "{}", output);
+    assert_eq!(output, app.command("magic items rarity RARE").unwrap());
+
+    let output = app.command("magic items").unwrap();
+    assert!(output.starts_with("# Magic Items\n"), "{}", output);
+    assert!(output.contains("\n* `Deck of Many Things`"), "{}", output);
+
+    assert_eq!(
+        "\"epic\" is not a magic item rarity. Try one of: common, uncommon, rare, very rare, legendary, artifact.",
+        app.command("srd magic items rarity epic").unwrap_err(),
+    );
+}
@@ -1,8 +1,11 @@
+mod caster;
 mod condition;
 mod item;
 mod item_category;
 mod magic_item;
+mod monster;
 mod open_game_license;
 mod spell;
 mod spells;
 mod traits;
+mod treasure;
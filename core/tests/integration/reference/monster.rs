@@ -0,0 +1,60 @@
+use crate::common::sync_app;
+use initiative_core::app::AutocompleteSuggestion;
+
+#[test]
+fn goblin() {
+    let mut app = sync_app();
+    let output = app.command("srd monster Goblin").unwrap();
+
+    assert!(
+        output.starts_with(
+            "# Goblin\n*Small humanoid (goblinoid), neutral evil*\n\n**Armor Class:** 15"
+        ),
+        "{}",
+        output,
+    );
+    assert!(
+        output.contains("| 8 (-1) | 14 (+2) | 10 (+0) |"),
+        "{}",
+        output
+    );
+    assert!(output.contains("**Challenge:** 1/4 (50 XP)"), "{}", output);
+    assert!(
+        output.contains("## Actions\n\n***Scimitar.*** "),
+        "{}",
+        output,
+    );
+    assert!(
+        output.contains("5 (`1d6 + 2`) slashing damage"),
+        "{}",
+        output
+    );
+    assert!(
+        output.ends_with("*Goblin is Open Game Content subject to the `Open Game License`.*"),
+        "{}",
+        output,
+    );
+
+    assert_eq!(Ok(output), app.command("goblin"));
+
+    assert_eq!(
+        vec![AutocompleteSuggestion::new("Goblin", "SRD monster")],
+        app.autocomplete("gobli"),
+    );
+}
+
+#[test]
+fn monsters() {
+    let output = sync_app().command("monsters").unwrap();
+    assert!(
+        output.starts_with("# Monsters\n* `Aboleth` (large aberration, CR 10)\n"),
+        "{}",
+        output,
+    );
+    assert_eq!(Ok(output), sync_app().command("srd monsters"));
+
+    assert_eq!(
+        vec![AutocompleteSuggestion::new("monsters", "SRD index")],
+        sync_app().autocomplete("Monsters"),
+    );
+}
@@ -62,9 +62,20 @@ You touch a willing creature to grant it the ability to see in the dark. For the
         sync_app().command("srd spell Darkvision").unwrap(),
     );
 
-    assert_eq!(2, sync_app().autocomplete("darkvision").len());
-    assert!(sync_app()
-        .autocomplete("darkvision")
-        .iter()
-        .any(|suggestion| suggestion.term == "Darkvision" && suggestion.summary == "SRD spell"));
+    // The spell shares its name with a trait, but spells take precedence in autocomplete.
+    assert_eq!(
+        vec![AutocompleteSuggestion::new("Darkvision", "SRD spell")],
+        sync_app().autocomplete("darkvision"),
+    );
+}
+
+#[test]
+fn spell_damage_can_be_rolled() {
+    let mut app = sync_app();
+
+    let output = app.command("Fireball").unwrap();
+    assert!(output.contains("takes `8d6` fire damage"), "{}", output);
+
+    let output = app.command("8d6").unwrap();
+    assert!(output.contains(" = **"), "{}", output);
 }
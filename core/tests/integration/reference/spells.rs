@@ -22,3 +22,27 @@ fn spells() {
         sync_app().autocomplete("Spells"),
     );
 }
+
+#[test]
+fn spells_by_level_and_school() {
+    let output = sync_app().command("srd spells level 1").unwrap();
+    assert!(output.starts_with("# Level 1 Spells\n"), "{}", output);
+    assert!(
+        output
+            .lines()
+            .skip(1)
+            .take_while(|line| line.starts_with("* "))
+            .all(|line| line.contains("(1st-level ")),
+        "{}",
+        output,
+    );
+
+    let output = sync_app().command("spells school abjuration").unwrap();
+    assert!(output.starts_with("# Abjuration Spells\n"), "{}", output);
+    assert!(
+        output.contains("* `Alarm` (1st-level abjuration)"),
+        "{}",
+        output
+    );
+    assert!(!output.contains("evocation"), "{}", output);
+}
@@ -44,11 +44,11 @@ You have superior vision in dark and dim conditions. You can see in dim light wi
         sync_app().command("srd trait Darkvision").unwrap(),
     );
 
-    assert_eq!(2, sync_app().autocomplete("darkvision").len());
-    assert!(sync_app()
-        .autocomplete("darkvision")
-        .iter()
-        .any(|suggestion| suggestion.term == "Darkvision" && suggestion.summary == "SRD trait"));
+    // The trait shares its name with a spell, which takes precedence in autocomplete.
+    assert_eq!(
+        vec![AutocompleteSuggestion::new("Darkvision", "SRD spell")],
+        sync_app().autocomplete("darkvision"),
+    );
 }
 
 #[test]
@@ -0,0 +1,41 @@
+use crate::common::sync_app;
+
+#[test]
+fn generate_treasure() {
+    let mut app = sync_app();
+    app.command("seed 1").unwrap();
+
+    let output = app.command("generate treasure 5").unwrap();
+    assert!(
+        output.starts_with("# Treasure (CR 5)\n\n**Coins:** "),
+        "{}",
+        output,
+    );
+    assert!(output.contains(" sp, "), "{}", output);
+
+    // Enough rolls to find a magic item, which links to its SRD entry.
+    let output = (0..50)
+        .map(|_| app.command("generate treasure 20").unwrap())
+        .find(|output| output.contains("**Magic item:**"))
+        .unwrap();
+    let command = output
+        .split_once("**Magic item:** `")
+        .and_then(|(_, rest)| rest.split_once('`'))
+        .map(|(command, _)| command.to_string())
+        .unwrap();
+    assert!(command.starts_with("srd magic item "), "{}", output);
+    assert!(app
+        .command(&command)
+        .unwrap()
+        .contains(" is Open Game Content subject to the `Open Game License`."));
+}
+
+#[test]
+fn generate_treasure_errors() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "\"31\" is not a challenge rating. Try a number from 0 to 30, eg. `generate treasure 5`.",
+        app.command("generate treasure 31").unwrap_err(),
+    );
+}
@@ -1,5 +1,5 @@
 use crate::common::{sync_app, sync_app_with_data_store};
-use initiative_core::NullDataStore;
+use initiative_core::{MemoryDataStore, NullDataStore};
 
 #[test]
 fn npc_can_be_deleted_from_temp() {
@@ -116,3 +116,26 @@ fn delete_works_with_unusable_data_store() {
         app.command("redo").unwrap(),
     );
 }
+
+#[test]
+fn npc_can_be_deleted_by_uuid() {
+    let data_store = MemoryDataStore::default();
+    let mut app = sync_app_with_data_store(data_store.clone());
+
+    app.command("npc named Potato Johnson").unwrap();
+    let uuid = *data_store.things.borrow().keys().next().unwrap();
+
+    assert_eq!(
+        "Potato Johnson was successfully deleted. Use `undo` to reverse this.",
+        app.command(&format!("delete {}", uuid)).unwrap(),
+    );
+    assert!(data_store.things.borrow().is_empty());
+
+    assert_eq!(
+        format!("There is no entity named \"{}\".", uuid),
+        app.command(&format!("delete {}", uuid)).unwrap_err(),
+    );
+
+    app.command("undo").unwrap();
+    app.command("Potato Johnson").unwrap();
+}
@@ -1,4 +1,4 @@
-use crate::common::sync_app_with_data_store;
+use crate::common::{get_name, sync_app_with_data_store};
 use initiative_core::{MemoryDataStore, NullDataStore};
 
 #[test]
@@ -29,6 +29,25 @@ fn npc_is_saved_to_storage() {
     );
 }
 
+#[test]
+fn saved_npc_can_be_loaded_after_restart() {
+    let data_store = MemoryDataStore::default();
+
+    let (npc_name, npc_details) = {
+        let mut app = sync_app_with_data_store(data_store.clone());
+        let npc_name = get_name(&app.command("npc").unwrap());
+        app.command(&format!("save {}", npc_name)).unwrap();
+        let npc_details = app.command(&format!("load {}", npc_name)).unwrap();
+        (npc_name, npc_details)
+    };
+
+    let mut app = sync_app_with_data_store(data_store);
+    assert_eq!(
+        npc_details,
+        app.command(&format!("load {}", npc_name)).unwrap(),
+    );
+}
+
 #[test]
 fn npc_is_saved_to_storage_by_alias() {
     let data_store = MemoryDataStore::default();
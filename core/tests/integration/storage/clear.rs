@@ -0,0 +1,40 @@
+use crate::common::sync_app;
+
+#[test]
+fn it_requires_confirmation() {
+    let mut app = sync_app();
+
+    app.command("a character named Aragorn").unwrap();
+
+    assert_eq!(
+        "This will permanently delete the 1 entry in your journal, forget your recent results and reset the time. It can't be undone, so to go ahead, use `clear world confirm`.",
+        app.command("clear world").unwrap_err(),
+    );
+    assert!(app.command("Aragorn").is_ok());
+}
+
+#[test]
+fn it_clears_everything() {
+    let mut app = sync_app();
+
+    app.command("a character named Aragorn").unwrap();
+    app.command("an inn named The Prancing Pony").unwrap();
+    app.command("npc").unwrap();
+    app.command("+1d").unwrap();
+
+    assert_eq!(
+        "The world was cleared. 2 entries were deleted from your journal, and the time was reset.",
+        app.command("clear world confirm").unwrap(),
+    );
+
+    assert_eq!(
+        "*There are no NPCs in your journal.*",
+        app.command("list npcs").unwrap(),
+    );
+    assert!(app.command("Aragorn").is_err());
+    assert_eq!(
+        app.command("now").unwrap(),
+        sync_app().command("now").unwrap(),
+    );
+    assert!(app.command("undo").is_err());
+}
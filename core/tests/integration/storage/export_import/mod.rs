@@ -1,12 +1,11 @@
 use crate::common::{sync_app, sync_app_with_dispatcher, SyncApp};
 use initiative_core::Event;
+use std::sync::Mutex;
 
-static mut LAST_EVENT: Option<Event> = None;
+static LAST_EVENT: Mutex<Option<Event>> = Mutex::new(None);
 
 fn event_dispatcher(event: Event) {
-    unsafe {
-        LAST_EVENT = Some(event);
-    }
+    *LAST_EVENT.lock().unwrap() = Some(event);
 }
 
 fn inspect_journal(app: &mut SyncApp) -> String {
@@ -41,14 +40,10 @@ fn export() {
     app.command("+1d").unwrap();
     app.command("export").unwrap();
 
-    let data = unsafe {
-        if let Some(Event::Export(data)) = &LAST_EVENT {
-            Some(data)
-        } else {
-            None
-        }
-    }
-    .unwrap();
+    let data = match LAST_EVENT.lock().unwrap().take() {
+        Some(Event::Export(data)) => data,
+        _ => panic!("Expected an export event."),
+    };
 
     assert_eq!(2, data.things.len());
 
@@ -80,8 +75,61 @@ fn import_event() {
         app.command("import").unwrap(),
     );
 
-    let event = unsafe { &LAST_EVENT };
-    assert!(matches!(event, Some(Event::Import)));
+    assert!(matches!(
+        LAST_EVENT.lock().unwrap().take(),
+        Some(Event::Import { overwrite: false }),
+    ));
+
+    app.command("import overwrite").unwrap();
+    assert!(matches!(
+        LAST_EVENT.lock().unwrap().take(),
+        Some(Event::Import { overwrite: true }),
+    ));
+}
+
+#[test]
+fn import_skips_existing_entries() {
+    let mut app = sync_app();
+    app.bulk_import(
+        serde_json::from_str(include_str!("v2.json")).unwrap(),
+        false,
+    )
+    .unwrap();
+
+    assert_eq!(
+        "Places: 5 skipped \\\nCharacters: 5 skipped \\\nKey/values: 1 skipped\n\n_Entries that already exist in your journal were skipped. To replace them, use `import overwrite`._",
+        app.bulk_import(serde_json::from_str(include_str!("v2.json")).unwrap(), false)
+            .unwrap(),
+    );
+
+    app.command("Faman Halin is named Frank").unwrap();
+
+    assert_eq!(
+        "Places: 5 updated \\\nCharacters: 5 updated \\\nKey/values: 1 updated",
+        app.bulk_import(serde_json::from_str(include_str!("v2.json")).unwrap(), true)
+            .unwrap(),
+    );
+
+    app.command("Faman Halin").unwrap();
+    assert!(app.command("Frank").is_err());
+}
+
+#[test]
+fn import_skips_name_collisions() {
+    let mut app = sync_app();
+    app.command("npc named Faman Halin").unwrap();
+
+    let output = app
+        .bulk_import(
+            serde_json::from_str(include_str!("v2.json")).unwrap(),
+            false,
+        )
+        .unwrap();
+    assert!(
+        output.starts_with("Places: 5 created \\\nCharacters: 4 created, 1 skipped"),
+        "{}",
+        output,
+    );
 }
 
 #[test]
@@ -90,18 +138,15 @@ fn export_and_import() {
         let mut app = sync_app_with_dispatcher(&event_dispatcher);
         app.command("inn named Foo").unwrap();
         app.command("npc named Blah").unwrap();
+        app.command("note add Blah: owes the party 50gp").unwrap();
         app.command("+1d").unwrap();
         app.command("export").unwrap();
 
         (
-            unsafe {
-                if let Some(Event::Export(data)) = LAST_EVENT.take() {
-                    Some(data)
-                } else {
-                    None
-                }
-            }
-            .unwrap(),
+            match LAST_EVENT.lock().unwrap().take() {
+                Some(Event::Export(data)) => data,
+                _ => panic!("Expected an export event."),
+            },
             inspect_journal(&mut app),
         )
     };
@@ -110,7 +155,7 @@ fn export_and_import() {
         let mut app = sync_app_with_dispatcher(&event_dispatcher);
         assert_eq!(
             "Places: 1 created \\\nCharacters: 1 created \\\nKey/values: 1 created",
-            app.bulk_import(backup_data).unwrap(),
+            app.bulk_import(backup_data, false).unwrap(),
         );
         inspect_journal(&mut app)
     };
@@ -127,7 +172,7 @@ fn bulk_import_v1() {
 
     assert_eq!(
         "Places: 5 created \\\nCharacters: 5 created \\\nKey/values: 1 created",
-        app.bulk_import(backup_data).unwrap(),
+        app.bulk_import(backup_data, false).unwrap(),
     );
 
     assert_eq!(
@@ -165,7 +210,7 @@ fn bulk_import_v2() {
 
     assert_eq!(
         "Places: 5 created \\\nCharacters: 5 created \\\nKey/values: 1 created",
-        app.bulk_import(backup_data).unwrap(),
+        app.bulk_import(backup_data, false).unwrap(),
     );
 
     assert_eq!(
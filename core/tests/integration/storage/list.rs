@@ -0,0 +1,93 @@
+use crate::common::sync_app;
+
+#[test]
+fn it_lists_things_by_kind() {
+    let mut app = sync_app();
+
+    app.command("a kingdom named Gondor").unwrap();
+    app.command("an inn named The Prancing Pony").unwrap();
+    app.command("a character named Aragorn").unwrap();
+
+    let output = app.command("list regions").unwrap();
+    assert!(
+        output.starts_with("# Regions\n\n👑 `Gondor` (kingdom) ("),
+        "{}",
+        output
+    );
+    assert!(output.ends_with("\n\n*1 in total.*"), "{}", output);
+
+    let output = app.command("list places").unwrap();
+    assert!(output.contains("`Gondor`"), "{}", output);
+    assert!(output.contains("`The Prancing Pony`"), "{}", output);
+    assert!(!output.contains("`Aragorn`"), "{}", output);
+
+    let output = app.command("list npcs").unwrap();
+    assert!(output.starts_with("# NPCs\n\n"), "{}", output);
+    assert!(output.contains("`Aragorn`"), "{}", output);
+
+    assert_eq!(
+        "*There are no locations in your journal.*",
+        app.command("list locations").unwrap(),
+    );
+}
+
+#[test]
+fn it_caps_long_lists() {
+    let mut app = sync_app();
+
+    for i in 0..55 {
+        app.command(&format!("an inn named Wren {}", i)).unwrap();
+    }
+
+    let output = app.command("list buildings").unwrap();
+    assert_eq!(
+        "*Showing 1-50 of 55 buildings. Use `list buildings page 2` to see more, or `search [name]` to narrow it down.*",
+        output.lines().last().unwrap(),
+    );
+}
+
+#[test]
+fn it_pages_long_lists() {
+    let mut app = sync_app();
+
+    for i in 0..55 {
+        app.command(&format!("an inn named Wren {:02}", i)).unwrap();
+    }
+
+    // Every entry appears exactly once across the pages, in order.
+    let names: Vec<String> = ["list buildings", "list buildings page 2"]
+        .into_iter()
+        .flat_map(|command| {
+            app.command(command)
+                .unwrap()
+                .split('`')
+                .skip(1)
+                .step_by(2)
+                .filter(|s| s.starts_with("Wren"))
+                .map(|s| s.to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    assert_eq!(
+        (0..55)
+            .map(|i| format!("Wren {:02}", i))
+            .collect::<Vec<_>>(),
+        names,
+    );
+
+    let output = app.command("list buildings page 2").unwrap();
+    assert_eq!(
+        "*Showing 51-55 of 55 buildings.*",
+        output.lines().last().unwrap(),
+    );
+
+    assert_eq!(
+        "There are only 2 pages of buildings.",
+        app.command("list buildings page 3").unwrap_err(),
+    );
+
+    assert_eq!(
+        "There is no page 0. Pages start at 1.",
+        app.command("list buildings page 0").unwrap_err(),
+    );
+}
@@ -54,7 +54,7 @@ fn npc_can_be_loaded_from_storage_with_location() {
     let mut app = sync_app();
     let backup_data = serde_json::from_str(include_str!("export_import/v2.json")).unwrap();
 
-    app.bulk_import(backup_data).unwrap();
+    app.bulk_import(backup_data, false).unwrap();
 
     assert_eq!(
         "<div class=\"thing-box npc\">
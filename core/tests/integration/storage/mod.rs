@@ -1,7 +1,12 @@
 mod change;
+mod clear;
 mod export_import;
 mod journal;
+mod list;
 mod load;
+mod recent;
+mod search;
+mod stats;
 mod undo_redo;
 
 use crate::common::SyncApp;
@@ -19,6 +24,7 @@ fn startup_error_with_unusable_data_store() {
             "{}",
             output,
         );
+        assert!(!output.contains("_Tip: "), "{}", output);
     }
 
     {
@@ -0,0 +1,110 @@
+use crate::common::{get_name, sync_app};
+
+#[test]
+fn save_by_number() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "You haven't generated anything yet. Try `npc` first.",
+        app.command("recent").unwrap_err(),
+    );
+
+    let first = get_name(&app.command("npc").unwrap());
+    let second = get_name(&app.command("inn").unwrap());
+    let third = get_name(&app.command("npc").unwrap());
+
+    // Saving doesn't change the numbering.
+    assert_eq!(
+        format!(
+            "{} was successfully saved. Use `undo` to reverse this.",
+            first,
+        ),
+        app.command("save 1").unwrap(),
+    );
+    assert_eq!(
+        format!(
+            "{} was successfully saved. Use `undo` to reverse this.",
+            second,
+        ),
+        app.command("save 2").unwrap(),
+    );
+
+    let output = app.command("recent").unwrap();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!("# Recently generated", lines[0], "{}", output);
+    assert!(lines[2].starts_with("**1** "), "{}", output);
+    assert!(lines[2].contains(&format!("`{}`", first)), "{}", output);
+    assert!(lines[3].contains(&format!("`{}`", second)), "{}", output);
+    assert!(lines[4].starts_with("**3** "), "{}", output);
+    assert!(lines[4].contains(&format!("`{}`", third)), "{}", output);
+    assert_eq!(
+        "_Refer to these by number, eg. `save 3` saves the most recent. The numbers stay the same as you generate more._",
+        lines[6],
+    );
+
+    // Numbers work anywhere a name does.
+    assert!(app.command("describe 3").unwrap().starts_with(&third));
+}
+
+#[test]
+fn numbers_are_stable() {
+    let mut app = sync_app();
+
+    let first = get_name(&app.command("npc").unwrap());
+    assert!(app
+        .command("load 1")
+        .unwrap()
+        .contains(&format!("# {}", first)));
+
+    app.command("npc").unwrap();
+    app.command("npc x3").unwrap();
+    assert!(app
+        .command("load 1")
+        .unwrap()
+        .contains(&format!("# {}", first)));
+}
+
+#[test]
+fn lists_count_as_generated() {
+    let mut app = sync_app();
+    app.command("npc").unwrap();
+
+    // List results keep their own shortcuts, but are numbered in recent history after the npc.
+    let output = app.command("npc x3").unwrap();
+    let last = output
+        .lines()
+        .find(|line| line.starts_with("~3~ "))
+        .unwrap();
+    let saved = app.command("save 4").unwrap();
+    let saved_name = saved.split(" was successfully saved").next().unwrap();
+    assert!(last.contains(&format!("`{}`", saved_name)), "{}", output);
+
+    let name = get_name(&app.command("npc").unwrap());
+    app.command(&format!("rename {} to Potato Johnson", name))
+        .unwrap();
+    assert!(app.command("recent").unwrap().contains("**5** "));
+    assert!(app.command("load 5").unwrap().contains("# Potato Johnson"));
+}
+
+#[test]
+fn clear_forgets_generated() {
+    let mut app = sync_app();
+
+    let name = get_name(&app.command("npc").unwrap());
+    app.command(&format!("initiative add {}", name)).unwrap();
+    app.command("initiative add Goblin").unwrap();
+    app.command("clear world confirm").unwrap();
+
+    assert_eq!(
+        "You haven't generated anything yet. Try `npc` first.",
+        app.command("recent").unwrap_err(),
+    );
+    assert_eq!(
+        "There is no entity named \"1\".",
+        app.command("save 1").unwrap_err(),
+    );
+
+    let output = app.command("initiative").unwrap();
+    assert!(!output.contains(&name), "{}", output);
+    assert!(output.contains("Goblin"), "{}", output);
+}
@@ -0,0 +1,69 @@
+use crate::common::sync_app;
+
+#[test]
+fn it_ranks_results_by_relevance() {
+    let mut app = sync_app();
+
+    for name in [
+        "Oldlantern",
+        "Brass Lantern",
+        "Lantern Hall",
+        "Lantern",
+        "Crown",
+    ] {
+        app.command(&format!("an inn named {}", name)).unwrap();
+    }
+
+    let output = app.command("search LANTERN").unwrap();
+    let names: Vec<&str> = output
+        .lines()
+        .skip(2)
+        .map(|line| line.split('`').nth(1).unwrap())
+        .collect();
+
+    assert!(
+        output.starts_with("# Search results for \"LANTERN\"\n\n"),
+        "{}",
+        output,
+    );
+    assert_eq!(
+        vec!["Lantern", "Lantern Hall", "Brass Lantern", "Oldlantern"],
+        names,
+    );
+    assert!(output.contains(" (place, "), "{}", output);
+}
+
+#[test]
+fn it_caps_results() {
+    let mut app = sync_app();
+
+    for i in 0..12 {
+        app.command(&format!("an inn named Wren {}", i)).unwrap();
+    }
+
+    let output = app.command("search wren").unwrap();
+    assert_eq!(
+        "*Showing 1-10 of 12 results. Use `search wren page 2` to see more, or try a more specific search.*",
+        output.lines().last().unwrap(),
+    );
+
+    let output = app.command("search wren page 2").unwrap();
+    assert_eq!(2, output.matches("`Wren ").count(), "{}", output);
+    assert_eq!(
+        "*Showing 11-12 of 12 results.*",
+        output.lines().last().unwrap(),
+    );
+
+    assert_eq!(
+        "There is no page 0. Pages start at 1.",
+        app.command("search wren page 0").unwrap_err(),
+    );
+}
+
+#[test]
+fn it_shows_a_message_when_nothing_matches() {
+    assert_eq!(
+        "Nothing in your journal matches \"potato\".",
+        sync_app().command("search potato").unwrap(),
+    );
+}
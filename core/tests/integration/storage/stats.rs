@@ -0,0 +1,39 @@
+use crate::common::sync_app;
+
+#[test]
+fn world_stats() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "*Your journal is empty, so there's nothing to count yet.*",
+        app.command("world stats").unwrap(),
+    );
+
+    app.command("an elf named Legolas").unwrap();
+    assert_eq!(
+        "# World stats\n\n**Characters:** 1 (1 elf)\\\n**Buildings:** 0\\\n**Locations:** 0\\\n**Regions:** 0\n\n*1 journal entry in total.*",
+        app.command("world stats").unwrap(),
+    );
+
+    app.command("a kingdom named Gondor").unwrap();
+    app.command("a forest named Fangorn").unwrap();
+    app.command("a forest named Mirkwood").unwrap();
+    app.command("an inn named The Prancing Pony").unwrap();
+    app.command("a dwarf named Gimli").unwrap();
+    app.command("a dwarf named Gloin").unwrap();
+
+    // Unsaved things aren't counted.
+    app.command("elf").unwrap();
+
+    assert_eq!(
+        "# World stats\n\n**Characters:** 3 (2 dwarf, 1 elf)\\\n**Buildings:** 1 (1 inn)\\\n**Locations:** 0\\\n**Regions:** 3 (2 forest, 1 kingdom)\n\n*7 journal entries in total.*",
+        app.command("stats").unwrap(),
+    );
+
+    // Places without a type still add up to the total.
+    app.command("a place named Somewhere").unwrap();
+    assert_eq!(
+        "# World stats\n\n**Characters:** 3 (2 dwarf, 1 elf)\\\n**Buildings:** 1 (1 inn)\\\n**Locations:** 0\\\n**Regions:** 3 (2 forest, 1 kingdom)\\\n**Other places:** 1 (1 place)\n\n*8 journal entries in total.*",
+        app.command("stats").unwrap(),
+    );
+}
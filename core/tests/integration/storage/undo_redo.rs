@@ -141,3 +141,30 @@ fn set_key_value() {
     assert_eq!(a, c);
     assert_eq!(b, d);
 }
+
+#[test]
+fn redo_is_cleared_by_new_change() {
+    let mut app = sync_app();
+    app.command("character named Potato Johnson").unwrap();
+    app.command("delete Potato Johnson").unwrap();
+    app.command("undo").unwrap();
+
+    app.command("Potato Johnson is an elf").unwrap();
+
+    assert_eq!("Nothing to redo.", app.command("redo").unwrap_err());
+    assert_eq!(
+        Some(&AutocompleteSuggestion::new("redo", "Nothing to redo.")),
+        app.autocomplete("redo").first(),
+    );
+
+    app.command("undo").unwrap();
+    assert_eq!(
+        "Successfully redid editing Potato Johnson. Use `undo` to reverse this.",
+        app.command("redo")
+            .unwrap()
+            .lines()
+            .last()
+            .unwrap()
+            .trim_matches('_'),
+    );
+}
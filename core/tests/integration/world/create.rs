@@ -158,6 +158,63 @@ fn create_with_unknown_words() {
     }
 }
 
+#[test]
+fn create_with_age() {
+    let mut app = sync_app();
+
+    let output = app.command("elf age 5").unwrap();
+    assert!(output.contains("**Age:** 5 years"), "{}", output);
+    assert!(output.contains("elf child"), "{}", output);
+}
+
+#[test]
+fn create_half_elf_without_hyphen() {
+    let mut app = sync_app();
+
+    for input in ["half-elf", "a half elf", "half orc"] {
+        let output = app.command(input).unwrap();
+        assert!(output.contains("half-"), "{}\n\n{}", input, output);
+    }
+}
+
+#[test]
+fn create_dragonborn_with_ancestry() {
+    let mut app = sync_app();
+
+    let output = app.command("a red dragonborn").unwrap();
+    assert!(
+        output.contains("**Draconic ancestry:** red dragon (fire)"),
+        "{}",
+        output,
+    );
+
+    let output = app.command("dragonborn").unwrap();
+    assert!(output.contains("**Draconic ancestry:** "), "{}", output);
+
+    let output = app.command("human").unwrap();
+    assert!(!output.contains("**Draconic ancestry:** "), "{}", output);
+}
+
+#[test]
+fn create_with_unknown_species() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "\"potato\" is not a recognized species. Try one of: dragonborn, dwarf, elf, gnome, half-elf, half-orc, halfling, human, tiefling.",
+        app.command("potato npc").unwrap_err(),
+    );
+
+    assert_eq!(
+        "\"Potato\" is not a recognized species. Try one of: dragonborn, dwarf, elf, gnome, half-elf, half-orc, halfling, human, tiefling.",
+        app.command("create Potato person").unwrap_err(),
+    );
+
+    {
+        let output = app.command("elf npc").unwrap();
+        assert!(output.contains("elf"), "{}", output);
+    }
+}
+
 #[test]
 fn generate_location_with_no_name_generator() {
     let mut app = sync_app();
@@ -177,3 +234,42 @@ fn generate_location_with_no_name_generator() {
         assert!(output.contains("# Narnia"), "{}", output);
     }
 }
+
+#[test]
+fn create_shop_with_inventory() {
+    let output = sync_app()
+        .command("general-store named The Sundry Shed")
+        .unwrap();
+    assert!(output.contains("\n\n**Inventory:**\n`"), "{}", output);
+}
+
+#[test]
+fn age_range() {
+    let mut app = sync_app();
+
+    for _ in 0..10 {
+        let output = app.command("human age 40-20").unwrap();
+        let age_years: u16 = output
+            .split_once("**Age:** ")
+            .and_then(|(_, s)| s.split_once(" years"))
+            .and_then(|(s, _)| s.parse().ok())
+            .unwrap();
+        assert!((20..=40).contains(&age_years), "{}", output);
+        assert!(!output.contains("! "), "{}", output);
+    }
+
+    let output = app.command("human aged 90-120").unwrap();
+    assert!(
+        output.contains("\n\n! A human rarely lives past 79 years, so that age range is unusual."),
+        "{}",
+        output,
+    );
+
+    let output = app.command("elderly human age 20-30").unwrap();
+    assert!(output.contains("*elderly human"), "{}", output);
+    assert!(
+        output.contains("\n\n! The age range 20-30 was ignored because an age was also given."),
+        "{}",
+        output,
+    );
+}
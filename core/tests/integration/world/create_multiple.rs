@@ -124,3 +124,65 @@ fn numeric_aliases() {
         generated_output,
     );
 }
+
+#[test]
+fn count() {
+    let mut app = sync_app();
+    let output = app.command("npc x5").unwrap();
+
+    assert!(output.starts_with("# 5 × person\n\n~1~ "), "{}", output);
+    assert!(output.contains("\\\n~5~ "), "{}", output);
+    assert!(!output.contains("~6~"), "{}", output);
+
+    let names: Vec<_> = output
+        .lines()
+        .filter(|line| line.starts_with('~'))
+        .map(|line| line.split('`').nth(1).unwrap().to_string())
+        .collect();
+    assert_eq!(5, names.len(), "{}", output);
+
+    let digit_outputs: Vec<_> = (1..=5).map(|i| app.command(&i.to_string())).collect();
+
+    for (name, digit_output) in names.iter().zip(digit_outputs) {
+        assert_eq!(app.command(&format!("load {}", name)), digit_output);
+        assert!(app.command(&format!("save {}", name)).is_ok(), "{}", name);
+    }
+}
+
+#[test]
+fn count_limit() {
+    let mut app = sync_app();
+
+    let output = app.command("npc x20").unwrap();
+    assert_eq!(
+        20,
+        output.lines().filter(|line| line.contains("` (")).count(),
+        "{}",
+        output,
+    );
+
+    assert_eq!(
+        Err("You can only create between 1 and 20 at a time.".to_string()),
+        app.command("npc x21"),
+    );
+    assert_eq!(
+        Err("You can only create between 1 and 20 at a time.".to_string()),
+        app.command("create npc x0"),
+    );
+}
+
+#[test]
+fn count_with_duplicate_names() {
+    let mut app = sync_app();
+
+    let output = app.command("a dwarf named Bob x3").unwrap();
+    let names: Vec<_> = output
+        .lines()
+        .filter(|line| line.starts_with('~'))
+        .map(|line| line.split('`').nth(1).unwrap().to_string())
+        .collect();
+    assert_eq!(vec!["Bob", "Bob (2)", "Bob (3)"], names, "{}", output);
+
+    let output = app.command("load Bob (3)").unwrap();
+    assert!(output.contains("# Bob (3)"), "{}", output);
+}
@@ -0,0 +1,82 @@
+use crate::common::{get_name, sync_app};
+
+#[test]
+fn place_population_is_displayed() {
+    let output = sync_app()
+        .command("a dwarvish kingdom named Stonehold")
+        .unwrap();
+
+    assert!(
+        output.contains("\n\n**Population:** 80% dwarf, "),
+        "{}",
+        output,
+    );
+}
+
+#[test]
+fn npcs_are_generated_with_local_demographics() {
+    let mut app = sync_app();
+    app.command("a dwarvish kingdom named Stonehold").unwrap();
+
+    let output = app.command("npc in Stonehold").unwrap();
+    assert!(
+        output.contains("**Location:** 👑 `Stonehold` (kingdom)"),
+        "{}",
+        output,
+    );
+
+    let name = get_name(&output);
+    assert!(
+        app.command(&format!("load {}", name))
+            .unwrap()
+            .contains("**Location:** 👑 `Stonehold` (kingdom)"),
+        "{}",
+        name,
+    );
+
+    // Seeded so that the proportion of dwarves is deterministic.
+    app.command("seed 0").unwrap();
+    let output = app.command("npc in Stonehold x20").unwrap();
+    assert!(output.matches(" dwarf, ").count() >= 10, "{}", output);
+}
+
+#[test]
+fn unsaved_location_is_not_recognized() {
+    let mut app = sync_app();
+    let name = get_name(&app.command("inn").unwrap());
+
+    assert_eq!(
+        Err(format!("Unknown command: \"npc in {}\"", name)),
+        app.command(&format!("npc in {}", name)),
+    );
+
+    app.command(&format!("save {}", name)).unwrap();
+    let output = app.command(&format!("npc in {}", name)).unwrap();
+    assert!(output.contains("**Location:**"), "{}", output);
+}
+
+#[test]
+fn set_and_show_demographics() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "Characters will now be generated with these demographics: 100% elf.",
+        app.command("set demographics elf 1 human 0").unwrap(),
+    );
+    assert_eq!(
+        "Characters are currently generated with these demographics: 100% elf.",
+        app.command("show demographics").unwrap(),
+    );
+
+    let output = app.command("npc").unwrap();
+    assert!(output.contains("elf"), "{}", output);
+
+    assert_eq!(
+        "\"-1\" is not a valid weight. Weights must be whole numbers of zero or more.",
+        app.command("set demographics human -1").unwrap_err(),
+    );
+    assert!(app
+        .command("set demographics owlbear 5")
+        .unwrap_err()
+        .starts_with("\"owlbear\" is not a recognized species. Try one of: "));
+}
@@ -0,0 +1,76 @@
+use crate::common::{get_name, sync_app};
+
+#[test]
+fn describe_npc() {
+    let mut app = sync_app();
+    let name = get_name(&app.command("npc").unwrap());
+
+    let output = app.command(&format!("describe {}", name)).unwrap();
+    assert!(output.starts_with(&format!("{} is a", name)), "{}", output);
+    assert!(output.ends_with('.'), "{}", output);
+    assert!(!output.contains("unknown"), "{}", output);
+    assert!(!output.contains('`'), "{}", output);
+}
+
+#[test]
+fn describe_npc_quirk() {
+    let mut app = sync_app();
+    let output = app.command("npc").unwrap();
+    let name = get_name(&output);
+    assert!(output.contains("\\\n**Quirk:** "), "{}", output);
+
+    let quirk = output
+        .split("**Quirk:** ")
+        .nth(1)
+        .and_then(|s| s.split(['\\', '\n']).next())
+        .unwrap()
+        .split_once(' ')
+        .unwrap()
+        .1
+        .to_string();
+
+    let output = app.command(&format!("describe {}", name)).unwrap();
+    assert!(output.contains(&format!(" {}.", quirk)), "{}", output);
+}
+
+#[test]
+fn describe_errors() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "There is no character named \"Potato Johnson\".",
+        app.command("describe Potato Johnson").unwrap_err(),
+    );
+
+    app.command("an inn named The Prancing Pony").unwrap();
+    assert_eq!(
+        "`The Prancing Pony` is not a character. Try `The Prancing Pony` to see its details instead.",
+        app.command("describe the prancing pony").unwrap_err(),
+    );
+}
+
+#[test]
+fn describe_npc_with_metric_units() {
+    let mut app = sync_app();
+    let name = get_name(&app.command("human adult").unwrap());
+
+    let output = app.command(&format!("describe {}", name)).unwrap();
+    assert!(output.contains(" lbs"), "{}", output);
+
+    assert_eq!(
+        "Measurements will now be shown in metric units.",
+        app.command("set units metric").unwrap(),
+    );
+
+    let output = app.command(&format!("describe {}", name)).unwrap();
+    assert!(output.contains(" cm tall and weighing "), "{}", output);
+    assert!(output.contains(" kg"), "{}", output);
+
+    let output = app.command(&format!("load {}", name)).unwrap();
+    assert!(output.contains("**Size:** "), "{}", output);
+    assert!(output.contains(" cm, "), "{}", output);
+
+    app.command("set units imperial").unwrap();
+    let output = app.command(&format!("describe {}", name)).unwrap();
+    assert!(output.contains(" lbs"), "{}", output);
+}
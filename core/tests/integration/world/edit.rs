@@ -46,6 +46,25 @@ fn edit_npc() {
     app.command("Joe").unwrap();
 }
 
+#[test]
+fn edit_npc_species_changes_age_category() {
+    let mut app = sync_app();
+
+    let output = app.command("a human aged 120 named Roger").unwrap();
+    assert!(output.contains("*geriatric human"), "{}", output);
+
+    let output = app.command("Roger is an elf").unwrap();
+    assert!(output.contains("*young adult elf"), "{}", output);
+    assert!(output.contains("**Age:** 120 years"), "{}", output);
+
+    let output = app.command("undo").unwrap();
+    assert!(output.contains("*geriatric human"), "{}", output);
+
+    // An explicit age category wins.
+    let output = app.command("Roger is elderly").unwrap();
+    assert!(output.contains("*elderly human"), "{}", output);
+}
+
 #[test]
 fn edit_place() {
     let mut app = sync_app();
@@ -0,0 +1,51 @@
+use crate::common::sync_app;
+
+#[test]
+fn generate_thing() {
+    let mut app = sync_app();
+
+    let output = app.command("generate npc").unwrap();
+    assert!(output.contains("**Species:**"), "{}", output);
+
+    let output = app
+        .command("generate tavern named The Drunken Dragon")
+        .unwrap();
+    assert!(output.contains("# The Drunken Dragon\n*inn*"), "{}", output);
+
+    let output = app.command("generate elf x3").unwrap();
+    assert!(output.starts_with("# 3 × elf\n"), "{}", output);
+}
+
+#[test]
+fn generate_region_suffix() {
+    let mut app = sync_app();
+
+    let output = app
+        .command("generate forest region named Mirkwood")
+        .unwrap();
+    assert!(output.contains("# Mirkwood\n*forest*"), "{}", output);
+
+    let output = app.command("generate mountains region").unwrap();
+    assert!(output.contains("\n*mountain*\n"), "{}", output);
+}
+
+#[test]
+fn generate_ambiguous() {
+    let mut app = sync_app();
+
+    // A cave is a location, not a region, no matter how it's asked for.
+    let output = app.command("generate cave named The Underdark").unwrap();
+    assert!(output.contains("# The Underdark\n*cave*"), "{}", output);
+
+    let error = app.command("generate cave region").unwrap_err();
+    assert!(
+        error.starts_with("\"cave\" is not a recognized type of region."),
+        "{}",
+        error,
+    );
+
+    // "generate town" is always a whole town, never a lone place called a town.
+    let output = app.command("generate town named Phandalin").unwrap();
+    assert!(output.contains("Phandalin"), "{}", output);
+    assert!(app.command("journal").unwrap().contains("`Phandalin`"));
+}
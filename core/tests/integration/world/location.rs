@@ -0,0 +1,77 @@
+use crate::common::{get_name, sync_app};
+
+#[test]
+fn who_is_at_lists_characters() {
+    let mut app = sync_app();
+    app.command("an inn named The Prancing Pony").unwrap();
+
+    assert_eq!(
+        "Nobody is at `The Prancing Pony` yet. To add someone, try `npc in The Prancing Pony`.",
+        app.command("who is at the prancing pony").unwrap(),
+    );
+
+    let barliman = get_name(&app.command("npc in The Prancing Pony").unwrap());
+    app.command(&format!("save {}", barliman)).unwrap();
+    let nob = get_name(&app.command("npc in The Prancing Pony").unwrap());
+    app.command("npc").unwrap();
+
+    let output = app.command("who's at The Prancing Pony").unwrap();
+    assert!(
+        output.starts_with("# Characters at The Prancing Pony\n\n"),
+        "{}",
+        output,
+    );
+    assert!(output.contains(&format!("`{}`", barliman)), "{}", output);
+    assert!(output.contains(&format!("`{}`", nob)), "{}", output);
+    assert_eq!(4, output.lines().count(), "{}", output);
+}
+
+#[test]
+fn who_is_at_errors() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "There is no place named \"Mordor\".",
+        app.command("who is at Mordor").unwrap_err(),
+    );
+
+    let name = get_name(&app.command("npc").unwrap());
+    assert_eq!(
+        format!("`{}` is not a place.", name),
+        app.command(&format!("who is at {}", name)).unwrap_err(),
+    );
+
+    let name = get_name(&app.command("inn").unwrap());
+    assert_eq!(
+        format!(
+            "Nobody can be at `{}` until it has been saved. Use `save {}` to save it to your `journal`.",
+            name, name,
+        ),
+        app.command(&format!("who is at {}", name)).unwrap_err(),
+    );
+}
+
+#[test]
+fn deleting_a_location_is_handled_gracefully() {
+    let mut app = sync_app();
+    app.command("an inn named The Prancing Pony").unwrap();
+
+    let name = get_name(&app.command("npc in The Prancing Pony").unwrap());
+    app.command(&format!("save {}", name)).unwrap();
+
+    assert_eq!(
+        "The Prancing Pony was successfully deleted. Use `undo` to reverse this.\n\n! There was 1 entry located in The Prancing Pony, which no longer has a location.",
+        app.command("delete The Prancing Pony").unwrap(),
+    );
+
+    let output = app.command(&format!("load {}", name)).unwrap();
+    assert!(!output.contains("**Location:**"), "{}", output);
+
+    app.command("undo").unwrap();
+    let output = app.command(&format!("load {}", name)).unwrap();
+    assert!(
+        output.contains("**Location:** 🏨 `The Prancing Pony` (inn)"),
+        "{}",
+        output,
+    );
+}
@@ -0,0 +1,49 @@
+use crate::common::sync_app;
+
+#[test]
+fn locations_in_region() {
+    let mut app = sync_app();
+
+    app.command("a kingdom named The North").unwrap();
+    app.command("a county named The Wolfswood in The North")
+        .unwrap();
+    app.command("a town named Winter Town in The North")
+        .unwrap();
+    app.command("a river named The White Knife in The Wolfswood")
+        .unwrap();
+    app.command("an inn named The Smoking Log in Winter Town")
+        .unwrap();
+
+    assert_eq!(
+        "# Locations in The North\n\n🏞 `The White Knife` (river)\\\n🏘 `Winter Town` (town)",
+        app.command("locations in the north").unwrap(),
+    );
+}
+
+#[test]
+fn locations_in_empty_region() {
+    let mut app = sync_app();
+
+    app.command("a kingdom named The North").unwrap();
+
+    assert_eq!(
+        "There are no locations in `The North` yet. To add one, try `put [name] in The North`.",
+        app.command("locations in The North").unwrap(),
+    );
+}
+
+#[test]
+fn locations_in_errors() {
+    let mut app = sync_app();
+
+    app.command("a town named Winter Town").unwrap();
+
+    assert_eq!(
+        "`Winter Town` is not a region. Try `what is in Winter Town` instead.",
+        app.command("locations in Winter Town").unwrap_err(),
+    );
+    assert_eq!(
+        "There is no place named \"Essos\".",
+        app.command("locations in Essos").unwrap_err(),
+    );
+}
@@ -1,6 +1,20 @@
 mod create;
 mod create_multiple;
+mod demographics;
+mod describe;
 mod edit;
+mod generate;
+mod location;
+mod locations_in;
+mod notes;
+mod pronoun;
+mod region;
+mod relate;
+mod rename;
+mod reroll;
+mod tags;
+mod town;
+mod tree;
 
 use crate::common::{get_name, sync_app};
 
@@ -88,7 +102,10 @@ fn generated_content_is_persisted() {
     // **Species:** human\
     // **Gender:** feminine\
     // **Age:** 64 years\
-    // **Size:** 5'7", 112 lbs (medium)
+    // **Size:** 5'7", 112 lbs (medium)\
+    // **Alignment:** neutral\
+    // **Occupation:** weaver\
+    // **Personality:** gruff and fair
     //
     // _Sybil has not yet been saved. Use ~save~ to save her to your `journal`. For more
     // suggestions, type ~more~._
@@ -102,7 +119,10 @@ fn generated_content_is_persisted() {
         persisted_output.lines().nth(2).unwrap(),
     );
     assert_eq!(
-        12,
+        generated_output
+            .lines()
+            .filter(|generated| !generated.starts_with('_'))
+            .count(),
         generated_output
             .lines()
             .zip(persisted_output.lines())
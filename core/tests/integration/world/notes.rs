@@ -0,0 +1,102 @@
+use crate::common::sync_app;
+
+#[test]
+fn add_and_replace_notes() {
+    let mut app = sync_app();
+    app.command("npc named Gundren").unwrap();
+
+    assert_eq!(
+        "There are no notes on `Gundren` yet. To add one, try `note add Gundren: [note]`.",
+        app.command("notes gundren").unwrap(),
+    );
+
+    let output = app
+        .command("note Gundren: \"owes the party 50gp\"")
+        .unwrap();
+    assert!(
+        output.contains("\n\n**Notes:** owes the party 50gp\n\n</div>"),
+        "{}",
+        output,
+    );
+    assert!(
+        output.ends_with("_A note was added to Gundren. Use `undo` to reverse this._"),
+        "{}",
+        output,
+    );
+
+    app.command("note add Gundren: knows the way to Wave Echo Cave")
+        .unwrap();
+    assert_eq!(
+        "# Notes on Gundren\n\nowes the party 50gp\\\nknows the way to Wave Echo Cave",
+        app.command("notes Gundren").unwrap(),
+    );
+    assert!(app
+        .command("describe Gundren")
+        .unwrap()
+        .ends_with("\n\n**Notes:** owes the party 50gp\\\nknows the way to Wave Echo Cave"));
+
+    app.command("note set Gundren: missing").unwrap();
+    assert_eq!(
+        "# Notes on Gundren\n\nmissing",
+        app.command("notes Gundren").unwrap(),
+    );
+
+    app.command("undo").unwrap();
+    assert_eq!(
+        "# Notes on Gundren\n\nowes the party 50gp\\\nknows the way to Wave Echo Cave",
+        app.command("notes Gundren").unwrap(),
+    );
+
+    let output = app.command("note set Gundren:").unwrap();
+    assert!(!output.contains("**Notes:**"), "{}", output);
+    assert!(
+        output.contains("_Gundren's notes were cleared. Use `undo` to reverse this._"),
+        "{}",
+        output,
+    );
+}
+
+#[test]
+fn note_on_unsaved_place() {
+    let mut app = sync_app();
+    let output = app.command("inn").unwrap();
+    let name = output.lines().nth(2).unwrap()[2..].to_string();
+
+    let output = app
+        .command(&format!("note add {}: the ale is watered down", name))
+        .unwrap();
+    assert!(
+        output.contains("\n\n**Notes:** the ale is watered down\n\n</div>"),
+        "{}",
+        output,
+    );
+    assert!(
+        output.ends_with(&format!(
+            "_A note was added to {} and {} was automatically saved to your `journal`. Use `undo` to reverse this._",
+            name, name,
+        )),
+        "{}",
+        output,
+    );
+}
+
+#[test]
+fn note_errors() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "There is no entity named \"Gundren\".",
+        app.command("note add Gundren: owes the party 50gp")
+            .unwrap_err(),
+    );
+    assert_eq!(
+        "There is no entity named \"Gundren\".",
+        app.command("notes Gundren").unwrap_err(),
+    );
+
+    app.command("npc named Gundren").unwrap();
+    assert_eq!(
+        "There is nothing to note. Try `note add Gundren: [note]`.",
+        app.command("note add gundren:").unwrap_err(),
+    );
+}
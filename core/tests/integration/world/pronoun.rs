@@ -0,0 +1,63 @@
+use crate::common::{get_name, sync_app};
+
+#[test]
+fn it_refers_to_the_last_thing() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "There is nothing for \"it\" to refer to yet. Try creating something first, eg. `npc`.",
+        app.command("save it").unwrap_err(),
+    );
+
+    let name = get_name(&app.command("npc").unwrap());
+    assert_eq!(
+        format!(
+            "{} was successfully saved. Use `undo` to reverse this.",
+            name,
+        ),
+        app.command("save it").unwrap(),
+    );
+    assert!(
+        app.command("describe that").unwrap().contains(&name),
+        "{}",
+        name,
+    );
+
+    let output = app.command("it is an elf").unwrap();
+    assert!(output.contains("elf"), "{}", output);
+    assert_eq!(
+        format!("# {}", name),
+        app.command("that").unwrap().lines().nth(2).unwrap(),
+    );
+
+    app.command("inn named The Prancing Pony").unwrap();
+    app.command(&format!("put {} in it", name)).unwrap();
+    assert_eq!(
+        format!("# {}", name),
+        app.command("that").unwrap().lines().nth(2).unwrap(),
+    );
+    app.command("rename The Prancing Pony to The Green Dragon")
+        .unwrap();
+    let output = app.command("who is at it").unwrap();
+    assert!(output.contains("The Green Dragon"), "{}", output);
+    assert!(output.contains(&name), "{}", output);
+}
+
+#[test]
+fn it_is_forgotten_when_deleted() {
+    let mut app = sync_app();
+
+    let name = get_name(&app.command("npc").unwrap());
+    app.command("save it").unwrap();
+    assert_eq!(
+        format!(
+            "{} was successfully deleted. Use `undo` to reverse this.",
+            name,
+        ),
+        app.command("delete it").unwrap(),
+    );
+    assert_eq!(
+        "There is nothing for \"it\" to refer to yet. Try creating something first, eg. `npc`.",
+        app.command("describe it").unwrap_err(),
+    );
+}
@@ -0,0 +1,74 @@
+use crate::common::sync_app;
+
+#[test]
+fn generate_region_with_geography() {
+    let mut app = sync_app();
+
+    let output = app.command("generate region woods named Mirkwood").unwrap();
+    assert!(output.contains("# Mirkwood\n*forest*"), "{}", output);
+
+    let output = app
+        .command("region MOUNTAINS named Misty Mountains")
+        .unwrap();
+    assert!(
+        output.contains("# Misty Mountains\n*mountain*"),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn generate_region_unknown_geography() {
+    let mut app = sync_app();
+
+    let error = app
+        .command("generate region potato named Idaho")
+        .unwrap_err();
+    assert!(
+        error.starts_with("\"potato\" is not a recognized type of region. Try one of: archipelago, barrens, coastline, "),
+        "{}",
+        error,
+    );
+    assert!(app.command("Idaho").is_err());
+
+    let error = app.command("generate region potato").unwrap_err();
+    assert!(
+        error.starts_with("\"potato\" is not a recognized type of region. Try one of: "),
+        "{}",
+        error,
+    );
+
+    let error = app.command("region").unwrap_err();
+    assert!(
+        error.starts_with(
+            "Regions are generated by type, eg. `region forest`. Try one of: archipelago, "
+        ),
+        "{}",
+        error,
+    );
+}
+
+#[test]
+fn generate_region_named_or_counted() {
+    let mut app = sync_app();
+
+    let output = app.command("region named Gondor").unwrap();
+    assert!(output.contains("# Gondor\n*region*"), "{}", output);
+
+    // Regions don't have a name generator, but "x3" is still read as a count.
+    assert_eq!(
+        "There is no name generator implemented for that type. You must specify your own name using `region named [name]`.",
+        app.command("region x3").unwrap_err(),
+    );
+}
+
+#[test]
+fn generate_region_without_name() {
+    let mut app = sync_app();
+
+    let output = app.command("generate region mountains").unwrap();
+    assert!(output.contains("\n*mountain*\n"), "{}", output);
+
+    let output = app.command("a swamp").unwrap();
+    assert!(output.contains("\n*swamp*\n"), "{}", output);
+}
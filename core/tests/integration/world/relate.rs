@@ -0,0 +1,125 @@
+use crate::common::sync_app;
+
+#[test]
+fn relate_npcs() {
+    let mut app = sync_app();
+    app.command("a dwarf man named Gundren").unwrap();
+    app.command("a dwarf man named Tharden").unwrap();
+    app.command("a dwarf girl named Nundra").unwrap();
+
+    let output = app.command("relate Gundren to Tharden as brother").unwrap();
+    assert!(
+        output.contains("\\\n**Relationships:** brother of `Tharden`"),
+        "{}",
+        output,
+    );
+    assert!(
+        output.ends_with("_Gundren is now Tharden's brother. Use `undo` to reverse this._"),
+        "{}",
+        output,
+    );
+
+    // The relationship is reflected on the other character.
+    let output = app.command("Tharden").unwrap();
+    assert!(
+        output.contains("\\\n**Relationships:** brother of `Gundren`"),
+        "{}",
+        output,
+    );
+
+    let output = app.command("relate Nundra to Gundren as child").unwrap();
+    assert!(
+        output.ends_with("_Nundra is now Gundren's daughter. Use `undo` to reverse this._"),
+        "{}",
+        output,
+    );
+
+    let output = app.command("Gundren").unwrap();
+    assert!(
+        output.contains("\\\n**Relationships:** brother of `Tharden` and father of `Nundra`"),
+        "{}",
+        output,
+    );
+
+    // Relating the same pair again replaces the relationship, whichever way around.
+    let output = app.command("relate Tharden to Gundren as rival").unwrap();
+    assert!(
+        output.ends_with(
+            "_Tharden is now Gundren's rival instead of brother. Use `undo` to reverse this._"
+        ),
+        "{}",
+        output,
+    );
+    let output = app.command("Gundren").unwrap();
+    assert!(
+        output.contains("\\\n**Relationships:** rival of `Tharden` and father of `Nundra`"),
+        "{}",
+        output,
+    );
+
+    app.command("undo").unwrap();
+    let output = app.command("Tharden").unwrap();
+    assert!(
+        output.contains("\\\n**Relationships:** brother of `Gundren`"),
+        "{}",
+        output,
+    );
+}
+
+#[test]
+fn relate_npcs_deleted() {
+    let mut app = sync_app();
+    app.command("a dwarf man named Gundren").unwrap();
+    app.command("a dwarf man named Tharden").unwrap();
+    app.command("relate Gundren to Tharden as brother").unwrap();
+
+    app.command("delete Tharden").unwrap();
+    let output = app.command("Gundren").unwrap();
+    assert!(!output.contains("Relationships"), "{}", output);
+
+    app.command("undo").unwrap();
+    let output = app.command("Gundren").unwrap();
+    assert!(
+        output.contains("\\\n**Relationships:** brother of `Tharden`"),
+        "{}",
+        output,
+    );
+
+    app.command("delete Gundren").unwrap();
+    let output = app.command("Tharden").unwrap();
+    assert!(!output.contains("Relationships"), "{}", output);
+}
+
+#[test]
+fn relate_npcs_errors() {
+    let mut app = sync_app();
+    app.command("a dwarf man named Gundren").unwrap();
+    app.command("an inn named The Prancing Pony").unwrap();
+
+    assert_eq!(
+        "There is no character named \"Tharden\".",
+        app.command("relate Gundren to Tharden as brother")
+            .unwrap_err(),
+    );
+    assert_eq!(
+        "`The Prancing Pony` is not a character, so it can't be related to anyone.",
+        app.command("relate Gundren to The Prancing Pony as friend")
+            .unwrap_err(),
+    );
+    assert_eq!(
+        "`Gundren` can't be related to themself.",
+        app.command("relate Gundren to Gundren as friend")
+            .unwrap_err(),
+    );
+
+    let output = app.command("dwarf").unwrap();
+    let name = crate::common::get_name(&output);
+    assert_eq!(
+        format!(
+            "Nobody can be related to `{}` until they have been saved. Use `save {}` to save them to your `journal`.",
+            name, name,
+        ),
+        app.command(&format!("relate Gundren to {} as friend", name))
+            .unwrap_err(),
+    );
+}
@@ -0,0 +1,93 @@
+use crate::common::{get_name, sync_app};
+
+#[test]
+fn rename_npc() {
+    let mut app = sync_app();
+
+    let name = get_name(&app.command("npc").unwrap());
+
+    let output = app
+        .command(&format!("rename {} to Potato Johnson", name))
+        .unwrap();
+    assert_eq!("Potato Johnson", get_name(&output));
+    assert!(
+        output.ends_with(&format!(
+            "_{} was renamed to Potato Johnson and automatically saved to your `journal`. Use `undo` to reverse this._",
+            name,
+        )),
+        "{}",
+        output,
+    );
+
+    assert!(app.command(&name).is_err());
+    app.command("Potato Johnson").unwrap();
+
+    let output = app
+        .command("rename potato johnson to Potato Jackson")
+        .unwrap();
+    assert!(
+        output.ends_with(
+            "_Potato Johnson was renamed to Potato Jackson. Use `undo` to reverse this._"
+        ),
+        "{}",
+        output,
+    );
+
+    let output = app.command("undo").unwrap();
+    assert_eq!("Potato Johnson", get_name(&output));
+}
+
+#[test]
+fn rename_place_case_only() {
+    let mut app = sync_app();
+
+    app.command("an inn named the prancing pony").unwrap();
+
+    let output = app
+        .command("rename the prancing pony to The Prancing Pony")
+        .unwrap();
+    assert_eq!("The Prancing Pony", get_name(&output));
+}
+
+#[test]
+fn rename_name_containing_to() {
+    let mut app = sync_app();
+
+    app.command("a tower named Tower to Stone").unwrap();
+
+    let output = app.command("rename Tower to Stone to Stonekeep").unwrap();
+    assert_eq!("Stonekeep", get_name(&output));
+    app.command("Stonekeep").unwrap();
+}
+
+#[test]
+fn rename_errors() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "There is no entity named \"Potato Johnson\".",
+        app.command("rename Potato Johnson to Potato Jackson")
+            .unwrap_err(),
+    );
+
+    app.command("a character named Potato Johnson").unwrap();
+    app.command("an inn named The Prancing Pony").unwrap();
+
+    assert_eq!(
+        "There is already an entity named `The Prancing Pony`. Try a different name, or `delete The Prancing Pony` first.",
+        app.command("rename Potato Johnson to the prancing pony")
+            .unwrap_err(),
+    );
+
+    for command in ["help", "npc", "it"] {
+        assert_eq!(
+            format!(
+                "`{}` is already a command, so it can't be used as a name.",
+                command,
+            ),
+            app.command(&format!("rename Potato Johnson to {}", command))
+                .unwrap_err(),
+        );
+    }
+    app.command("Potato Johnson").unwrap();
+}
@@ -0,0 +1,208 @@
+use crate::common::{get_name, sync_app};
+
+#[test]
+fn reroll_npc_name() {
+    let mut app = sync_app();
+
+    let generated_output = app.command("npc").unwrap();
+    let name = get_name(&generated_output);
+
+    let output = app.command("reroll name").unwrap();
+    let new_name = get_name(&output);
+    assert_ne!(name, new_name);
+    assert!(
+        output.ends_with(&format!(
+            "_{}'s name was rerolled and {} was automatically saved to your `journal`. Use `undo` to reverse this._",
+            name, new_name,
+        )),
+        "{}",
+        output,
+    );
+
+    // Everything but the name is unchanged.
+    assert_eq!(
+        details(&generated_output).replace(&name, &new_name),
+        details(&output),
+    );
+
+    assert!(app.command(&name).is_err());
+    app.command(&new_name).unwrap();
+
+    {
+        let output = app.command("undo").unwrap();
+        assert_eq!(name, get_name(&output));
+    }
+
+    app.command(&name).unwrap();
+}
+
+#[test]
+fn reroll_npc_except_name() {
+    let mut app = sync_app();
+
+    let generated_output = app.command("npc").unwrap();
+    let name = get_name(&generated_output);
+    let gender = |output: &str| {
+        output
+            .lines()
+            .find(|line| line.starts_with("**Gender:**"))
+            .map(|line| line.to_string())
+    };
+
+    let output = app.command("reroll except name").unwrap();
+    assert_eq!(name, get_name(&output));
+    assert!(
+        output.contains(&format!(
+            "_Everything but {0}'s name was rerolled and {0} was automatically saved to your `journal`. Use `undo` to reverse this._",
+            name,
+        )),
+        "{}",
+        output,
+    );
+
+    // Changing the gender might leave a name that doesn't fit, which is pointed out.
+    let mut previous_output = output;
+    for _ in 0..20 {
+        let output = app.command("reroll body").unwrap();
+        assert_eq!(name, get_name(&output));
+        assert_eq!(
+            gender(&previous_output) != gender(&output),
+            output.contains("Use `reroll name` if it no longer fits."),
+            "{}\n\n{}",
+            previous_output,
+            output,
+        );
+        previous_output = output;
+    }
+
+    app.command("a house named Kingsbridge").unwrap();
+    assert_eq!(
+        Err("There is no body to reroll for Kingsbridge.".to_string()),
+        app.command("reroll body"),
+    );
+}
+
+#[test]
+fn reroll_npc_age() {
+    let mut app = sync_app();
+
+    let generated_output = app.command("npc").unwrap();
+    let name = get_name(&generated_output);
+
+    for i in 0..5 {
+        let output = app.command("reroll age").unwrap();
+        assert_eq!(name, get_name(&output));
+        assert!(
+            output.ends_with(&if i == 0 {
+                format!(
+                    "_{0}'s age was rerolled and {0} was automatically saved to your `journal`. Use `undo` to reverse this._",
+                    name,
+                )
+            } else {
+                format!("_{}'s age was rerolled. Use `undo` to reverse this._", name)
+            }),
+            "{}",
+            output,
+        );
+
+        // The only lines that can change are the summary line, the age in years and the size.
+        let unchanged = |output: &str| {
+            details(output)
+                .lines()
+                .filter(|line| {
+                    !line.starts_with('*')
+                        && !line.starts_with("**Age:**")
+                        && !line.starts_with("**Size:**")
+                })
+                .map(|line| line.to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(unchanged(&generated_output), unchanged(&output));
+    }
+}
+
+#[test]
+fn reroll_npc_species() {
+    let mut app = sync_app();
+
+    let mut previous_output = app.command("npc").unwrap();
+    let species = |output: &str| {
+        output
+            .lines()
+            .find(|line| line.starts_with("**Species:**"))
+            .map(|line| line.to_string())
+    };
+
+    for _ in 0..5 {
+        let name = get_name(&previous_output);
+        let output = app.command("reroll species").unwrap();
+        let new_name = get_name(&output);
+
+        // The name is generated to suit the species, so it's rerolled along with it.
+        assert_ne!(name, new_name);
+        assert_ne!(species(&previous_output), species(&output));
+        assert!(
+            output.contains(&format!("_{}'s species was rerolled", name)),
+            "{}",
+            output,
+        );
+        app.command(&new_name).unwrap();
+
+        previous_output = output;
+    }
+}
+
+#[test]
+fn reroll_place_name() {
+    let mut app = sync_app();
+
+    let name = get_name(&app.command("inn").unwrap());
+    let output = app.command("reroll name").unwrap();
+
+    assert_ne!(name, get_name(&output));
+    assert!(output.contains("*inn*"), "{}", output);
+}
+
+#[test]
+fn reroll_errors() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        Err("There is nothing to reroll.".to_string()),
+        app.command("reroll name"),
+    );
+
+    let name = get_name(&app.command("inn").unwrap());
+
+    assert_eq!(
+        Err(format!("There is no age to reroll for {}.", name)),
+        app.command("reroll age"),
+    );
+
+    let name = get_name(&app.command("elf named Legolas").unwrap());
+    assert_eq!(
+        Err(format!(
+            "{}'s species is elf, and only dragonborn have an ancestry to reroll.",
+            name,
+        )),
+        app.command("reroll ancestry"),
+    );
+
+    app.command("dragonborn named Arjhan").unwrap();
+    app.command("reroll ancestry").unwrap();
+
+    assert_eq!(
+        Err("\"potato\" can't be rerolled. Try one of: name, gender, age, species, ethnicity, occupation, personality, alignment, ancestry, quirk, except name, body.".to_string()),
+        app.command("reroll potato"),
+    );
+}
+
+/// The details view, minus the name and the trailing notice.
+fn details(output: &str) -> String {
+    output
+        .lines()
+        .skip(3)
+        .take_while(|line| !line.starts_with('_'))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
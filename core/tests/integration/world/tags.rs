@@ -0,0 +1,147 @@
+use crate::common::{sync_app, sync_app_with_dispatcher};
+use initiative_core::Event;
+use std::sync::Mutex;
+
+static LAST_EVENT: Mutex<Option<Event>> = Mutex::new(None);
+
+fn event_dispatcher(event: Event) {
+    *LAST_EVENT.lock().unwrap() = Some(event);
+}
+
+#[test]
+fn tag_and_untag() {
+    let mut app = sync_app();
+    app.command("a dwarf man named Gundren Rockseeker").unwrap();
+    app.command("an inn named The Prancing Pony").unwrap();
+
+    let output = app
+        .command("tag Gundren Rockseeker Faction:Dwarves quest-giver")
+        .unwrap();
+    assert!(
+        output.contains("\n\n**Tags:** faction:dwarves, quest-giver\n\n</div>"),
+        "{}",
+        output,
+    );
+    assert!(
+        output.ends_with(
+            "_Gundren Rockseeker was tagged faction:dwarves, quest-giver. Use `undo` to reverse this._",
+        ),
+        "{}",
+        output,
+    );
+
+    // Tags are normalized, so these are the same tag.
+    app.command("tag The Prancing Pony #Quest-Giver").unwrap();
+    assert_eq!(
+        "`The Prancing Pony` already has those tags.",
+        app.command("tag The Prancing Pony quest-giver")
+            .unwrap_err(),
+    );
+
+    let output = app.command("list tag quest-giver").unwrap();
+    assert!(
+        output.starts_with("# Things tagged quest-giver\n\n"),
+        "{}",
+        output,
+    );
+    assert!(output.contains("`Gundren Rockseeker`"), "{}", output);
+    assert!(output.contains("`The Prancing Pony`"), "{}", output);
+    assert!(output.ends_with("*2 in total.*"), "{}", output);
+
+    let output = app.command("untag Gundren Rockseeker quest-giver").unwrap();
+    assert!(
+        output.contains("\n\n**Tags:** faction:dwarves\n\n</div>"),
+        "{}",
+        output,
+    );
+    assert!(
+        output.ends_with(
+            "_Gundren Rockseeker is no longer tagged quest-giver. Use `undo` to reverse this._",
+        ),
+        "{}",
+        output,
+    );
+
+    let output = app.command("list tag Quest Giver").unwrap();
+    assert!(!output.contains("Gundren"), "{}", output);
+
+    assert_eq!(
+        "`Gundren Rockseeker` isn't tagged quest-giver.",
+        app.command("untag Gundren Rockseeker quest-giver")
+            .unwrap_err(),
+    );
+
+    // Removing the last tag leaves nothing behind.
+    let output = app
+        .command("untag Gundren Rockseeker faction:dwarves")
+        .unwrap();
+    assert!(!output.contains("**Tags:**"), "{}", output);
+
+    app.command("untag The Prancing Pony quest-giver").unwrap();
+    assert_eq!(
+        "*There are no things tagged quest-giver in your journal.*",
+        app.command("list tag quest-giver").unwrap(),
+    );
+
+    app.command("undo").unwrap();
+    assert!(app
+        .command("list tag quest-giver")
+        .unwrap()
+        .contains("`The Prancing Pony`"));
+}
+
+#[test]
+fn tag_errors() {
+    let mut app = sync_app();
+
+    assert_eq!(
+        "There is no entity named \"Gundren\".",
+        app.command("tag Gundren quest-giver").unwrap_err(),
+    );
+}
+
+#[test]
+fn tags_are_exported_and_imported() {
+    let mut app = sync_app_with_dispatcher(&event_dispatcher);
+    app.command("npc named Gundren").unwrap();
+    app.command("tag Gundren quest-giver").unwrap();
+    app.command("export").unwrap();
+
+    let data_json = match LAST_EVENT.lock().unwrap().take() {
+        Some(Event::Export(data)) => serde_json::to_string(&data).unwrap(),
+        _ => panic!("Expected an export event."),
+    };
+
+    assert!(
+        data_json.contains(r#""tags":["quest-giver"]"#),
+        "{}",
+        data_json,
+    );
+
+    let mut app = sync_app();
+    app.bulk_import(serde_json::from_str(&data_json).unwrap(), false)
+        .unwrap();
+    assert!(app
+        .command("list tag quest-giver")
+        .unwrap()
+        .contains("`Gundren`"));
+}
+
+#[test]
+fn tag_and_list_round_trip() {
+    let mut app = sync_app();
+    app.command("a dwarf man named Gundren").unwrap();
+
+    // Spaces separate tags when listing, just as they do when tagging.
+    app.command("tag Gundren Quest Giver").unwrap();
+    let output = app.command("list tag quest giver").unwrap();
+    assert!(
+        output.starts_with("# Things tagged quest, giver\n\n"),
+        "{}",
+        output,
+    );
+    assert!(output.contains("`Gundren`"), "{}", output);
+
+    let output = app.command("list tag quest").unwrap();
+    assert!(output.contains("`Gundren`"), "{}", output);
+}
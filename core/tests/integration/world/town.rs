@@ -0,0 +1,89 @@
+use crate::common::sync_app;
+
+#[test]
+fn generate_town() {
+    let mut app = sync_app();
+
+    let output = app.command("generate town").unwrap();
+    let town_name = town_name(&output);
+
+    assert_eq!(3, output.matches("\n## ").count(), "{}", output);
+    assert!(output.contains("(town)"), "{}", output);
+    assert!(output.contains("(inn)"), "{}", output);
+    assert!(
+        output.contains(&format!("`{} General Store` (general-store)", town_name)),
+        "{}",
+        output,
+    );
+    assert!(
+        output.contains(&format!("`Temple of {}` (temple)", town_name)),
+        "{}",
+        output,
+    );
+    assert!(
+        output.ends_with(&format!(
+            "_{} and everyone in it have been saved to your `journal`. Use `who is at [place]` to see who is where._",
+            town_name,
+        )),
+        "{}",
+        output,
+    );
+
+    // Every building is in the town, and every character is in one of the buildings.
+    let output = app
+        .command(&format!("load {} General Store", town_name))
+        .unwrap();
+    assert!(
+        output.contains(&format!("**Location:** 🏘 `{}` (town)", town_name)),
+        "{}",
+        output,
+    );
+
+    let output = app
+        .command(&format!("who is at Temple of {}", town_name))
+        .unwrap();
+    assert_eq!(
+        2,
+        output.lines().filter(|line| line.contains('`')).count(),
+        "{}",
+        output,
+    );
+}
+
+#[test]
+fn generate_town_named() {
+    let mut app = sync_app();
+
+    let output = app.command("generate town named Foxford").unwrap();
+    assert_eq!("Foxford", town_name(&output));
+    assert!(output.contains("`Temple of Foxford`"), "{}", output);
+
+    assert_eq!(
+        "That name is already in use by 🏘 `Foxford` (town).",
+        app.command("generate town named foxford").unwrap_err(),
+    );
+}
+
+#[test]
+fn generate_town_name_clash() {
+    let mut app = sync_app();
+
+    app.command("generate town named Foxford").unwrap();
+    app.command("delete Foxford").unwrap();
+
+    // The general store is still there, so nothing is saved rather than half a town.
+    assert_eq!(
+        "That name is already in use by 🪙 `Foxford General Store` (general-store).",
+        app.command("generate town named Foxford").unwrap_err(),
+    );
+    assert!(app.command("Foxford").is_err());
+}
+
+fn town_name(output: &str) -> String {
+    output
+        .lines()
+        .next()
+        .unwrap()
+        .trim_start_matches("# ")
+        .to_string()
+}
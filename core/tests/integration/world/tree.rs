@@ -0,0 +1,140 @@
+use crate::common::sync_app;
+
+#[test]
+fn put_region_in_region() {
+    let mut app = sync_app();
+
+    app.command("a continent named Westeros").unwrap();
+    app.command("a kingdom named The North").unwrap();
+
+    let output = app.command("put the north in westeros").unwrap();
+    assert!(
+        output.contains("**Location:** 🗺 `Westeros` (continent)"),
+        "{}",
+        output,
+    );
+    assert!(
+        output.ends_with("_The North was put in Westeros. Use `undo` to reverse this._"),
+        "{}",
+        output,
+    );
+
+    app.command("undo").unwrap();
+    let output = app.command("The North").unwrap();
+    assert!(!output.contains("**Location:**"), "{}", output);
+}
+
+#[test]
+fn put_errors() {
+    let mut app = sync_app();
+
+    app.command("a continent named Westeros").unwrap();
+    app.command("a kingdom named The North").unwrap();
+    app.command("a duchy named Winterfell").unwrap();
+    app.command("put The North in Westeros").unwrap();
+    app.command("put Winterfell in The North").unwrap();
+
+    assert_eq!(
+        "`Westeros` can't be put inside itself.",
+        app.command("put Westeros in Westeros").unwrap_err(),
+    );
+    assert_eq!(
+        "`Winterfell` is inside `Westeros`, so `Westeros` can't also be inside `Winterfell`.",
+        app.command("put Westeros in Winterfell").unwrap_err(),
+    );
+    assert_eq!(
+        "There is no place named \"Essos\".",
+        app.command("put Westeros in Essos").unwrap_err(),
+    );
+    assert_eq!(
+        "There is no entity named \"Essos\".",
+        app.command("put Essos in Westeros").unwrap_err(),
+    );
+
+    let name = app.command("npc").unwrap().lines().nth(2).unwrap()[2..].to_string();
+    app.command("npc named Ned").unwrap();
+    assert_eq!(
+        "`Ned` is not a place, so nothing can be put in it.",
+        app.command(&format!("put {} in Ned", name)).unwrap_err(),
+    );
+
+    app.command("inn").unwrap();
+    let output = app.command("inn").unwrap();
+    let inn = output.lines().nth(2).unwrap()[2..].to_string();
+    assert_eq!(
+        format!(
+            "Nothing can be put in `{}` until it has been saved. Use `save {}` to save it to your `journal`.",
+            inn, inn,
+        ),
+        app.command(&format!("put Ned in {}", inn)).unwrap_err(),
+    );
+}
+
+#[test]
+fn put_deep_cycle() {
+    let mut app = sync_app();
+
+    // A chain deeper than the tree display goes.
+    for i in 1..=13 {
+        app.command(&format!("a region named P{}", i)).unwrap();
+        if i > 1 {
+            app.command(&format!("put P{} in P{}", i, i - 1)).unwrap();
+        }
+    }
+
+    assert_eq!(
+        "`P13` is inside `P1`, so `P1` can't also be inside `P13`.",
+        app.command("put P1 in P13").unwrap_err(),
+    );
+}
+
+#[test]
+fn tree() {
+    let mut app = sync_app();
+
+    app.command("a continent named Westeros").unwrap();
+    app.command("a kingdom named The North").unwrap();
+    app.command("a kingdom named The Reach").unwrap();
+    app.command("a duchy named Winterfell").unwrap();
+    app.command("put The Reach in Westeros").unwrap();
+    app.command("put The North in Westeros").unwrap();
+    app.command("put Winterfell in The North").unwrap();
+
+    assert_eq!(
+        "# Westeros
+
+🗺 `Westeros` (continent)
+
+* 👑 `The North` (kingdom)
+  * 👑 `Winterfell` (duchy)
+* 👑 `The Reach` (kingdom)",
+        app.command("what is in westeros").unwrap(),
+    );
+
+    assert_eq!(
+        "# Winterfell
+
+👑 `Winterfell` (duchy)
+
+_There are no places in `Winterfell` yet. To add one, try `put [name] in Winterfell`._",
+        app.command("what's in Winterfell").unwrap(),
+    );
+
+    // Deleting a region leaves the regions inside it without a parent, and undoing the deletion
+    // restores them.
+    app.command("delete The North").unwrap();
+    let output = app.command("Winterfell").unwrap();
+    assert!(!output.contains("**Location:**"), "{}", output);
+    assert!(!app
+        .command("what is in Westeros")
+        .unwrap()
+        .contains("Winterfell"));
+
+    app.command("undo").unwrap();
+    let output = app.command("Winterfell").unwrap();
+    assert!(
+        output.contains("**Location:** 👑 `The North`, 🗺 `Westeros`"),
+        "{}",
+        output
+    );
+}
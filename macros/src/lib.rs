@@ -47,6 +47,7 @@ pub fn uuid(input: TokenStream) -> TokenStream {
 /// following functions:
 ///
 /// * `get_words()`
+/// * `variants()` (every variant, including those of nested WordLists, without aliases)
 /// * `as_str()`
 /// * `word_count()`
 /// * `parse_cs()`
@@ -27,6 +27,8 @@ Latest `changelog` entry:
         )
     );
 
+    // The length of the message alone, which is always a character boundary because the
+    // optional message is appended after it.
     let motd_len = motd.len();
 
     if let Some(message) = message {
@@ -132,13 +132,28 @@ pub fn run(input: TokenStream) -> Result<TokenStream, String> {
 
             result
         }
-        "MagicItem" => srd_5e::magic_items()?
+        "MagicItem" => {
+            let items = srd_5e::items()?;
+
+            srd_5e::magic_items()?
+                .iter()
+                .map(|item| {
+                    EntryBuilder::default()
+                        .with_ident(&item.token())
+                        .with_name(item.name.to_owned())
+                        .with_details(&item.display_details(&items))
+                        .into_entry()
+                        .unwrap()
+                })
+                .collect()
+        }
+        "Monster" => srd_5e::monsters()?
             .iter()
-            .map(|item| {
+            .map(|monster| {
                 EntryBuilder::default()
-                    .with_ident(&item.token())
-                    .with_name(item.name.to_owned())
-                    .with_details(&item.display_details())
+                    .with_ident(&monster.token())
+                    .with_name(monster.name.to_owned())
+                    .with_details(&monster.display_details())
                     .into_entry()
                     .unwrap()
             })
@@ -199,13 +214,155 @@ pub fn run(input: TokenStream) -> Result<TokenStream, String> {
         quote! { #ident::#variant => #output }
     });
 
-    let get_list = if ident == "Spell" {
-        let list_output: String = std::iter::once(format!("# {}s", ident))
-            .chain(
-                srd_5e::spells()?
-                    .iter()
-                    .map(|spell| format!("\n* {}", spell.display_summary())),
+    let get_metadata = if ident == "Item" {
+        let items = srd_5e::items()?;
+
+        let get_category_index_cases = items.iter().map(|item| {
+            let variant: syn::Ident = syn::parse_str(&item.token()).unwrap();
+            let category_index = item.category_index();
+            quote! { #ident::#variant => #category_index }
+        });
+
+        let get_cost_cases = items.iter().map(|item| {
+            let variant: syn::Ident = syn::parse_str(&item.token()).unwrap();
+            let cost = item.cost();
+            quote! { #ident::#variant => #cost }
+        });
+
+        let all_variants = entries.iter().map(|entry| &entry.ident);
+
+        quote! {
+            pub fn get_all() -> impl Iterator<Item = Self> {
+                [#(#ident::#all_variants),*].into_iter()
+            }
+
+            pub fn get_category_index(&self) -> &'static str {
+                match self {
+                    #(#get_category_index_cases),*
+                }
+            }
+
+            pub fn get_cost(&self) -> &'static str {
+                match self {
+                    #(#get_cost_cases),*
+                }
+            }
+        }
+    } else if ident == "Spell" {
+        let spells = srd_5e::spells()?;
+
+        let get_level_cases = spells.iter().map(|spell| {
+            let variant: syn::Ident = syn::parse_str(&spell.token()).unwrap();
+            let level = spell.level();
+            quote! { #ident::#variant => #level }
+        });
+
+        let get_school_cases = spells.iter().map(|spell| {
+            let variant: syn::Ident = syn::parse_str(&spell.token()).unwrap();
+            let school = spell.school();
+            quote! { #ident::#variant => #school }
+        });
+
+        let get_summary_cases = spells.iter().map(|spell| {
+            let variant: syn::Ident = syn::parse_str(&spell.token()).unwrap();
+            let summary = spell.display_summary().to_string();
+            quote! { #ident::#variant => #summary }
+        });
+
+        let all_variants = entries.iter().map(|entry| &entry.ident);
+
+        quote! {
+            pub fn get_all() -> impl Iterator<Item = Self> {
+                [#(#ident::#all_variants),*].into_iter()
+            }
+
+            pub fn get_level(&self) -> u8 {
+                match self {
+                    #(#get_level_cases),*
+                }
+            }
+
+            pub fn get_school(&self) -> &'static str {
+                match self {
+                    #(#get_school_cases),*
+                }
+            }
+
+            pub fn get_summary(&self) -> &'static str {
+                match self {
+                    #(#get_summary_cases),*
+                }
+            }
+        }
+    } else if ident == "MagicItem" {
+        let magic_items = srd_5e::magic_items()?;
+
+        let get_rarities_cases = magic_items.iter().map(|item| {
+            let variant: syn::Ident = syn::parse_str(&item.token()).unwrap();
+            let rarities = item.rarities();
+            quote! { #ident::#variant => &[#(#rarities),*] }
+        });
+
+        let rarities = srd_5e::MagicItem::RARITIES;
+        let all_variants = entries.iter().map(|entry| &entry.ident);
+
+        quote! {
+            pub const RARITIES: &'static [&'static str] = &[#(#rarities),*];
+
+            pub fn get_all() -> impl Iterator<Item = Self> {
+                [#(#ident::#all_variants),*].into_iter()
+            }
+
+            pub fn get_rarities(&self) -> &'static [&'static str] {
+                match self {
+                    #(#get_rarities_cases),*
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let list_entries = match format!("{}", ident).as_str() {
+        "Condition" => Some(
+            srd_5e::conditions()?
+                .iter()
+                .map(|condition| format!("\n* {}", condition.display_summary()))
+                .collect::<Vec<_>>(),
+        ),
+        "ItemCategory" => {
+            let mut names: Vec<_> = entries.iter().map(|entry| &entry.name).collect();
+            names.sort_unstable();
+
+            Some(
+                names
+                    .into_iter()
+                    .map(|name| format!("\n* `srd item category {}`", name))
+                    .collect::<Vec<_>>(),
             )
+        }
+        "Monster" => Some(
+            srd_5e::monsters()?
+                .iter()
+                .map(|monster| format!("\n* {}", monster.display_summary()))
+                .collect::<Vec<_>>(),
+        ),
+        "Spell" => Some(
+            srd_5e::spells()?
+                .iter()
+                .map(|spell| format!("\n* {}", spell.display_summary()))
+                .collect::<Vec<_>>(),
+        ),
+        _ => None,
+    };
+
+    let get_list = if let Some(list_entries) = list_entries {
+        let title = match format!("{}", ident).as_str() {
+            "ItemCategory" => "Item Categories".to_string(),
+            _ => format!("{}s", ident),
+        };
+        let list_output: String = std::iter::once(format!("# {}", title))
+            .chain(list_entries)
             .collect();
 
         quote! {
@@ -236,6 +393,8 @@ pub fn run(input: TokenStream) -> Result<TokenStream, String> {
 
             #get_list
 
+            #get_metadata
+
             pub fn get_name(&self) -> &'static str {
                 match self {
                     #(#get_name_cases),*
@@ -15,6 +15,8 @@ fn impl_word_list(ast: &syn::DeriveInput) -> Result<TokenStream, String> {
         let mut as_str_cases = Vec::new();
         let mut words = Vec::new();
         let mut words_chain = Vec::new();
+        let mut variants = Vec::new();
+        let mut variants_chain = Vec::new();
         let mut word_count_chain = Vec::new();
 
         data_enum.variants.iter().try_for_each(|variant| {
@@ -59,6 +61,7 @@ fn impl_word_list(ast: &syn::DeriveInput) -> Result<TokenStream, String> {
                     let term_lc = term.to_lowercase();
                     words.push(quote! { #term, });
                     as_str_cases.push(quote! { #name::#ident => #term, });
+                    variants.push(quote! { #name::#ident, });
                     from_str_match_cases.push(quote! { #term_lc => Ok(#name::#ident), });
                 }
                 syn::Fields::Unnamed(fields) => {
@@ -75,6 +78,8 @@ fn impl_word_list(ast: &syn::DeriveInput) -> Result<TokenStream, String> {
                         } else
                     });
                     words_chain.push(quote! { .chain(#field_type::get_words()) });
+                    variants_chain
+                        .push(quote! { .chain(#field_type::variants().map(#name::#ident)) });
                     word_count_chain.push(quote! { + #field_type::word_count() });
                 }
                 syn::Fields::Named(_) => {
@@ -97,6 +102,14 @@ fn impl_word_list(ast: &syn::DeriveInput) -> Result<TokenStream, String> {
                     #(#words_chain)*
                 }
 
+                pub fn variants() -> impl Iterator<Item = Self> {
+                    [
+                        #(#variants)*
+                    ]
+                    .into_iter()
+                    #(#variants_chain)*
+                }
+
                 pub const fn as_str(&self) -> &'static str {
                     match self {
                         #(#as_str_cases)*
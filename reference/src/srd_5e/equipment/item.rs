@@ -106,6 +106,14 @@ impl Item {
         crate::to_camel_case(&self.index)
     }
 
+    pub fn category_index(&self) -> &str {
+        &self.item_category.index
+    }
+
+    pub fn cost(&self) -> String {
+        self.cost.to_string()
+    }
+
     pub fn display_table_row<'a>(&'a self, columns: &'a [Column]) -> TableRowView {
         TableRowView {
             item: self,
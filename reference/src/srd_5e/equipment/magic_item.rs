@@ -1,3 +1,4 @@
+use super::Item;
 use crate::srd_5e::{write_text_block, Reference};
 use serde::Deserialize;
 use std::fmt;
@@ -15,6 +16,16 @@ pub struct MagicItem {
 }
 
 impl MagicItem {
+    /// The rarities of magic items, from most to least common.
+    pub const RARITIES: &'static [&'static str] = &[
+        "common",
+        "uncommon",
+        "rare",
+        "very rare",
+        "legendary",
+        "artifact",
+    ];
+
     pub fn token(&self) -> String {
         crate::to_camel_case(&self.index)
     }
@@ -23,18 +34,59 @@ impl MagicItem {
         SummaryView(self)
     }
 
-    pub fn display_details(&self) -> DetailsView {
-        DetailsView(self)
+    pub fn display_details<'a>(&'a self, items: &'a [Item]) -> DetailsView<'a> {
+        DetailsView(self, self.base_item(items))
     }
 
     pub fn has_variants(&self) -> bool {
         !self.variants.is_empty()
     }
+
+    /// The mundane item named in the first line of the description, eg. "Weapon (longsword),
+    /// rare", if there is exactly one. Items like "Weapon (any sword)" have none.
+    pub fn base_item<'a>(&self, items: &'a [Item]) -> Option<&'a Item> {
+        let (_, rest) = self.desc.first()?.split_once(" (")?;
+        let (base_name, _) = rest.split_once(')')?;
+        let base_name = base_name.to_lowercase();
+        let base_armor_name = format!("{} armor", base_name);
+
+        items.iter().find(|item| {
+            let name = item.name().to_lowercase();
+            name == base_name || name == base_armor_name
+        })
+    }
+
+    /// The rarities named in the first line of the description, eg. "Weapon (any sword), rare
+    /// (requires attunement)". Items that come in several strengths can have more than one.
+    pub fn rarities(&self) -> Vec<&'static str> {
+        let words: Vec<String> = self
+            .desc
+            .first()
+            .and_then(|line| line.split_once(", "))
+            .map(|(_, rarity)| rarity)
+            .unwrap_or_default()
+            .split(|c: char| !c.is_alphabetic())
+            .map(|word| word.to_lowercase())
+            .collect();
+
+        Self::RARITIES
+            .iter()
+            .filter(|&&rarity| match rarity {
+                "rare" => words
+                    .iter()
+                    .enumerate()
+                    .any(|(i, word)| word == "rare" && (i == 0 || words[i - 1] != "very")),
+                "very rare" => words.windows(2).any(|pair| pair == ["very", "rare"]),
+                _ => words.iter().any(|word| word == rarity),
+            })
+            .copied()
+            .collect()
+    }
 }
 
 pub struct SummaryView<'a>(&'a MagicItem);
 
-pub struct DetailsView<'a>(&'a MagicItem);
+pub struct DetailsView<'a>(&'a MagicItem, Option<&'a Item>);
 
 impl<'a> fmt::Display for SummaryView<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -48,12 +100,17 @@ impl<'a> fmt::Display for SummaryView<'a> {
 
 impl<'a> fmt::Display for DetailsView<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let magic_item = self.0;
+        let (magic_item, base_item) = (self.0, self.1);
 
         writeln!(f, "# {}", magic_item.name)?;
 
         if let Some(line) = magic_item.desc.first() {
-            writeln!(f, "\n*{}*", line)?;
+            if let (Some(base_item), Some((start, rest))) = (base_item, line.split_once(" (")) {
+                let end = rest.split_once(')').map(|(_, end)| end).unwrap_or_default();
+                writeln!(f, "\n*{} (`{}`){}*", start, base_item.name(), end)?;
+            } else {
+                writeln!(f, "\n*{}*", line)?;
+            }
         }
 
         if let Some(chunk) = magic_item.desc.get(1..) {
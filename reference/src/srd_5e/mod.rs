@@ -1,11 +1,13 @@
 pub use conditions::Condition;
 pub use equipment::{Item, ItemCategory, MagicItem};
+pub use monster::Monster;
 pub use spell::Spell;
 pub use std::fmt;
 pub use traits::Trait;
 
 mod conditions;
 mod equipment;
+mod monster;
 mod spell;
 mod traits;
 
@@ -44,6 +46,13 @@ pub fn magic_items() -> Result<Vec<MagicItem>, String> {
     .map_err(|e| format!("{}", e))
 }
 
+pub fn monsters() -> Result<Vec<Monster>, String> {
+    serde_json::from_str(include_str!(
+        "../../../data/srd_5e/src/5e-SRD-Monsters.json",
+    ))
+    .map_err(|e| format!("{}", e))
+}
+
 pub fn spells() -> Result<Vec<Spell>, String> {
     serde_json::from_str(include_str!("../../../data/srd_5e/src/5e-SRD-Spells.json"))
         .map_err(|e| format!("{}", e))
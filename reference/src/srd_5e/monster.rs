@@ -0,0 +1,356 @@
+use super::Reference;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[derive(Debug, Deserialize)]
+pub struct Monster {
+    index: String,
+    pub name: String,
+
+    size: String,
+
+    #[serde(rename = "type")]
+    monster_type: String,
+    subtype: Option<String>,
+    alignment: String,
+
+    armor_class: ArmorClass,
+    hit_points: u16,
+    hit_dice: String,
+
+    #[serde(default)]
+    speed: BTreeMap<String, Value>,
+
+    strength: u8,
+    dexterity: u8,
+    constitution: u8,
+    intelligence: u8,
+    wisdom: u8,
+    charisma: u8,
+
+    #[serde(default)]
+    damage_vulnerabilities: Vec<String>,
+
+    #[serde(default)]
+    damage_resistances: Vec<String>,
+
+    #[serde(default)]
+    damage_immunities: Vec<String>,
+
+    #[serde(default)]
+    condition_immunities: Vec<Reference>,
+
+    #[serde(default)]
+    senses: BTreeMap<String, Value>,
+
+    #[serde(default)]
+    languages: String,
+
+    challenge_rating: f32,
+    xp: u32,
+
+    #[serde(default)]
+    special_abilities: Vec<Ability>,
+
+    #[serde(default)]
+    actions: Vec<Ability>,
+
+    #[serde(default)]
+    reactions: Vec<Ability>,
+
+    #[serde(default)]
+    legendary_actions: Vec<Ability>,
+}
+
+/// Older versions of the database give a bare number, newer versions a list of sources.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ArmorClass {
+    Value(u8),
+    List(Vec<ArmorClassEntry>),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ArmorClassEntry {
+    #[serde(rename = "type")]
+    armor_type: String,
+    value: u8,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Ability {
+    name: String,
+    desc: String,
+}
+
+pub struct SummaryView<'a>(&'a Monster);
+
+pub struct DetailsView<'a>(&'a Monster);
+
+impl Monster {
+    pub fn token(&self) -> String {
+        crate::to_camel_case(&self.index)
+    }
+
+    pub fn display_summary(&self) -> SummaryView<'_> {
+        SummaryView(self)
+    }
+
+    pub fn display_details(&self) -> DetailsView<'_> {
+        DetailsView(self)
+    }
+
+    fn get_size_type(&self) -> String {
+        if let Some(subtype) = &self.subtype {
+            format!("{} {} ({})", self.size, self.monster_type, subtype)
+        } else {
+            format!("{} {}", self.size, self.monster_type)
+        }
+    }
+
+    fn get_challenge_rating(&self) -> String {
+        match self.challenge_rating {
+            0.125 => "1/8".to_string(),
+            0.25 => "1/4".to_string(),
+            0.5 => "1/2".to_string(),
+            cr => format!("{}", cr),
+        }
+    }
+}
+
+impl<'a> fmt::Display for SummaryView<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let monster = self.0;
+        write!(
+            f,
+            "`{}` ({}, CR {})",
+            monster.name,
+            monster.get_size_type().to_lowercase(),
+            monster.get_challenge_rating(),
+        )
+    }
+}
+
+impl<'a> fmt::Display for DetailsView<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let monster = self.0;
+
+        write!(
+            f,
+            "# {}\n*{}, {}*",
+            monster.name,
+            monster.get_size_type(),
+            monster.alignment,
+        )?;
+
+        write!(f, "\n\n**Armor Class:** {}", monster.armor_class)?;
+        write!(
+            f,
+            "\\\n**Hit Points:** {} ({})",
+            monster.hit_points, monster.hit_dice,
+        )?;
+
+        if !monster.speed.is_empty() {
+            write!(f, "\\\n**Speed:** ")?;
+            write_value_list(f, &monster.speed, "walk")?;
+        }
+
+        write!(
+            f,
+            "\n\n| STR | DEX | CON | INT | WIS | CHA |\n|---|---|---|---|---|---|\n| {} | {} | {} | {} | {} | {} |",
+            AbilityScore(monster.strength),
+            AbilityScore(monster.dexterity),
+            AbilityScore(monster.constitution),
+            AbilityScore(monster.intelligence),
+            AbilityScore(monster.wisdom),
+            AbilityScore(monster.charisma),
+        )?;
+
+        {
+            let mut first = true;
+            let mut write_line = |f: &mut fmt::Formatter, label: &str, value: &str| {
+                if value.is_empty() {
+                    return Ok(());
+                }
+
+                if first {
+                    write!(f, "\n\n")?;
+                    first = false;
+                } else {
+                    writeln!(f, "\\")?;
+                }
+
+                write!(f, "**{}:** {}", label, value)
+            };
+
+            write_line(
+                f,
+                "Damage Vulnerabilities",
+                &monster.damage_vulnerabilities.join(", "),
+            )?;
+            write_line(
+                f,
+                "Damage Resistances",
+                &monster.damage_resistances.join(", "),
+            )?;
+            write_line(
+                f,
+                "Damage Immunities",
+                &monster.damage_immunities.join(", "),
+            )?;
+            write_line(
+                f,
+                "Condition Immunities",
+                &monster
+                    .condition_immunities
+                    .iter()
+                    .map(|condition| condition.name.to_lowercase())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            )?;
+            write_line(f, "Senses", &ValueList(&monster.senses).to_string())?;
+            write_line(f, "Languages", &monster.languages)?;
+            write_line(
+                f,
+                "Challenge",
+                &format!(
+                    "{} ({} XP)",
+                    monster.get_challenge_rating(),
+                    ThousandsSeparated(monster.xp),
+                ),
+            )?;
+        }
+
+        write_abilities(f, None, &monster.special_abilities)?;
+        write_abilities(f, Some("Actions"), &monster.actions)?;
+        write_abilities(f, Some("Reactions"), &monster.reactions)?;
+        write_abilities(f, Some("Legendary Actions"), &monster.legendary_actions)?;
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for ArmorClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Value(value) => write!(f, "{}", value),
+            Self::List(entries) => {
+                let mut first = true;
+
+                for entry in entries {
+                    if !first {
+                        write!(f, ", ")?;
+                    }
+                    first = false;
+
+                    match entry.armor_type.as_str() {
+                        "armor" | "dex" => write!(f, "{}", entry.value)?,
+                        "natural" => write!(f, "{} (natural armor)", entry.value)?,
+                        armor_type => write!(f, "{} ({})", entry.value, armor_type)?,
+                    }
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+struct AbilityScore(u8);
+
+impl fmt::Display for AbilityScore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let modifier = (i16::from(self.0) - 10).div_euclid(2);
+
+        if modifier >= 0 {
+            write!(f, "{} (+{})", self.0, modifier)
+        } else {
+            write!(f, "{} ({})", self.0, modifier)
+        }
+    }
+}
+
+struct ThousandsSeparated(u32);
+
+impl fmt::Display for ThousandsSeparated {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = self.0.to_string();
+
+        for (i, c) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", c)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Speeds and senses are key/value maps in the source data, eg. `{"walk": "30 ft."}`.
+struct ValueList<'a>(&'a BTreeMap<String, Value>);
+
+impl<'a> fmt::Display for ValueList<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_value_list(f, self.0, "")
+    }
+}
+
+/// Write the map as a comma-separated list. The `bare_key` (if present) is written first and
+/// without a label, matching the printed stat blocks ("30 ft., fly 60 ft.").
+fn write_value_list(
+    f: &mut fmt::Formatter,
+    values: &BTreeMap<String, Value>,
+    bare_key: &str,
+) -> fmt::Result {
+    let mut first = true;
+
+    let bare = values.get_key_value(bare_key);
+    let rest = values.iter().filter(|(key, _)| key.as_str() != bare_key);
+
+    for (key, value) in bare.into_iter().chain(rest) {
+        let value = match value {
+            Value::String(s) => s.to_string(),
+            Value::Number(n) => n.to_string(),
+            // eg. `"hover": true`
+            _ => continue,
+        };
+
+        if !first {
+            write!(f, ", ")?;
+        }
+        first = false;
+
+        if key == bare_key {
+            write!(f, "{}", value)?;
+        } else if key == "passive_perception" {
+            write!(f, "passive Perception {}", value)?;
+        } else {
+            write!(f, "{} {}", key.replace('_', " "), value)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_abilities(
+    f: &mut fmt::Formatter,
+    heading: Option<&str>,
+    abilities: &[Ability],
+) -> fmt::Result {
+    if abilities.is_empty() {
+        return Ok(());
+    }
+
+    if let Some(heading) = heading {
+        write!(f, "\n\n## {}", heading)?;
+    }
+
+    for ability in abilities {
+        write!(f, "\n\n***{}.*** {}", ability.name, ability.desc)?;
+    }
+
+    Ok(())
+}
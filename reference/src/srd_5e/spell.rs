@@ -55,6 +55,16 @@ impl Spell {
         DetailsView(self)
     }
 
+    /// The spell level, where 0 is a cantrip.
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    /// The lowercase name of the school of magic, eg. "evocation".
+    pub fn school(&self) -> String {
+        self.school.name.to_lowercase()
+    }
+
     fn get_level_school(&self) -> String {
         match (self.level, &self.school) {
             (0, s) => format!("{} cantrip", s.name),
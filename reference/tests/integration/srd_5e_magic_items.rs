@@ -1,4 +1,4 @@
-use initiative_reference::srd_5e::magic_items;
+use initiative_reference::srd_5e::{items, magic_items, MagicItem};
 
 #[test]
 fn deck_of_many_things() {
@@ -81,6 +81,60 @@ Once a card is drawn, it fades from existence. Unless the card is the Fool or th
 ***Ruin.*** All forms of wealth that you carry or own, other than magic items, are lost to you. Portable property vanishes. Businesses, buildings, and land you own are lost in a way that alters reality the least. Any documentation that proves you should own something lost to this card also disappears.
 
 ***Skull.*** You summon an avatar of death-a ghostly humanoid skeleton clad in a tattered black robe and carrying a spectral scythe. It appears in a space of the GM's choice within 10 feet of you and attacks you, warning all others that you must win the battle alone. The avatar fights until you die or it drops to 0 hit points, whereupon it disappears. If anyone tries to help you, the helper summons its own avatar of death. A creature slain by an avatar of death can't be restored to life.",
-        format!("{}", magic_item.display_details()),
+        format!("{}", magic_item.display_details(&items().unwrap())),
+    );
+}
+
+#[test]
+fn rarities() {
+    let magic_items = magic_items().unwrap();
+    let rarities = |name: &str| {
+        magic_items
+            .iter()
+            .find(|i| i.name == name)
+            .unwrap()
+            .rarities()
+    };
+
+    assert_eq!(vec!["legendary"], rarities("Deck of Many Things"));
+    assert_eq!(vec!["rare"], rarities("Flame Tongue"));
+    assert_eq!(vec!["rare"], rarities("Rod of Rulership"));
+
+    let weapon: MagicItem = serde_json::from_str(
+        r#"{"index":"weapon-1-2-or-3","name":"Weapon, +1, +2, or +3","desc":["Weapon (any), uncommon (+1), rare (+2), or very rare (+3)"]}"#,
+    )
+    .unwrap();
+    assert_eq!(vec!["uncommon", "rare", "very rare"], weapon.rarities());
+}
+
+#[test]
+fn base_item() {
+    let items = items().unwrap();
+
+    let magic_items = magic_items().unwrap();
+    let flame_tongue = magic_items
+        .iter()
+        .find(|i| i.name == "Flame Tongue")
+        .unwrap();
+    assert!(flame_tongue.base_item(&items).is_none());
+    assert!(format!("{}", flame_tongue.display_details(&items))
+        .contains("\n*Weapon (any sword), rare (requires attunement)*\n"));
+
+    let sword: MagicItem = serde_json::from_str(
+        r#"{"index":"sun-blade","name":"Sun Blade","desc":["Weapon (longsword), rare (requires attunement)","This item appears to be a longsword hilt."]}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        Some("Longsword".to_string()),
+        sword.base_item(&items).map(|item| item.name()),
+    );
+    assert_eq!(
+        "\
+# Sun Blade
+
+*Weapon (`Longsword`), rare (requires attunement)*
+
+This item appears to be a longsword hilt.",
+        format!("{}", sword.display_details(&items)).trim_end(),
     );
 }
@@ -31,6 +31,8 @@ fn acid_splash() {
     let spell = spells.iter().find(|s| s.name == "Acid Splash").unwrap();
 
     assert_eq!("AcidSplash", spell.token());
+    assert_eq!(0, spell.level());
+    assert_eq!("conjuration", spell.school());
     assert_eq!(
         "`Acid Splash` (conjuration cantrip)",
         format!("{}", spell.display_summary()),
@@ -28,7 +28,7 @@ terminalElement.addEventListener(
   async (event) => await import_database(
     async (data) => {
       try {
-        terminal.output(await wasm.bulk_import(data))
+        terminal.output(await wasm.bulk_import(data, event.detail))
       } catch (e) {
         terminal.output("! " + e)
       }
@@ -41,14 +41,17 @@ pub async fn autocomplete(input: JsValue) -> JsValue {
 }
 
 #[wasm_bindgen]
-pub async fn bulk_import(data: JsValue) -> Result<String, String> {
+pub async fn bulk_import(data: JsValue, overwrite: bool) -> Result<String, String> {
     app()
-        .bulk_import(data.into_serde().map_err(|e| {
-            format!(
-                "The file you tried to import is not valid. The parser error was {}.",
-                e
-            )
-        })?)
+        .bulk_import(
+            data.into_serde().map_err(|e| {
+                format!(
+                    "The file you tried to import is not valid. The parser error was {}.",
+                    e
+                )
+            })?,
+            overwrite,
+        )
         .await
 }
 
@@ -59,7 +62,11 @@ fn event_dispatcher(event: core::Event) {
             init.detail(&JsValue::from_serde(&data).unwrap());
             CustomEvent::new_with_event_init_dict("initiative.export", &init).unwrap()
         }
-        core::Event::Import => CustomEvent::new("initiative.startImport").unwrap(),
+        core::Event::Import { overwrite } => {
+            let mut init = CustomEventInit::new();
+            init.detail(&JsValue::from_bool(overwrite));
+            CustomEvent::new_with_event_init_dict("initiative.startImport", &init).unwrap()
+        }
     };
 
     get_root_element()